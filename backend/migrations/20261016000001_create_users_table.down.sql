@@ -0,0 +1,3 @@
+-- Drop user sessions and users tables
+DROP TABLE IF EXISTS user_sessions;
+DROP TABLE IF EXISTS users;
@@ -0,0 +1,6 @@
+-- Add a role to each user account (reader, librarian or admin)
+ALTER TABLE users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'reader' AFTER display_name;
+
+-- Accounts created before roles existed had full access
+UPDATE users SET role = 'admin';
@@ -0,0 +1,2 @@
+ALTER TABLE users DROP FOREIGN KEY fk_users_borrower;
+ALTER TABLE users DROP COLUMN borrower_id;
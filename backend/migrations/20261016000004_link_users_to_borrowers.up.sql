@@ -0,0 +1,4 @@
+-- Link a user account to a borrower so they can see their own loans
+ALTER TABLE users
+    ADD COLUMN borrower_id CHAR(36) NULL AFTER role,
+    ADD CONSTRAINT fk_users_borrower FOREIGN KEY (borrower_id) REFERENCES borrowers(id) ON DELETE SET NULL;
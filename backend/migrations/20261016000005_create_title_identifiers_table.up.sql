@@ -0,0 +1,12 @@
+-- Additional identifiers of a title (second ISBN, EAN, ISSN, LCCN, OCLC)
+-- Values are stored normalized so searches and duplicate detection match exactly
+CREATE TABLE title_identifiers (
+    id CHAR(36) PRIMARY KEY,
+    title_id CHAR(36) NOT NULL,
+    identifier_type ENUM('isbn10', 'isbn13', 'ean', 'issn', 'lccn', 'oclc') NOT NULL,
+    value VARCHAR(32) NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_title_identifier (title_id, identifier_type, value),
+    INDEX idx_title_identifiers_value (value)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
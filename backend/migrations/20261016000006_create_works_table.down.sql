@@ -0,0 +1,10 @@
+ALTER TABLE titles
+DROP FOREIGN KEY fk_titles_work;
+
+ALTER TABLE titles
+DROP INDEX idx_titles_work_id;
+
+ALTER TABLE titles
+DROP COLUMN work_id;
+
+DROP TABLE IF EXISTS works;
@@ -0,0 +1,21 @@
+-- Abstract works grouping the editions and translations of the same text
+CREATE TABLE works (
+    id CHAR(36) PRIMARY KEY,
+    title VARCHAR(500) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+CREATE INDEX idx_works_title ON works(title);
+
+-- A title is an edition of at most one work
+ALTER TABLE titles
+ADD COLUMN work_id CHAR(36);
+
+ALTER TABLE titles
+ADD CONSTRAINT fk_titles_work
+FOREIGN KEY (work_id) REFERENCES works(id)
+ON DELETE SET NULL;
+
+CREATE INDEX idx_titles_work_id ON titles(work_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS title_tags;
+DROP TABLE IF EXISTS tags;
@@ -0,0 +1,2 @@
+-- titles.genre_id still holds the primary genre, only secondary genres are lost
+DROP TABLE IF EXISTS title_genres;
@@ -0,0 +1,18 @@
+-- Titles can belong to several genres, one of them flagged as primary
+-- titles.genre_id is kept as a copy of the primary genre for existing queries
+CREATE TABLE title_genres (
+    title_id CHAR(36) NOT NULL,
+    genre_id CHAR(36) NOT NULL,
+    is_primary BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (title_id, genre_id),
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    FOREIGN KEY (genre_id) REFERENCES genres(id) ON DELETE CASCADE,
+    INDEX idx_title_genres_genre_id (genre_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- The existing single genre becomes the primary genre
+INSERT INTO title_genres (title_id, genre_id, is_primary)
+SELECT id, genre_id, TRUE
+FROM titles
+WHERE genre_id IS NOT NULL;
@@ -0,0 +1,8 @@
+ALTER TABLE genres
+DROP FOREIGN KEY fk_genres_parent;
+
+ALTER TABLE genres
+DROP INDEX idx_genres_parent_id;
+
+ALTER TABLE genres
+DROP COLUMN parent_id;
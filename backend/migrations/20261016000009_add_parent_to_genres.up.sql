@@ -0,0 +1,10 @@
+-- Genres form a tree (e.g. Fiction > Fantasy > Urban Fantasy)
+-- Genres with sub-genres cannot be deleted, so the foreign key never cascades
+ALTER TABLE genres
+ADD COLUMN parent_id CHAR(36) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
+
+ALTER TABLE genres
+ADD CONSTRAINT fk_genres_parent
+FOREIGN KEY (parent_id) REFERENCES genres(id);
+
+CREATE INDEX idx_genres_parent_id ON genres(parent_id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS volume_custom_values;
+DROP TABLE IF EXISTS title_custom_values;
+DROP TABLE IF EXISTS custom_fields;
@@ -0,0 +1,34 @@
+-- Custom fields defined by administrators (e.g. reading level, box number)
+-- Values are stored as normalized text, one table per scope so that they are
+-- removed together with their title or volume
+CREATE TABLE custom_fields (
+    id CHAR(36) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    field_type ENUM('text', 'number', 'date', 'boolean', 'enum') NOT NULL,
+    scope ENUM('title', 'volume') NOT NULL,
+    -- JSON array of the allowed values of an enum field
+    options TEXT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY unique_custom_field_name (scope, name)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+CREATE TABLE title_custom_values (
+    title_id CHAR(36) NOT NULL,
+    field_id CHAR(36) NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (title_id, field_id),
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    FOREIGN KEY (field_id) REFERENCES custom_fields(id) ON DELETE CASCADE,
+    INDEX idx_title_custom_values_field (field_id, value(100))
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+CREATE TABLE volume_custom_values (
+    volume_id CHAR(36) NOT NULL,
+    field_id CHAR(36) NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (volume_id, field_id),
+    FOREIGN KEY (volume_id) REFERENCES volumes(id) ON DELETE CASCADE,
+    FOREIGN KEY (field_id) REFERENCES custom_fields(id) ON DELETE CASCADE,
+    INDEX idx_volume_custom_values_field (field_id, value(100))
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,12 @@
+-- Records still in the trash become visible again
+ALTER TABLE borrowers
+    DROP INDEX idx_borrowers_deleted_at,
+    DROP COLUMN deleted_at;
+
+ALTER TABLE authors
+    DROP INDEX idx_authors_deleted_at,
+    DROP COLUMN deleted_at;
+
+ALTER TABLE titles
+    DROP INDEX idx_titles_deleted_at,
+    DROP COLUMN deleted_at;
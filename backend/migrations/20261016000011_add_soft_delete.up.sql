@@ -0,0 +1,13 @@
+-- Deleted titles, authors and borrowers are kept in a trash until they are
+-- restored or purged; deleted_at is NULL for live records
+ALTER TABLE titles
+    ADD COLUMN deleted_at DATETIME NULL,
+    ADD INDEX idx_titles_deleted_at (deleted_at);
+
+ALTER TABLE authors
+    ADD COLUMN deleted_at DATETIME NULL,
+    ADD INDEX idx_authors_deleted_at (deleted_at);
+
+ALTER TABLE borrowers
+    ADD COLUMN deleted_at DATETIME NULL,
+    ADD INDEX idx_borrowers_deleted_at (deleted_at);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS title_revision_changes;
+DROP TABLE IF EXISTS title_revisions;
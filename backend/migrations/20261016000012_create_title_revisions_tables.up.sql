@@ -0,0 +1,23 @@
+-- Revision history of title metadata
+-- Each update of a title records one revision, numbered per title, with one
+-- row per changed field; values are stored as text (NULL for an empty field)
+CREATE TABLE title_revisions (
+    id CHAR(36) PRIMARY KEY,
+    title_id CHAR(36) NOT NULL,
+    revision_number INT NOT NULL,
+    -- Kept when the user account is removed, only the link is lost
+    user_id CHAR(36) NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE SET NULL,
+    UNIQUE KEY unique_title_revision (title_id, revision_number)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+CREATE TABLE title_revision_changes (
+    revision_id CHAR(36) NOT NULL,
+    field_name VARCHAR(50) NOT NULL,
+    old_value TEXT NULL,
+    new_value TEXT NULL,
+    PRIMARY KEY (revision_id, field_name),
+    FOREIGN KEY (revision_id) REFERENCES title_revisions(id) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
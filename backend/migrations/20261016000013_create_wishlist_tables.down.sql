@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS wishlist_share_links;
+DROP TABLE IF EXISTS wishlist_items;
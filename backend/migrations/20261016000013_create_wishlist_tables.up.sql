@@ -0,0 +1,24 @@
+-- Wishlist of titles to acquire, one entry per title
+-- Acquiring a title creates its first volume and removes the entry
+CREATE TABLE wishlist_items (
+    title_id CHAR(36) PRIMARY KEY,
+    priority ENUM('high', 'medium', 'low') NOT NULL DEFAULT 'medium',
+    desired_edition VARCHAR(255) NULL,
+    max_price DECIMAL(10, 2) NULL,
+    notes TEXT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    INDEX idx_wishlist_items_priority (priority)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Read-only links to the wishlist for people without an account
+-- Only the SHA-256 hash of each token is stored
+CREATE TABLE wishlist_share_links (
+    id CHAR(36) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    created_by CHAR(36) NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (created_by) REFERENCES users(id) ON DELETE SET NULL
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
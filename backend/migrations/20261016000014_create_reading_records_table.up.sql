@@ -0,0 +1,18 @@
+-- Reading tracking: one row per reading of a title by a user
+-- A re-read is a new row once the previous reading is finished
+CREATE TABLE reading_records (
+    id CHAR(36) PRIMARY KEY,
+    user_id CHAR(36) NOT NULL,
+    title_id CHAR(36) NOT NULL,
+    status ENUM('to_read', 'reading', 'read') NOT NULL DEFAULT 'to_read',
+    started_on DATE NULL,
+    finished_on DATE NULL,
+    current_page INT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    INDEX idx_reading_records_user_status (user_id, status),
+    INDEX idx_reading_records_title (title_id, user_id),
+    INDEX idx_reading_records_finished_on (finished_on)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
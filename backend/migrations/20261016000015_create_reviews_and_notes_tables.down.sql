@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS title_notes;
+DROP TABLE IF EXISTS title_reviews;
@@ -0,0 +1,28 @@
+-- Star ratings and written reviews, at most one per user and title
+CREATE TABLE title_reviews (
+    id CHAR(36) PRIMARY KEY,
+    title_id CHAR(36) NOT NULL,
+    user_id CHAR(36) NOT NULL,
+    rating TINYINT NULL,
+    review TEXT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_title_review (title_id, user_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+-- Private notes and favourite quotes, optionally tied to a page
+CREATE TABLE title_notes (
+    id CHAR(36) PRIMARY KEY,
+    title_id CHAR(36) NOT NULL,
+    user_id CHAR(36) NOT NULL,
+    kind ENUM('note', 'quote') NOT NULL DEFAULT 'note',
+    content TEXT NOT NULL,
+    page INT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    INDEX idx_title_notes_title_user (title_id, user_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,13 @@
+-- Typed relations between titles (sequel, translation, commentary, adaptation, companion).
+-- Each relation is stored once; the other title shows it with the inverse type.
+CREATE TABLE title_relations (
+    id CHAR(36) PRIMARY KEY,
+    title_id CHAR(36) NOT NULL,
+    related_title_id CHAR(36) NOT NULL,
+    relation_type ENUM('sequel_of', 'translation_of', 'commentary_on', 'adaptation_of', 'companion_to') NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    FOREIGN KEY (related_title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_title_relation (title_id, related_title_id, relation_type),
+    INDEX idx_title_relations_related (related_title_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
@@ -0,0 +1,3 @@
+ALTER TABLE series
+    DROP COLUMN reading_order,
+    DROP COLUMN total_volumes;
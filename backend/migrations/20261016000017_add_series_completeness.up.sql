@@ -0,0 +1,4 @@
+-- Completeness tracking: how many volumes the series has, and how to read it
+ALTER TABLE series
+    ADD COLUMN total_volumes INT NULL AFTER description,
+    ADD COLUMN reading_order TEXT NULL AFTER total_volumes;
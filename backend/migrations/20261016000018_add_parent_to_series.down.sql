@@ -0,0 +1,8 @@
+ALTER TABLE series
+DROP FOREIGN KEY fk_series_parent;
+
+ALTER TABLE series
+DROP INDEX idx_series_parent_id;
+
+ALTER TABLE series
+DROP COLUMN parent_id;
@@ -0,0 +1,10 @@
+-- Series can be nested (e.g. Star Wars > The High Republic)
+-- Series with sub-series cannot be deleted, so the foreign key never cascades
+ALTER TABLE series
+ADD COLUMN parent_id CHAR(36) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci AFTER description;
+
+ALTER TABLE series
+ADD CONSTRAINT fk_series_parent
+FOREIGN KEY (parent_id) REFERENCES series(id);
+
+CREATE INDEX idx_series_parent_id ON series(parent_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS title_content_authors;
+DROP TABLE IF EXISTS title_contents;
@@ -0,0 +1,26 @@
+-- Contents of anthologies and collections: one entry per story, essay or poem,
+-- each with its own authors.
+CREATE TABLE title_contents (
+    id CHAR(36) PRIMARY KEY,
+    title_id CHAR(36) NOT NULL,
+    position INT NOT NULL,
+    title VARCHAR(500) NOT NULL,
+    page INT NULL,
+    original_year INT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (title_id) REFERENCES titles(id) ON DELETE CASCADE,
+    INDEX idx_title_contents_title (title_id, position),
+    INDEX idx_title_contents_name (title)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+CREATE TABLE title_content_authors (
+    content_id CHAR(36) NOT NULL,
+    author_id CHAR(36) NOT NULL,
+    role ENUM('main_author', 'co_author', 'translator', 'illustrator', 'editor') NOT NULL DEFAULT 'main_author',
+    display_order INT NOT NULL DEFAULT 1,
+    PRIMARY KEY (content_id, author_id, role),
+    FOREIGN KEY (content_id) REFERENCES title_contents(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES authors(id) ON DELETE CASCADE,
+    INDEX idx_title_content_authors_author (author_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
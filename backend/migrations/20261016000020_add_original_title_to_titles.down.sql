@@ -0,0 +1,6 @@
+ALTER TABLE titles
+    DROP INDEX idx_titles_original_language,
+    DROP INDEX idx_titles_original_title,
+    DROP COLUMN translation_notes,
+    DROP COLUMN original_language,
+    DROP COLUMN original_title;
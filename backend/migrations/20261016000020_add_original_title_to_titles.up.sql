@@ -0,0 +1,7 @@
+-- Original title and language of translations, with free-form translation notes
+ALTER TABLE titles
+    ADD COLUMN original_title VARCHAR(500) NULL AFTER language,
+    ADD COLUMN original_language VARCHAR(10) NULL AFTER original_title,
+    ADD COLUMN translation_notes TEXT NULL AFTER original_language,
+    ADD INDEX idx_titles_original_title (original_title),
+    ADD INDEX idx_titles_original_language (original_language);
@@ -0,0 +1,658 @@
+//! Authentication support for the API.
+//!
+//! This module contains the password hashing helpers, session token handling and
+//! the actix middleware that protects every `/api/v1` route.
+//!
+//! # Session Tokens
+//!
+//! A successful login creates a random token that is returned to the client and
+//! also set as the `rbibli_session` cookie. Only the SHA-256 hash of the token is
+//! stored in the `user_sessions` table, so a database leak does not expose usable
+//! credentials. Clients send the token back either as `Authorization: Bearer <token>`
+//! or through the cookie.
+//!
+//! # Protected Routes
+//!
+//! `require_auth` rejects unauthenticated calls to `/api/v1/*` with `401 Unauthorized`.
+//! The health checks, the static frontend files, the login endpoint and the
+//! `/api/v1/public/*` routes (which check a token of their own) stay open.
+//!
+//! # Roles
+//!
+//! Every user has a `UserRole` (reader < librarian < admin). Routes needing more
+//! than a reader declare it in `backend::run` with `RequireRole`:
+//!
+//! ```rust,ignore
+//! .route("/api/v1/titles", web::post().to(create_title).wrap(RequireRole(UserRole::Librarian)))
+//! ```
+//!
+//! Calls made with an insufficient role get `403 Forbidden` with code `FORBIDDEN`.
+//!
+//! # API Tokens
+//!
+//! Scripts authenticate with long-lived API tokens (prefixed `rbk_`) sent as
+//! `Authorization: Bearer`. A token acts as its owner, limited to its scopes:
+//! `required_scope` maps each route to the scope it needs (`GET` needs `read`,
+//! other methods need `write`). Tokens cannot be used to manage tokens.
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::middleware::Next;
+use actix_web::http::Method;
+use actix_web::{web, Error, FromRequest, HttpMessage, HttpRequest, HttpResponse};
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future::LocalBoxFuture;
+use log::{debug, error, info, warn};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use sqlx::{MySqlPool, Row};
+use uuid::Uuid;
+
+use crate::configuration::AuthSettings;
+use crate::models::{ApiScope, UserRole};
+use crate::AppState;
+
+/// Name of the cookie carrying the session token.
+pub const SESSION_COOKIE: &str = "rbibli_session";
+
+/// Minimum accepted password length for user accounts.
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Prefix distinguishing API tokens from session tokens.
+pub const API_TOKEN_PREFIX: &str = "rbk_";
+
+/// API routes reachable without a session.
+const PUBLIC_API_ROUTES: &[&str] = &["/api/v1/auth/login"];
+
+/// Prefix of the API routes that authenticate with a token in their URL
+/// (e.g. wishlist share links) instead of a session.
+const PUBLIC_API_PREFIX: &str = "/api/v1/public/";
+
+/// API routes (below `/api/v1/`) covered by the `loans:*` scopes.
+const LOAN_RESOURCES: &[&str] = &["loans", "borrowers", "borrower-groups", "statistics/loans", "me/loans"];
+
+/// API routes (below `/api/v1/`) covered by the `users:*` scopes.
+const USER_RESOURCES: &[&str] = &["users"];
+
+/// The user attached to a request by `require_auth`.
+///
+/// Handlers can take it as an argument to know who is calling:
+///
+/// ```rust,ignore
+/// pub async fn me(user: AuthenticatedUser) -> impl Responder { ... }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub id: String,
+    pub username: String,
+    pub role: UserRole,
+    /// Scopes of the API token used, or `None` for a login session
+    pub scopes: Option<Vec<ApiScope>>,
+}
+
+impl AuthenticatedUser {
+    /// Returns `true` if the request was authenticated with an API token.
+    pub fn is_api_token(&self) -> bool {
+        self.scopes.is_some()
+    }
+}
+
+impl FromRequest for AuthenticatedUser {
+    type Error = Error;
+    type Future = std::future::Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let user = req.extensions().get::<AuthenticatedUser>().cloned();
+        std::future::ready(user.ok_or_else(|| {
+            actix_web::error::InternalError::from_response("", unauthorized_response()).into()
+        }))
+    }
+}
+
+/// Hashes a password with Argon2id and a random salt.
+///
+/// The returned string is in PHC format and embeds the salt and parameters.
+pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    Ok(Argon2::default()
+        .hash_password(password.as_bytes(), &salt)?
+        .to_string())
+}
+
+/// Checks a password against a PHC hash produced by `hash_password`.
+///
+/// Returns `false` if the hash cannot be parsed.
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    match PasswordHash::new(password_hash) {
+        Ok(parsed) => Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok(),
+        Err(e) => {
+            warn!("Stored password hash could not be parsed: {}", e);
+            false
+        }
+    }
+}
+
+/// Generates a new random session token (256 bits, URL-safe base64).
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Generates a new API token (`rbk_` followed by 256 random bits).
+pub fn generate_api_token() -> String {
+    format!("{}{}", API_TOKEN_PREFIX, generate_token())
+}
+
+/// Returns the hex-encoded SHA-256 hash of a token, as stored in the database.
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// Extracts the session token from the `Authorization: Bearer` header or the session cookie.
+pub fn token_from_request(req: &HttpRequest) -> Option<String> {
+    if let Some(token) = req
+        .headers()
+        .get(actix_web::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+    {
+        return Some(token.to_string());
+    }
+
+    req.cookie(SESSION_COOKIE)
+        .map(|c| c.value().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Builds the JSON body returned for unauthenticated API calls.
+pub fn unauthorized_response() -> HttpResponse {
+    HttpResponse::Unauthorized().json(serde_json::json!({
+        "error": {
+            "code": "UNAUTHORIZED",
+            "message": "Authentication required"
+        }
+    }))
+}
+
+/// Builds the JSON body returned when the caller's role is too low for a route.
+pub fn forbidden_response(required: UserRole, actual: UserRole) -> HttpResponse {
+    HttpResponse::Forbidden().json(serde_json::json!({
+        "error": {
+            "code": "FORBIDDEN",
+            "message": format!("This action requires the '{}' role", required),
+            "details": {
+                "required_role": required.to_string(),
+                "role": actual.to_string()
+            }
+        }
+    }))
+}
+
+/// Builds the JSON body returned when an API token lacks the scope a route needs.
+pub fn insufficient_scope_response(required: ApiScope) -> HttpResponse {
+    HttpResponse::Forbidden().json(serde_json::json!({
+        "error": {
+            "code": "FORBIDDEN",
+            "message": format!("This API token lacks the '{}' scope", required),
+            "details": {
+                "required_scope": required.to_string()
+            }
+        }
+    }))
+}
+
+/// Returns the scope an API token needs to call a route, or `None` if any token may call it.
+pub fn required_scope(path: &str, method: &Method) -> Option<ApiScope> {
+    let resource = path.strip_prefix("/api/v1/")?;
+    if resource.starts_with("auth/") {
+        return None;
+    }
+
+    let matches = |prefixes: &[&str]| {
+        prefixes.iter().any(|prefix| {
+            resource == *prefix || resource.starts_with(&format!("{}/", prefix))
+        })
+    };
+    let read_only = matches!(*method, Method::GET | Method::HEAD);
+
+    let scope = if matches(LOAN_RESOURCES) {
+        if read_only { ApiScope::LoansRead } else { ApiScope::LoansWrite }
+    } else if matches(USER_RESOURCES) {
+        if read_only { ApiScope::UsersRead } else { ApiScope::UsersWrite }
+    } else if read_only {
+        ApiScope::CatalogRead
+    } else {
+        ApiScope::CatalogWrite
+    };
+    Some(scope)
+}
+
+/// Returns the percent-decoded request path, which is what the router matches routes on.
+///
+/// Access checks must use it instead of `HttpRequest::path`, or `/%61pi/v1/titles`
+/// would be treated as a public path while still reaching `/api/v1/titles`.
+pub fn route_path(req: &HttpRequest) -> &str {
+    req.match_info().as_str()
+}
+
+/// Returns `true` for the routes managing API tokens, which API tokens may not call.
+pub fn is_token_management_route(path: &str) -> bool {
+    path == "/api/v1/tokens" || path.starts_with("/api/v1/tokens/")
+}
+
+/// Returns `true` for the API routes `require_auth` lets through without a session.
+pub fn is_public_route(path: &str) -> bool {
+    PUBLIC_API_ROUTES.contains(&path) || path.starts_with(PUBLIC_API_PREFIX)
+}
+
+/// Parses the comma-separated `api_tokens.scopes` column, skipping unknown values.
+pub fn scopes_from_db(value: &str) -> Vec<ApiScope> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| {
+            let scope = ApiScope::parse(s);
+            if scope.is_none() {
+                warn!("Ignoring unknown API token scope '{}'", s);
+            }
+            scope
+        })
+        .collect()
+}
+
+/// Parses a stored role, falling back to the least privileged one for unknown values.
+pub fn role_from_db(value: &str) -> UserRole {
+    UserRole::from_db(value).unwrap_or_else(|| {
+        warn!("Unknown user role '{}', treating it as reader", value);
+        UserRole::Reader
+    })
+}
+
+/// Creates a session for the given user and returns the clear token and its expiration.
+///
+/// Expired sessions are purged at the same time.
+pub async fn create_session(
+    pool: &MySqlPool,
+    user_id: &str,
+    ttl_hours: i64,
+) -> Result<(String, DateTime<Utc>), sqlx::Error> {
+    let token = generate_token();
+    let expires_at = Utc::now() + Duration::hours(ttl_hours);
+
+    sqlx::query("DELETE FROM user_sessions WHERE expires_at < UTC_TIMESTAMP()")
+        .execute(pool)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO user_sessions (id, user_id, token_hash, expires_at) VALUES (?, ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id)
+    .bind(hash_token(&token))
+    .bind(expires_at.naive_utc())
+    .execute(pool)
+    .await?;
+
+    Ok((token, expires_at))
+}
+
+/// Looks up the active user owning a non-expired session token.
+pub async fn find_session_user(
+    pool: &MySqlPool,
+    token: &str,
+) -> Result<Option<AuthenticatedUser>, sqlx::Error> {
+    let query = r#"
+        SELECT u.id, u.username, u.role
+        FROM user_sessions s
+        INNER JOIN users u ON s.user_id = u.id
+        WHERE s.token_hash = ? AND s.expires_at > UTC_TIMESTAMP() AND u.is_active = TRUE
+    "#;
+
+    let row = sqlx::query(query)
+        .bind(hash_token(token))
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|row| AuthenticatedUser {
+        id: row.get("id"),
+        username: row.get("username"),
+        role: role_from_db(row.get("role")),
+        scopes: None,
+    }))
+}
+
+/// Looks up the active user owning a valid (not revoked, not expired) API token.
+///
+/// Records the time of use on success.
+pub async fn find_api_token_user(
+    pool: &MySqlPool,
+    token: &str,
+) -> Result<Option<AuthenticatedUser>, sqlx::Error> {
+    let query = r#"
+        SELECT t.id AS token_id, t.scopes, u.id, u.username, u.role
+        FROM api_tokens t
+        INNER JOIN users u ON t.user_id = u.id
+        WHERE t.token_hash = ?
+          AND t.revoked_at IS NULL
+          AND (t.expires_at IS NULL OR t.expires_at > UTC_TIMESTAMP())
+          AND u.is_active = TRUE
+    "#;
+
+    let Some(row) = sqlx::query(query)
+        .bind(hash_token(token))
+        .fetch_optional(pool)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let token_id: String = row.get("token_id");
+    sqlx::query("UPDATE api_tokens SET last_used_at = UTC_TIMESTAMP() WHERE id = ?")
+        .bind(&token_id)
+        .execute(pool)
+        .await?;
+
+    Ok(Some(AuthenticatedUser {
+        id: row.get("id"),
+        username: row.get("username"),
+        role: role_from_db(row.get("role")),
+        scopes: Some(scopes_from_db(row.get("scopes"))),
+    }))
+}
+
+/// Middleware rejecting unauthenticated calls to `/api/v1/*`.
+///
+/// On success the `AuthenticatedUser` is stored in the request extensions.
+///
+/// # Returns
+///
+/// * The inner service response when the route is public or the token is valid
+/// * `401 Unauthorized` with code `UNAUTHORIZED` when no valid session or API token is presented
+/// * `403 Forbidden` with code `FORBIDDEN` when an API token lacks the route's scope
+/// * `500 Internal Server Error` if the session lookup fails
+pub async fn require_auth(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let path = route_path(req.request()).to_string();
+    if !path.starts_with("/api/v1/") || is_public_route(&path) {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    }
+
+    let Some(token) = token_from_request(req.request()) else {
+        debug!("Rejecting unauthenticated request to {}", path);
+        return Ok(req.into_response(unauthorized_response()).map_into_right_body());
+    };
+
+    let Some(state) = req.app_data::<web::Data<AppState>>().cloned() else {
+        error!("Application state missing while authenticating {}", path);
+        return Ok(req
+            .into_response(HttpResponse::InternalServerError().finish())
+            .map_into_right_body());
+    };
+
+    let lookup = if token.starts_with(API_TOKEN_PREFIX) {
+        find_api_token_user(&state.db_pool, &token).await
+    } else {
+        find_session_user(&state.db_pool, &token).await
+    };
+
+    match lookup {
+        Ok(Some(user)) => {
+            debug!("Request to {} authenticated as {}", path, user.username);
+
+            if let Some(scopes) = &user.scopes {
+                if is_token_management_route(&path) {
+                    let response = HttpResponse::Forbidden().json(serde_json::json!({
+                        "error": {
+                            "code": "FORBIDDEN",
+                            "message": "API tokens cannot be used to manage API tokens"
+                        }
+                    }));
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+                if let Some(required) = required_scope(&path, req.method())
+                    && !scopes.iter().any(|scope| scope.grants(required))
+                {
+                    debug!("API token of {} lacks scope {} for {}", user.username, required, path);
+                    return Ok(req
+                        .into_response(insufficient_scope_response(required))
+                        .map_into_right_body());
+                }
+            }
+
+            req.extensions_mut().insert(user);
+            next.call(req).await.map(ServiceResponse::map_into_left_body)
+        }
+        Ok(None) => {
+            warn!("Invalid or expired session token for {}", path);
+            Ok(req.into_response(unauthorized_response()).map_into_right_body())
+        }
+        Err(e) => {
+            error!("Database error while checking session: {}", e);
+            let response = HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to check session"
+                }
+            }));
+            Ok(req.into_response(response).map_into_right_body())
+        }
+    }
+}
+
+/// Route middleware requiring a minimum role.
+///
+/// Must run after `require_auth`, which is the case when it wraps a single route.
+/// Callers with a lower role get `403 Forbidden`.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireRole(pub UserRole);
+
+impl<S, B> Transform<S, ServiceRequest> for RequireRole
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RequireRoleMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RequireRoleMiddleware {
+            service,
+            required: self.0,
+        }))
+    }
+}
+
+/// Service created by `RequireRole`.
+pub struct RequireRoleMiddleware<S> {
+    service: S,
+    required: UserRole,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireRoleMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let role = req.extensions().get::<AuthenticatedUser>().map(|user| user.role);
+
+        let response = match role {
+            Some(role) if role >= self.required => {
+                let fut = self.service.call(req);
+                return Box::pin(async move { fut.await.map(ServiceResponse::map_into_left_body) });
+            }
+            Some(role) => {
+                debug!("Rejecting {} for role {} (requires {})", req.path(), role, self.required);
+                forbidden_response(self.required, role)
+            }
+            None => unauthorized_response(),
+        };
+
+        let response = req.into_response(response).map_into_right_body();
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// Creates the initial administrator account when the `users` table is empty.
+///
+/// Uses `auth.initial_admin_password` from the configuration if set. Otherwise a
+/// random password is generated and printed once on standard output (not in the
+/// log) so the first login is possible.
+pub async fn ensure_initial_admin(
+    pool: &MySqlPool,
+    settings: &AuthSettings,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let user_count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM users")
+        .fetch_one(pool)
+        .await?;
+
+    if user_count > 0 {
+        return Ok(());
+    }
+
+    let (password, generated) = match &settings.initial_admin_password {
+        Some(password) if !password.is_empty() => (password.expose().to_string(), false),
+        _ => (generate_token()[..16].to_string(), true),
+    };
+
+    let password_hash = hash_password(&password).map_err(|e| e.to_string())?;
+
+    sqlx::query(
+        "INSERT INTO users (id, username, password_hash, display_name, role, is_active) VALUES (?, ?, ?, ?, ?, TRUE)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&settings.initial_admin_username)
+    .bind(password_hash)
+    .bind("Administrator")
+    .bind(UserRole::Admin.to_string())
+    .execute(pool)
+    .await?;
+
+    if generated {
+        warn!(
+            "Created initial user '{}' with a generated password printed on standard output. \
+             Change it after the first login.",
+            settings.initial_admin_username
+        );
+        println!("Initial password of '{}': {}", settings.initial_admin_username, password);
+    } else {
+        info!("Created initial user '{}'", settings.initial_admin_username);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use actix_web::App;
+
+    #[test]
+    fn test_password_hash_roundtrip() {
+        let hash = hash_password("correct horse").expect("hashing failed");
+        assert!(verify_password("correct horse", &hash));
+        assert!(!verify_password("wrong horse", &hash));
+        assert!(!verify_password("correct horse", "not-a-phc-string"));
+    }
+
+    #[test]
+    fn test_role_ordering_and_parsing() {
+        assert!(UserRole::Admin > UserRole::Librarian);
+        assert!(UserRole::Librarian > UserRole::Reader);
+        assert_eq!(role_from_db("librarian"), UserRole::Librarian);
+        assert_eq!(role_from_db("superuser"), UserRole::Reader);
+    }
+
+    #[test]
+    fn test_required_scope() {
+        assert_eq!(required_scope("/api/v1/titles", &Method::GET), Some(ApiScope::CatalogRead));
+        assert_eq!(required_scope("/api/v1/titles/42", &Method::PUT), Some(ApiScope::CatalogWrite));
+        assert_eq!(required_scope("/api/v1/loans/42/extend", &Method::POST), Some(ApiScope::LoansWrite));
+        assert_eq!(required_scope("/api/v1/borrower-groups", &Method::GET), Some(ApiScope::LoansRead));
+        assert_eq!(required_scope("/api/v1/statistics/loans", &Method::GET), Some(ApiScope::LoansRead));
+        assert_eq!(required_scope("/api/v1/statistics/library", &Method::GET), Some(ApiScope::CatalogRead));
+        assert_eq!(required_scope("/api/v1/users", &Method::POST), Some(ApiScope::UsersWrite));
+        assert_eq!(required_scope("/api/v1/me/loans", &Method::GET), Some(ApiScope::LoansRead));
+        assert_eq!(required_scope("/api/v1/auth/me", &Method::GET), None);
+        assert_eq!(required_scope("/health", &Method::GET), None);
+    }
+
+    #[test]
+    fn test_api_token_checks_use_decoded_path() {
+        let request = actix_web::test::TestRequest::post().uri("/api/v1/%6Coans").to_http_request();
+        assert_eq!(required_scope(route_path(&request), &Method::POST), Some(ApiScope::LoansWrite));
+
+        let request = actix_web::test::TestRequest::post().uri("/api/v1/%74okens").to_http_request();
+        assert!(is_token_management_route(route_path(&request)));
+        let request = actix_web::test::TestRequest::delete().uri("/%61pi/v1/tokens/42").to_http_request();
+        assert!(is_token_management_route(route_path(&request)));
+        assert!(!is_token_management_route("/api/v1/tokenstore"));
+    }
+
+    #[test]
+    fn test_public_routes() {
+        assert!(is_public_route("/api/v1/auth/login"));
+        assert!(is_public_route("/api/v1/public/wishlist/some-token"));
+        assert!(!is_public_route("/api/v1/wishlist"));
+        assert!(!is_public_route("/api/v1/auth/me"));
+    }
+
+    #[actix_web::test]
+    async fn test_encoded_api_path_requires_auth() {
+        let app = actix_web::test::init_service(
+            App::new()
+                .wrap(actix_web::middleware::from_fn(require_auth))
+                .route("/api/v1/titles", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        for uri in ["/api/v1/titles", "/%61pi/v1/titles", "/api/v1/%74itles"] {
+            let request = actix_web::test::TestRequest::get().uri(uri).to_request();
+            let response = actix_web::test::call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_scopes_from_db() {
+        assert_eq!(
+            scopes_from_db("catalog:read, loans:write,bogus"),
+            vec![ApiScope::CatalogRead, ApiScope::LoansWrite]
+        );
+        assert!(ApiScope::LoansWrite.grants(ApiScope::LoansRead));
+        assert!(!ApiScope::LoansRead.grants(ApiScope::LoansWrite));
+        assert!(!ApiScope::CatalogWrite.grants(ApiScope::LoansRead));
+    }
+
+    #[test]
+    fn test_tokens_are_unique_and_hashed() {
+        let token1 = generate_token();
+        let token2 = generate_token();
+        assert_ne!(token1, token2);
+        assert_eq!(hash_token(&token1).len(), 64);
+        assert_eq!(hash_token(&token1), hash_token(&token1));
+        assert!(generate_api_token().starts_with(API_TOKEN_PREFIX));
+    }
+}
@@ -13,7 +13,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let database_url = configuration.database.connection_string();
 
     let pool = MySqlPoolOptions::new()
-        .connect(&database_url)
+        .connect(database_url.expose())
         .await?;
 
     let row: (String,) = sqlx::query_as(
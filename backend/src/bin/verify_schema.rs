@@ -15,7 +15,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     println!("Connecting to database...");
     let pool = MySqlPoolOptions::new()
-        .connect(&database_url)
+        .connect(database_url.expose())
         .await?;
 
     println!("Connected. Querying schema for 'titles' table...");
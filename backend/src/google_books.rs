@@ -116,6 +116,10 @@ pub struct BookData {
     pub publication_year: Option<i32>,
     pub pages: Option<i32>,
     pub language: Option<String>,
+    /// Title of the work this edition translates, when the provider knows it
+    pub original_title: Option<String>,
+    /// Language code of the work this edition translates, when the provider knows it
+    pub original_language: Option<String>,
     pub isbn: String,
     pub summary: Option<String>,
     pub categories: Vec<String>,
@@ -204,6 +208,9 @@ pub async fn fetch_book_by_isbn(isbn: &str) -> Result<BookData, Box<dyn Error>>
         publication_year,
         pages: volume_info.page_count,
         language: volume_info.language.clone(),
+        // Google Books does not tell which work an edition translates
+        original_title: None,
+        original_language: None,
         isbn: clean_isbn,
         summary: volume_info.description.clone(),
         categories: volume_info.categories.clone().unwrap_or_default(),
@@ -0,0 +1,250 @@
+//! API handlers for managing personal API tokens.
+//!
+//! This module provides HTTP handlers for creating, listing and revoking the
+//! long-lived tokens that scripts use instead of a password. Each token acts as
+//! the user who created it, limited to its scopes. These routes only accept a
+//! login session, never an API token.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::{self, AuthenticatedUser};
+use crate::models::{ApiToken, CreateApiTokenRequest, CreateApiTokenResponse, UserRole};
+use crate::AppState;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use log::{info, error};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Number of leading token characters stored in clear to recognize a token in lists.
+const TOKEN_PREFIX_LENGTH: usize = 12;
+
+/// Longest lifetime a token can be given (about ten years), in days.
+const MAX_TOKEN_LIFETIME_DAYS: i64 = 3650;
+
+fn to_utc(value: NaiveDateTime) -> DateTime<Utc> {
+    DateTime::from_naive_utc_and_offset(value, Utc)
+}
+
+fn api_token_from_row(row: &MySqlRow) -> Option<ApiToken> {
+    let id: String = row.get("id");
+    let created_at: NaiveDateTime = row.get("created_at");
+    let last_used_at: Option<NaiveDateTime> = row.get("last_used_at");
+    let expires_at: Option<NaiveDateTime> = row.get("expires_at");
+    let revoked_at: Option<NaiveDateTime> = row.get("revoked_at");
+
+    Some(ApiToken {
+        id: Uuid::parse_str(&id).ok()?,
+        name: row.get("name"),
+        token_prefix: row.get("token_prefix"),
+        scopes: auth::scopes_from_db(row.get("scopes")),
+        created_at: to_utc(created_at),
+        last_used_at: last_used_at.map(to_utc),
+        expires_at: expires_at.map(to_utc),
+        revoked_at: revoked_at.map(to_utc),
+    })
+}
+
+/// Lists the API tokens of the current user, including revoked ones.
+///
+/// **Endpoint**: `GET /api/v1/tokens`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `ApiToken` objects (newest first)
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_api_tokens(
+    data: web::Data<AppState>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/tokens - Fetching API tokens of {}", user.username);
+
+    let query = r#"
+        SELECT id, name, token_prefix, scopes, created_at, last_used_at, expires_at, revoked_at
+        FROM api_tokens
+        WHERE user_id = ?
+        ORDER BY created_at DESC
+    "#;
+
+    match sqlx::query(query).bind(&user.id).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let tokens: Vec<ApiToken> = rows.iter().filter_map(api_token_from_row).collect();
+            info!("Successfully fetched {} API tokens", tokens.len());
+            HttpResponse::Ok().json(tokens)
+        }
+        Err(e) => {
+            error!("Failed to fetch API tokens: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch API tokens",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Creates an API token for the current user.
+///
+/// **Endpoint**: `POST /api/v1/tokens`
+///
+/// The clear token is only returned in this response; only its hash is stored.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "name": "Nightly report",
+///   "scopes": ["catalog:read", "loans:read"],
+///   "expires_in_days": 365
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with a `CreateApiTokenResponse`
+/// * `HttpResponse::BadRequest` if the name or scopes are missing or the lifetime is not between 1 and `MAX_TOKEN_LIFETIME_DAYS` days
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn create_api_token(
+    request: web::Json<CreateApiTokenRequest>,
+    user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    info!("POST /api/v1/tokens - Creating API token '{}' for {}", request.name, user.username);
+
+    let name = request.name.trim();
+    if name.is_empty() || request.scopes.is_empty() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": "A token needs a name and at least one scope"
+            }
+        }));
+    }
+    if request.expires_in_days.is_some_and(|days| !(1..=MAX_TOKEN_LIFETIME_DAYS).contains(&days)) {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": format!("expires_in_days must be between 1 and {}", MAX_TOKEN_LIFETIME_DAYS)
+            }
+        }));
+    }
+
+    let mut scopes = Vec::new();
+    for scope in &request.scopes {
+        if !scopes.contains(scope) {
+            scopes.push(*scope);
+        }
+    }
+    let scopes_column = scopes
+        .iter()
+        .map(|scope| scope.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let id = Uuid::new_v4();
+    let token = auth::generate_api_token();
+    let token_prefix: String = token.chars().take(TOKEN_PREFIX_LENGTH).collect();
+    let now = Utc::now();
+    let expires_at = request.expires_in_days.map(|days| now + Duration::days(days));
+
+    match sqlx::query(
+        "INSERT INTO api_tokens (id, user_id, name, token_hash, token_prefix, scopes, created_at, expires_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+    .bind(id.to_string())
+    .bind(&user.id)
+    .bind(name)
+    .bind(auth::hash_token(&token))
+    .bind(&token_prefix)
+    .bind(&scopes_column)
+    .bind(now.naive_utc())
+    .bind(expires_at.map(|e| e.naive_utc()))
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Successfully created API token {} ({})", name, token_prefix);
+            HttpResponse::Created().json(CreateApiTokenResponse {
+                token,
+                api_token: ApiToken {
+                    id,
+                    name: name.to_string(),
+                    token_prefix,
+                    scopes,
+                    created_at: now,
+                    last_used_at: None,
+                    expires_at,
+                    revoked_at: None,
+                },
+            })
+        }
+        Err(e) => {
+            error!("Failed to create API token: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to create API token",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Revokes an API token.
+///
+/// **Endpoint**: `DELETE /api/v1/tokens/{id}`
+///
+/// The token row is kept (with `revoked_at` set) so it still shows in the list.
+///
+/// # Business Rules
+///
+/// - Users can revoke their own tokens; admins can revoke any token.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` on success
+/// * `HttpResponse::NotFound` if no active token with this ID belongs to the caller
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn revoke_api_token(
+    id: web::Path<String>,
+    user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    info!("DELETE /api/v1/tokens/{} - Revoking API token", id);
+
+    let mut query_builder = sqlx::QueryBuilder::<sqlx::MySql>::new(
+        "UPDATE api_tokens SET revoked_at = UTC_TIMESTAMP() WHERE revoked_at IS NULL AND id = "
+    );
+    query_builder.push_bind(id.as_str());
+    if user.role < UserRole::Admin {
+        query_builder.push(" AND user_id = ");
+        query_builder.push_bind(&user.id);
+    }
+
+    match query_builder.build().execute(&data.db_pool).await {
+        Ok(result) if result.rows_affected() == 0 => HttpResponse::NotFound().json(serde_json::json!({
+            "error": {
+                "code": "NOT_FOUND",
+                "message": "API token not found"
+            }
+        })),
+        Ok(_) => {
+            info!("Successfully revoked API token: {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "API token revoked successfully"
+            }))
+        }
+        Err(e) => {
+            error!("Failed to revoke API token: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to revoke API token",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
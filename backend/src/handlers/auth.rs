@@ -0,0 +1,225 @@
+//! API handlers for logging in and out.
+//!
+//! This module provides the endpoints that open and close a session. The session
+//! token returned by `login` must accompany every other `/api/v1` call, either as
+//! `Authorization: Bearer <token>` or through the `rbibli_session` cookie.
+//!
+//! # Endpoints
+//!
+//! - `POST /api/v1/auth/login` - Check credentials and open a session (public)
+//! - `POST /api/v1/auth/logout` - Close the current session
+//! - `GET /api/v1/auth/me` - Return the logged-in user
+
+use actix_web::cookie::{time, Cookie, SameSite};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use log::{info, warn, error};
+use sqlx::Row;
+
+use crate::auth::{self, AuthenticatedUser, SESSION_COOKIE};
+use crate::handlers::users::user_from_row;
+use crate::models::{LoginRequest, LoginResponse};
+use crate::AppState;
+
+/// Logs a user in and opens a new session.
+///
+/// **Endpoint**: `POST /api/v1/auth/login`
+///
+/// This is the only `/api/v1` route reachable without a session.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "username": "admin",
+///   "password": "secret"
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with a `LoginResponse` (token, expiration, user) and the session cookie
+/// * `HttpResponse::Unauthorized` (401) with code `INVALID_CREDENTIALS` if the username
+///   is unknown, the password is wrong or the account is disabled
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn login(
+    data: web::Data<AppState>,
+    req: web::Json<LoginRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/auth/login - Login attempt for user: {}", req.username);
+
+    let query = r#"
+        SELECT id, username, display_name, role, borrower_id, is_active, password_hash, created_at, updated_at
+        FROM users
+        WHERE username = ?
+    "#;
+
+    let row = match sqlx::query(query)
+        .bind(&req.username)
+        .fetch_optional(&data.db_pool)
+        .await
+    {
+        Ok(row) => row,
+        Err(e) => {
+            error!("Database error while fetching user: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to log in",
+                    "details": { "error": e.to_string() }
+                }
+            }));
+        }
+    };
+
+    let user = row.and_then(|row| {
+        let password_hash: String = row.get("password_hash");
+        if !auth::verify_password(&req.password, &password_hash) {
+            return None;
+        }
+        user_from_row(&row)
+    });
+
+    let user = match user {
+        Some(user) if user.is_active => user,
+        _ => {
+            warn!("Failed login for user: {}", req.username);
+            return HttpResponse::Unauthorized().json(serde_json::json!({
+                "error": {
+                    "code": "INVALID_CREDENTIALS",
+                    "message": "Invalid username or password"
+                }
+            }));
+        }
+    };
+
+    let user_id = user.id.to_string();
+    let ttl_hours = data.auth.session_ttl_hours;
+    let (token, expires_at) = match auth::create_session(&data.db_pool, &user_id, ttl_hours).await {
+        Ok(session) => session,
+        Err(e) => {
+            error!("Failed to create session: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to create session",
+                    "details": { "error": e.to_string() }
+                }
+            }));
+        }
+    };
+
+    if let Err(e) = sqlx::query("UPDATE users SET last_login_at = NOW() WHERE id = ?")
+        .bind(&user_id)
+        .execute(&data.db_pool)
+        .await
+    {
+        warn!("Failed to record last login for {}: {}", user.username, e);
+    }
+
+    let cookie = Cookie::build(SESSION_COOKIE, token.clone())
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Strict)
+        .max_age(time::Duration::hours(ttl_hours))
+        .finish();
+
+    info!("User {} logged in", user.username);
+    HttpResponse::Ok().cookie(cookie).json(LoginResponse {
+        token,
+        expires_at: expires_at.timestamp(),
+        user,
+    })
+}
+
+/// Logs the current user out by deleting the session.
+///
+/// **Endpoint**: `POST /api/v1/auth/logout`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` and an expired session cookie
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn logout(
+    data: web::Data<AppState>,
+    http_req: HttpRequest,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("POST /api/v1/auth/logout - Logging out user: {}", user.username);
+
+    if let Some(token) = auth::token_from_request(&http_req)
+        && let Err(e) = sqlx::query("DELETE FROM user_sessions WHERE token_hash = ?")
+            .bind(auth::hash_token(&token))
+            .execute(&data.db_pool)
+            .await
+    {
+        error!("Failed to delete session: {}", e);
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": {
+                "code": "DATABASE_ERROR",
+                "message": "Failed to log out",
+                "details": { "error": e.to_string() }
+            }
+        }));
+    }
+
+    let mut cookie = Cookie::build(SESSION_COOKIE, "").path("/").finish();
+    cookie.make_removal();
+
+    HttpResponse::Ok().cookie(cookie).json(serde_json::json!({
+        "message": "Logged out successfully"
+    }))
+}
+
+/// Returns the currently logged-in user.
+///
+/// **Endpoint**: `GET /api/v1/auth/me`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with the `User` object
+/// * `HttpResponse::NotFound` if the user was deleted meanwhile
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn me(
+    data: web::Data<AppState>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/auth/me - Fetching current user: {}", user.username);
+
+    let query = r#"
+        SELECT id, username, display_name, role, borrower_id, is_active, created_at, updated_at
+        FROM users
+        WHERE id = ?
+    "#;
+
+    match sqlx::query(query)
+        .bind(&user.id)
+        .fetch_optional(&data.db_pool)
+        .await
+    {
+        Ok(Some(row)) => match user_from_row(&row) {
+            Some(user) => HttpResponse::Ok().json(user),
+            None => HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "INTERNAL_ERROR",
+                    "message": "Failed to parse user"
+                }
+            })),
+        },
+        Ok(None) => HttpResponse::NotFound().json(serde_json::json!({
+            "error": {
+                "code": "NOT_FOUND",
+                "message": "User not found"
+            }
+        })),
+        Err(e) => {
+            error!("Database error while fetching current user: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch user",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
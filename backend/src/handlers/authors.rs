@@ -45,6 +45,8 @@ pub async fn list_authors(data: web::Data<AppState>) -> impl Responder {
             COUNT(ta.id) as title_count
         FROM authors a
         LEFT JOIN title_authors ta ON a.id = ta.author_id
+            AND EXISTS (SELECT 1 FROM titles t WHERE t.id = ta.title_id AND t.deleted_at IS NULL)
+        WHERE a.deleted_at IS NULL
         GROUP BY a.id
         ORDER BY a.last_name ASC, a.first_name ASC
     "#;
@@ -150,7 +152,7 @@ pub async fn get_author(
         SELECT id, first_name, last_name, biography, birth_date, death_date,
                nationality, website_url, created_at, updated_at
         FROM authors
-        WHERE id = ?
+        WHERE id = ? AND deleted_at IS NULL
     "#;
 
     match sqlx::query(query)
@@ -355,7 +357,7 @@ pub async fn update_author(
     }
 
     query.push_str(&updates.join(", "));
-    query.push_str(", updated_at = NOW() WHERE id = ?");
+    query.push_str(", updated_at = NOW() WHERE id = ? AND deleted_at IS NULL");
 
     // Build query with parameters
     let mut sql_query = sqlx::query(&query);
@@ -418,12 +420,13 @@ pub async fn update_author(
     }
 }
 
-/// Deletes an author.
+/// Moves an author to the trash.
 ///
 /// **Endpoint**: `DELETE /api/v1/authors/{id}`
 ///
-/// Removes an author record. Note that this may fail or cascade depending on foreign key constraints
-/// with `title_authors` (though usually handled by ON DELETE CASCADE or similar logic if configured).
+/// The author is only marked with `deleted_at`, so their links to titles are kept and come
+/// back if the author is restored. Purging the author from the trash removes the
+/// `title_authors` rows through `ON DELETE CASCADE`.
 ///
 /// # Arguments
 ///
@@ -433,7 +436,7 @@ pub async fn update_author(
 /// # Returns
 ///
 /// * `HttpResponse::Ok` on success
-/// * `HttpResponse::NotFound` if author does not exist
+/// * `HttpResponse::NotFound` if author does not exist or is already in the trash
 /// * `HttpResponse::BadRequest` if UUID format is invalid
 /// * `HttpResponse::InternalServerError` if database operation fails
 pub async fn delete_author(
@@ -454,7 +457,7 @@ pub async fn delete_author(
         }));
     }
 
-    let query = "DELETE FROM authors WHERE id = ?";
+    let query = "UPDATE authors SET deleted_at = UTC_TIMESTAMP() WHERE id = ? AND deleted_at IS NULL";
 
     match sqlx::query(query)
         .bind(&author_id)
@@ -471,9 +474,9 @@ pub async fn delete_author(
                     }
                 }))
             } else {
-                info!("Successfully deleted author: {}", author_id);
+                info!("Moved author {} to the trash", author_id);
                 HttpResponse::Ok().json(serde_json::json!({
-                    "message": "Author deleted successfully"
+                    "message": "Author moved to the trash"
                 }))
             }
         }
@@ -35,6 +35,7 @@ pub async fn list_borrowers(data: web::Data<AppState>) -> impl Responder {
             COALESCE((SELECT COUNT(*) FROM loans WHERE borrower_id = b.id AND status = 'active'), 0) as active_loan_count
         FROM borrowers b
         LEFT JOIN borrower_groups g ON b.group_id = g.id
+        WHERE b.deleted_at IS NULL
         ORDER BY b.name
     ";
 
@@ -201,7 +202,7 @@ pub async fn update_borrower(
     query_builder.push(updates.join(", "));
     query_builder.push(" WHERE id = '");
     query_builder.push(id.as_str());
-    query_builder.push("'");
+    query_builder.push("' AND deleted_at IS NULL");
 
     match query_builder.build().execute(&data.db_pool).await {
         Ok(result) => {
@@ -223,11 +224,11 @@ pub async fn update_borrower(
     }
 }
 
-/// Deletes a borrower.
+/// Moves a borrower to the trash.
 ///
 /// **Endpoint**: `DELETE /api/v1/borrowers/{id}`
 ///
-/// Removes a borrower record.
+/// The borrower is only marked with `deleted_at` and can be restored from the trash.
 ///
 /// # Business Rules
 ///
@@ -281,7 +282,7 @@ pub async fn delete_borrower(
     }
 
     // All checks passed - proceed with deletion
-    match sqlx::query("DELETE FROM borrowers WHERE id = ?")
+    match sqlx::query("UPDATE borrowers SET deleted_at = UTC_TIMESTAMP() WHERE id = ? AND deleted_at IS NULL")
         .bind(id.as_str())
         .execute(&data.db_pool)
         .await
@@ -292,9 +293,9 @@ pub async fn delete_borrower(
                     "error": "Borrower not found"
                 }))
             } else {
-                info!("Successfully deleted borrower: {}", id);
+                info!("Moved borrower {} to the trash", id);
                 HttpResponse::Ok().json(serde_json::json!({
-                    "message": "Borrower deleted successfully"
+                    "message": "Borrower moved to the trash"
                 }))
             }
         }
@@ -0,0 +1,592 @@
+//! API handlers for bulk edits of titles and volumes.
+//!
+//! # Endpoints
+//!
+//! - `POST /api/v1/titles/bulk` - Set the genre, series or language of many titles, add or remove tags
+//! - `POST /api/v1/volumes/bulk` - Set the location or condition of many volumes
+//!
+//! Items are selected by ID, with the title search filters (see
+//! [`TitleSearchParams`](crate::models::TitleSearchParams)), or both. The whole
+//! batch runs in a single transaction and the response reports the outcome of
+//! every item. With `dry_run` the same report is computed and the transaction
+//! is rolled back. Title changes are recorded in the revision history.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::AuthenticatedUser;
+use crate::handlers::title_revisions::{fetch_title_snapshot, record_revision, snapshot_changes, snapshot_field};
+use crate::handlers::titles::{
+    check_genres_exist, dedup_genre_ids, parse_genre_ids, replace_title_genres, search_filters, search_title_ids,
+    invalid_language_response,
+};
+use crate::models::{
+    BulkEditReport, BulkItemResult, BulkItemStatus, BulkTitleEditRequest, BulkTitlePatch, BulkVolumeEditRequest,
+    BulkVolumePatch, TitleFieldChange, TitleSearchParams, VolumeCondition,
+};
+use crate::AppState;
+use log::{info, warn, error};
+use shared::languages;
+use shared::models::tags::{normalize_tag_name, MAX_TAG_LENGTH};
+use sqlx::{MySqlConnection, MySqlPool, Row};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Maximum number of titles or volumes changed by one bulk edit.
+const MAX_BULK_ITEMS: usize = 1000;
+
+/// A title patch checked against the database.
+struct TitlePatch {
+    genre_id: Option<String>,
+    /// `Some(None)` takes the titles out of their series
+    series_id: Option<Option<String>>,
+    language: Option<String>,
+    /// Normalized names of the tags to add and to remove
+    add_tags: Vec<String>,
+    remove_tags: Vec<String>,
+}
+
+/// A volume patch checked against the database.
+struct VolumePatch {
+    /// `Some(None)` clears the location
+    location_id: Option<Option<String>>,
+    condition: Option<VolumeCondition>,
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn validation_error(message: &str) -> HttpResponse {
+    warn!("Rejected bulk edit: {}", message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": { "code": "VALIDATION_ERROR", "message": message }
+    }))
+}
+
+fn no_updates_response() -> HttpResponse {
+    warn!("Rejected bulk edit without changes");
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": { "code": "NO_UPDATES", "message": "The patch does not change anything" }
+    }))
+}
+
+fn invalid_tag_response(name: &str) -> HttpResponse {
+    warn!("Rejected invalid tag name '{}'", name);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "INVALID_TAG",
+            "message": format!("A tag name must contain 1 to {} characters", MAX_TAG_LENGTH)
+        }
+    }))
+}
+
+/// Normalizes a list of tag names, dropping duplicates, or returns the first invalid name.
+fn normalize_tag_names(names: &[String]) -> Result<Vec<String>, &str> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::new();
+    for name in names {
+        let Some(tag) = normalize_tag_name(name) else {
+            return Err(name);
+        };
+        if seen.insert(tag.to_lowercase()) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+/// Checks a title patch, or builds the 400 response explaining what is wrong with it.
+async fn validate_title_patch(pool: &MySqlPool, patch: BulkTitlePatch) -> Result<TitlePatch, HttpResponse> {
+    let genre_id = patch.genre_id.map(|id| id.trim().to_string());
+    if let Some(ref genre_id) = genre_id {
+        check_genres_exist(pool, std::slice::from_ref(genre_id)).await?;
+    }
+
+    let series_id = match patch.series_id.map(|id| id.trim().to_string()) {
+        None => None,
+        Some(id) if id.is_empty() => Some(None),
+        Some(id) => {
+            match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM series WHERE id = ?")
+                .bind(&id)
+                .fetch_one(pool)
+                .await
+            {
+                Ok(0) => {
+                    warn!("Rejected unknown series {}", id);
+                    return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                        "error": { "code": "INVALID_SERIES", "message": "The series does not exist" }
+                    })));
+                }
+                Ok(_) => Some(Some(id)),
+                Err(e) => return Err(database_error("Failed to check series", e)),
+            }
+        }
+    };
+
+    let language = match patch.language {
+        Some(language) => Some(
+            languages::normalize(&language)
+                .map_err(|e| invalid_language_response("language", &language, &e))?,
+        ),
+        None => None,
+    };
+
+    let add_tags = normalize_tag_names(&patch.add_tags).map_err(invalid_tag_response)?;
+    let remove_tags = normalize_tag_names(&patch.remove_tags).map_err(invalid_tag_response)?;
+    if add_tags.iter().any(|tag| remove_tags.iter().any(|other| other.to_lowercase() == tag.to_lowercase())) {
+        return Err(validation_error("A tag cannot be both added and removed"));
+    }
+
+    if genre_id.is_none() && series_id.is_none() && language.is_none() && add_tags.is_empty() && remove_tags.is_empty() {
+        return Err(no_updates_response());
+    }
+    Ok(TitlePatch { genre_id, series_id, language, add_tags, remove_tags })
+}
+
+/// Checks a volume patch, or builds the 400 response explaining what is wrong with it.
+async fn validate_volume_patch(pool: &MySqlPool, patch: BulkVolumePatch) -> Result<VolumePatch, HttpResponse> {
+    let location_id = match patch.location_id.map(|id| id.trim().to_string()) {
+        None => None,
+        Some(id) if id.is_empty() => Some(None),
+        Some(id) => {
+            match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM locations WHERE id = ?")
+                .bind(&id)
+                .fetch_one(pool)
+                .await
+            {
+                Ok(0) => {
+                    warn!("Rejected unknown location {}", id);
+                    return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                        "error": { "code": "INVALID_LOCATION", "message": "The location does not exist" }
+                    })));
+                }
+                Ok(_) => Some(Some(id)),
+                Err(e) => return Err(database_error("Failed to check location", e)),
+            }
+        }
+    };
+
+    if location_id.is_none() && patch.condition.is_none() {
+        return Err(no_updates_response());
+    }
+    Ok(VolumePatch { location_id, condition: patch.condition })
+}
+
+/// Appends the IDs not seen yet, keeping the order of first appearance.
+fn push_unique(ids: &mut Vec<String>, seen: &mut HashSet<String>, new_ids: impl IntoIterator<Item = String>) {
+    for id in new_ids {
+        let id = id.trim().to_string();
+        if !id.is_empty() && seen.insert(id.clone()) {
+            ids.push(id);
+        }
+    }
+}
+
+/// Validates the search filter of a bulk edit, returning the 400 response if it is invalid.
+fn filter_error(filter: &mut TitleSearchParams) -> Option<HttpResponse> {
+    filter.validate().err().map(|e| {
+        warn!("Invalid bulk edit filter: {}", e);
+        HttpResponse::BadRequest().json(serde_json::json!({
+            "error": { "code": "INVALID_PARAMETERS", "message": e }
+        }))
+    })
+}
+
+/// The 400 response for an empty selection or one larger than [`MAX_BULK_ITEMS`], if needed.
+fn selection_size_error(ids: &[String]) -> Option<HttpResponse> {
+    if ids.is_empty() {
+        return Some(validation_error("Provide IDs or a filter matching at least one item"));
+    }
+    if ids.len() > MAX_BULK_ITEMS {
+        warn!("Rejected bulk edit of {} items", ids.len());
+        return Some(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "TOO_MANY_ITEMS",
+                "message": format!("A bulk edit can change at most {} items", MAX_BULK_ITEMS),
+                "details": { "count": ids.len(), "max": MAX_BULK_ITEMS }
+            }
+        })));
+    }
+    None
+}
+
+/// The tag names of a title, sorted and comma-separated, as reported in the changes.
+async fn title_tag_names(conn: &mut MySqlConnection, title_id: &str) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, Option<String>>(
+        "SELECT GROUP_CONCAT(tg.name ORDER BY tg.name SEPARATOR ', ') \
+         FROM title_tags tt INNER JOIN tags tg ON tt.tag_id = tg.id WHERE tt.title_id = ?"
+    )
+    .bind(title_id)
+    .fetch_one(&mut *conn)
+    .await
+}
+
+/// Finds the IDs of the tags to add, creating the missing ones, and of the existing tags to remove.
+async fn resolve_tags(conn: &mut MySqlConnection, patch: &TitlePatch) -> Result<(Vec<String>, Vec<String>), sqlx::Error> {
+    let mut add_ids = Vec::new();
+    for name in &patch.add_tags {
+        sqlx::query("INSERT IGNORE INTO tags (id, name) VALUES (?, ?)")
+            .bind(Uuid::new_v4().to_string())
+            .bind(name)
+            .execute(&mut *conn)
+            .await?;
+        let id: String = sqlx::query_scalar("SELECT id FROM tags WHERE name = ?")
+            .bind(name)
+            .fetch_one(&mut *conn)
+            .await?;
+        add_ids.push(id);
+    }
+
+    let mut remove_ids = Vec::new();
+    for name in &patch.remove_tags {
+        let id: Option<String> = sqlx::query_scalar("SELECT id FROM tags WHERE name = ?")
+            .bind(name)
+            .fetch_optional(&mut *conn)
+            .await?;
+        remove_ids.extend(id);
+    }
+    Ok((add_ids, remove_ids))
+}
+
+/// Applies a patch to one title and records the revision.
+async fn apply_title_patch(
+    conn: &mut MySqlConnection,
+    title_id: &str,
+    patch: &TitlePatch,
+    tag_ids: &(Vec<String>, Vec<String>),
+    user_id: &str,
+) -> Result<BulkItemResult, sqlx::Error> {
+    let Some(before) = fetch_title_snapshot(conn, title_id).await? else {
+        return Ok(BulkItemResult {
+            id: title_id.to_string(),
+            label: None,
+            status: BulkItemStatus::NotFound,
+            changes: Vec::new(),
+        });
+    };
+    let tags_before = title_tag_names(conn, title_id).await?;
+
+    // The new genre becomes primary, in front of the title's other genres
+    let current_genres = parse_genre_ids(snapshot_field(&before, "genre_ids").map(str::to_string));
+    let genre_ids = patch.genre_id.as_ref().map(|genre_id| {
+        let mut ids = vec![genre_id.clone()];
+        ids.extend(current_genres.iter().cloned());
+        dedup_genre_ids(&ids)
+    }).filter(|ids| *ids != current_genres);
+
+    let mut update_parts = Vec::new();
+    if genre_ids.is_some() {
+        update_parts.push("genre_id = ?");
+    }
+    if patch.series_id.is_some() {
+        update_parts.push("series_id = ?");
+    }
+    if patch.language.is_some() {
+        update_parts.push("language = ?");
+    }
+    if !update_parts.is_empty() {
+        // updated_at only moves when a value actually changes (ON UPDATE CURRENT_TIMESTAMP)
+        let query = format!("UPDATE titles SET {} WHERE id = ?", update_parts.join(", "));
+        let mut query_builder = sqlx::query(&query);
+        if let Some(ref genre_ids) = genre_ids {
+            query_builder = query_builder.bind(genre_ids.first());
+        }
+        if let Some(ref series_id) = patch.series_id {
+            query_builder = query_builder.bind(series_id);
+        }
+        if let Some(ref language) = patch.language {
+            query_builder = query_builder.bind(language);
+        }
+        query_builder.bind(title_id).execute(&mut *conn).await?;
+    }
+    if let Some(ref genre_ids) = genre_ids {
+        replace_title_genres(conn, title_id, genre_ids).await?;
+    }
+
+    let (add_ids, remove_ids) = tag_ids;
+    for tag_id in add_ids {
+        sqlx::query("INSERT IGNORE INTO title_tags (title_id, tag_id) VALUES (?, ?)")
+            .bind(title_id)
+            .bind(tag_id)
+            .execute(&mut *conn)
+            .await?;
+    }
+    if !remove_ids.is_empty() {
+        let query = format!(
+            "DELETE FROM title_tags WHERE title_id = ? AND tag_id IN ({})",
+            vec!["?"; remove_ids.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query(&query).bind(title_id);
+        for tag_id in remove_ids {
+            query_builder = query_builder.bind(tag_id);
+        }
+        query_builder.execute(&mut *conn).await?;
+    }
+
+    let after = fetch_title_snapshot(conn, title_id).await?.unwrap_or_else(|| before.clone());
+    record_revision(conn, title_id, user_id, &before, &after).await?;
+    let mut changes = snapshot_changes(&before, &after);
+    let tags_after = title_tag_names(conn, title_id).await?;
+    if tags_after != tags_before {
+        changes.push(TitleFieldChange {
+            field: "tags".to_string(),
+            old_value: tags_before,
+            new_value: tags_after,
+        });
+    }
+
+    Ok(BulkItemResult {
+        id: title_id.to_string(),
+        label: snapshot_field(&after, "title").map(str::to_string),
+        status: if changes.is_empty() { BulkItemStatus::Unchanged } else { BulkItemStatus::Updated },
+        changes,
+    })
+}
+
+/// Applies a patch to one volume.
+async fn apply_volume_patch(
+    conn: &mut MySqlConnection,
+    volume_id: &str,
+    patch: &VolumePatch,
+) -> Result<BulkItemResult, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT v.barcode, v.location_id, v.`condition` FROM volumes v \
+         INNER JOIN titles t ON v.title_id = t.id \
+         WHERE v.id = ? AND t.deleted_at IS NULL FOR UPDATE"
+    )
+    .bind(volume_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let Some(row) = row else {
+        return Ok(BulkItemResult {
+            id: volume_id.to_string(),
+            label: None,
+            status: BulkItemStatus::NotFound,
+            changes: Vec::new(),
+        });
+    };
+
+    let mut changes = Vec::new();
+    let location_id: Option<String> = row.get("location_id");
+    if let Some(ref new_location_id) = patch.location_id
+        && *new_location_id != location_id
+    {
+        changes.push(TitleFieldChange {
+            field: "location_id".to_string(),
+            old_value: location_id,
+            new_value: new_location_id.clone(),
+        });
+    }
+    let condition: String = row.get("condition");
+    if let Some(ref new_condition) = patch.condition {
+        // The column stores the lowercase name of the condition
+        let new_condition = new_condition.to_string().to_lowercase();
+        if new_condition != condition {
+            changes.push(TitleFieldChange {
+                field: "condition".to_string(),
+                old_value: Some(condition),
+                new_value: Some(new_condition),
+            });
+        }
+    }
+
+    if !changes.is_empty() {
+        let update_parts: Vec<String> = changes
+            .iter()
+            .map(|change| match change.field.as_str() {
+                "condition" => "`condition` = ?".to_string(),
+                field => format!("{} = ?", field),
+            })
+            .collect();
+        let query = format!("UPDATE volumes SET {} WHERE id = ?", update_parts.join(", "));
+        let mut query_builder = sqlx::query(&query);
+        for change in &changes {
+            query_builder = query_builder.bind(&change.new_value);
+        }
+        query_builder.bind(volume_id).execute(&mut *conn).await?;
+    }
+
+    Ok(BulkItemResult {
+        id: volume_id.to_string(),
+        label: row.get("barcode"),
+        status: if changes.is_empty() { BulkItemStatus::Unchanged } else { BulkItemStatus::Updated },
+        changes,
+    })
+}
+
+/// Applies the same changes to many titles in a single transaction.
+///
+/// **Endpoint**: `POST /api/v1/titles/bulk`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "title_ids": ["title-uuid-1", "title-uuid-2"],
+///   "filter": { "series_id": "series-uuid" },
+///   "patch": { "genre_id": "genre-uuid", "add_tags": ["signed"] },
+///   "dry_run": true
+/// }
+/// ```
+///
+/// The patch may set `genre_id` (the new primary genre, other genres are kept),
+/// `series_id` (`""` to clear) and `language`, and list tag names to add (created
+/// if needed) or remove.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) with a `BulkEditReport`
+/// * `HttpResponse::BadRequest` (400) if the patch, the filter or the selection is invalid
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails; nothing is saved
+pub async fn bulk_edit_titles(
+    data: web::Data<AppState>,
+    req: web::Json<BulkTitleEditRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    let BulkTitleEditRequest { title_ids, filter, patch, dry_run } = req.into_inner();
+    info!("POST /api/v1/titles/bulk - Bulk edit by {} (dry run: {})", user.username, dry_run);
+
+    let patch = match validate_title_patch(&data.db_pool, patch).await {
+        Ok(patch) => patch,
+        Err(response) => return response,
+    };
+
+    let mut ids = Vec::new();
+    let mut seen = HashSet::new();
+    push_unique(&mut ids, &mut seen, title_ids);
+    if let Some(mut filter) = filter {
+        if let Some(response) = filter_error(&mut filter) {
+            return response;
+        }
+        match search_title_ids(&data.db_pool, &filter, &user.id).await {
+            Ok(matching) => push_unique(&mut ids, &mut seen, matching),
+            Err(response) => return response,
+        }
+    }
+    if let Some(response) = selection_size_error(&ids) {
+        return response;
+    }
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to start transaction", e),
+    };
+    let tag_ids = match resolve_tags(&mut tx, &patch).await {
+        Ok(tag_ids) => tag_ids,
+        Err(e) => return database_error("Failed to resolve tags", e),
+    };
+    let mut items = Vec::with_capacity(ids.len());
+    for title_id in &ids {
+        match apply_title_patch(&mut tx, title_id, &patch, &tag_ids, &user.id).await {
+            Ok(item) => items.push(item),
+            Err(e) => return database_error(&format!("Failed to update title {}", title_id), e),
+        }
+    }
+
+    let result = if dry_run { tx.rollback().await } else { tx.commit().await };
+    if let Err(e) = result {
+        return database_error("Failed to finish bulk edit", e);
+    }
+
+    let report = BulkEditReport::new(dry_run, items);
+    info!(
+        "Bulk edit of {} titles: {} updated, {} unchanged, {} not found (dry run: {})",
+        report.matched, report.updated, report.unchanged, report.not_found, dry_run
+    );
+    HttpResponse::Ok().json(report)
+}
+
+/// Applies the same changes to many volumes in a single transaction.
+///
+/// **Endpoint**: `POST /api/v1/volumes/bulk`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "volume_ids": ["volume-uuid-1"],
+///   "filter": { "genre_id": "genre-uuid" },
+///   "patch": { "location_id": "location-uuid", "condition": "Good" },
+///   "dry_run": false
+/// }
+/// ```
+///
+/// `filter` selects every volume of the matching titles. `location_id` may be `""`
+/// to clear the location.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) with a `BulkEditReport`
+/// * `HttpResponse::BadRequest` (400) if the patch, the filter or the selection is invalid
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails; nothing is saved
+pub async fn bulk_edit_volumes(
+    data: web::Data<AppState>,
+    req: web::Json<BulkVolumeEditRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    let BulkVolumeEditRequest { volume_ids, filter, patch, dry_run } = req.into_inner();
+    info!("POST /api/v1/volumes/bulk - Bulk edit by {} (dry run: {})", user.username, dry_run);
+
+    let patch = match validate_volume_patch(&data.db_pool, patch).await {
+        Ok(patch) => patch,
+        Err(response) => return response,
+    };
+
+    let mut ids = Vec::new();
+    let mut seen = HashSet::new();
+    push_unique(&mut ids, &mut seen, volume_ids);
+    if let Some(mut filter) = filter {
+        if let Some(response) = filter_error(&mut filter) {
+            return response;
+        }
+        let filters = match search_filters(&data.db_pool, &filter, &user.id).await {
+            Ok(filters) => filters,
+            Err(response) => return response,
+        };
+        let query = format!(
+            "SELECT id FROM volumes WHERE title_id IN ({}) ORDER BY title_id, copy_number",
+            filters.id_query()
+        );
+        let mut query_builder = sqlx::query_scalar::<_, String>(&query);
+        for value in &filters.bind_values {
+            query_builder = query_builder.bind(value);
+        }
+        match query_builder.fetch_all(&data.db_pool).await {
+            Ok(matching) => push_unique(&mut ids, &mut seen, matching),
+            Err(e) => return database_error("Failed to search volumes", e),
+        }
+    }
+    if let Some(response) = selection_size_error(&ids) {
+        return response;
+    }
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to start transaction", e),
+    };
+    let mut items = Vec::with_capacity(ids.len());
+    for volume_id in &ids {
+        match apply_volume_patch(&mut tx, volume_id, &patch).await {
+            Ok(item) => items.push(item),
+            Err(e) => return database_error(&format!("Failed to update volume {}", volume_id), e),
+        }
+    }
+
+    let result = if dry_run { tx.rollback().await } else { tx.commit().await };
+    if let Err(e) = result {
+        return database_error("Failed to finish bulk edit", e);
+    }
+
+    let report = BulkEditReport::new(dry_run, items);
+    info!(
+        "Bulk edit of {} volumes: {} updated, {} unchanged, {} not found (dry run: {})",
+        report.matched, report.updated, report.unchanged, report.not_found, dry_run
+    );
+    HttpResponse::Ok().json(report)
+}
@@ -0,0 +1,484 @@
+//! API handlers for user-defined custom fields on titles and volumes.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/custom-fields` - List field definitions, optionally for one scope
+//! - `GET /api/v1/custom-fields/{id}` - Get a field definition
+//! - `POST /api/v1/custom-fields` - Define a field (admin)
+//! - `PUT /api/v1/custom-fields/{id}` - Rename a field or change its options (admin)
+//! - `DELETE /api/v1/custom-fields/{id}` - Delete a field and all its values (admin)
+//!
+//! Values are not managed here: they are sent in the `custom_fields` map of the
+//! title and volume create/update requests, validated with
+//! [`validate_custom_values`] and stored with [`save_custom_values`].
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{
+    CreateCustomFieldRequest, CustomField, CustomFieldListParams, CustomFieldScope, CustomFieldType,
+    UpdateCustomFieldRequest,
+};
+use crate::AppState;
+use log::{info, warn, error};
+use shared::models::custom_fields::{normalize_options, MAX_CUSTOM_FIELD_NAME_LENGTH};
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySqlConnection, MySqlPool, Row};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+const SELECT_CUSTOM_FIELDS: &str =
+    "SELECT id, name, field_type, scope, options, created_at, updated_at FROM custom_fields";
+
+fn custom_field_from_row(row: &MySqlRow) -> Option<CustomField> {
+    let id: String = row.get("id");
+    let field_type: String = row.get("field_type");
+    let scope: String = row.get("scope");
+    let options: Option<String> = row.get("options");
+
+    Some(CustomField {
+        id: Uuid::parse_str(&id).ok()?,
+        name: row.get("name"),
+        field_type: CustomFieldType::from_db(&field_type)?,
+        scope: CustomFieldScope::from_db(&scope)?,
+        options: options
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+/// The table holding the values of a scope and its record column.
+fn values_table(scope: CustomFieldScope) -> (&'static str, &'static str) {
+    match scope {
+        CustomFieldScope::Title => ("title_custom_values", "title_id"),
+        CustomFieldScope::Volume => ("volume_custom_values", "volume_id"),
+    }
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn invalid_field_response(message: String) -> HttpResponse {
+    warn!("Rejected custom field: {}", message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": { "code": "INVALID_CUSTOM_FIELD", "message": message }
+    }))
+}
+
+fn invalid_value_response(message: String) -> HttpResponse {
+    warn!("Rejected custom field value: {}", message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": { "code": "INVALID_CUSTOM_FIELD_VALUE", "message": message }
+    }))
+}
+
+fn duplicate_field_response(scope: CustomFieldScope, name: &str) -> HttpResponse {
+    warn!("Custom field '{}' already exists for {}s", name, scope);
+    HttpResponse::Conflict().json(serde_json::json!({
+        "error": {
+            "code": "DUPLICATE_CUSTOM_FIELD",
+            "message": format!("A {} field named '{}' already exists", scope, name)
+        }
+    }))
+}
+
+/// Trims a field name, or explains why it is invalid.
+fn validate_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_CUSTOM_FIELD_NAME_LENGTH {
+        return Err(format!(
+            "A custom field name must contain 1 to {} characters",
+            MAX_CUSTOM_FIELD_NAME_LENGTH
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Cleans the options of a field, checking that only `enum` fields have some.
+fn validate_options(field_type: CustomFieldType, options: &[String]) -> Result<Vec<String>, String> {
+    let options = normalize_options(options);
+    match field_type {
+        CustomFieldType::Enum if options.is_empty() => {
+            Err("An enum field needs at least one option".to_string())
+        }
+        CustomFieldType::Enum => Ok(options),
+        _ if !options.is_empty() => Err(format!(
+            "Only enum fields have options, not {} fields",
+            field_type
+        )),
+        _ => Ok(options),
+    }
+}
+
+/// Loads a field definition.
+pub async fn fetch_custom_field(pool: &MySqlPool, id: &str) -> Result<Option<CustomField>, sqlx::Error> {
+    let row = sqlx::query(&format!("{} WHERE id = ?", SELECT_CUSTOM_FIELDS))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row.as_ref().and_then(custom_field_from_row))
+}
+
+/// Parses the custom field values of a record, aggregated by the query as a
+/// JSON object (`JSON_OBJECTAGG(field_id, value)`).
+pub fn parse_custom_values(json: Option<String>) -> BTreeMap<String, String> {
+    json.and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Validates the custom field values of a title or volume request.
+///
+/// Every key must be the UUID of a field of `scope`, and every value must fit
+/// the field type (see `CustomField::normalize_value`).
+///
+/// # Returns
+///
+/// * `Ok(values)` - The normalized values by field UUID, `None` for blank values to clear
+/// * `Err(HttpResponse)` - 400 `INVALID_CUSTOM_FIELD_VALUE`, or 500 if the fields cannot be loaded
+pub async fn validate_custom_values(
+    pool: &MySqlPool,
+    scope: CustomFieldScope,
+    values: &BTreeMap<String, String>,
+) -> Result<Vec<(String, Option<String>)>, HttpResponse> {
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = format!(
+        "{} WHERE id IN ({})",
+        SELECT_CUSTOM_FIELDS,
+        vec!["?"; values.len()].join(", ")
+    );
+    let mut query_builder = sqlx::query(&query);
+    for field_id in values.keys() {
+        query_builder = query_builder.bind(field_id);
+    }
+    let fields: Vec<CustomField> = match query_builder.fetch_all(pool).await {
+        Ok(rows) => rows.iter().filter_map(custom_field_from_row).collect(),
+        Err(e) => return Err(database_error("Failed to fetch custom fields", e)),
+    };
+
+    let mut normalized = Vec::with_capacity(values.len());
+    for (field_id, value) in values {
+        let Some(field) = fields
+            .iter()
+            .find(|field| field.id.to_string() == *field_id && field.scope == scope)
+        else {
+            return Err(invalid_value_response(format!("Unknown {} custom field {}", scope, field_id)));
+        };
+        match field.normalize_value(value) {
+            Ok(value) => normalized.push((field_id.clone(), value)),
+            Err(message) => return Err(invalid_value_response(format!("{}: {}", field.name, message))),
+        }
+    }
+    Ok(normalized)
+}
+
+/// Stores the custom field values of a title or volume, as returned by
+/// [`validate_custom_values`]. Fields not listed keep their value.
+pub async fn save_custom_values(
+    conn: &mut MySqlConnection,
+    scope: CustomFieldScope,
+    record_id: &str,
+    values: &[(String, Option<String>)],
+) -> Result<(), sqlx::Error> {
+    let (table, record_column) = values_table(scope);
+    for (field_id, value) in values {
+        match value {
+            Some(value) => {
+                sqlx::query(&format!(
+                    "INSERT INTO {table} ({record_column}, field_id, value) VALUES (?, ?, ?) \
+                     ON DUPLICATE KEY UPDATE value = VALUES(value)"
+                ))
+                .bind(record_id)
+                .bind(field_id)
+                .bind(value)
+                .execute(&mut *conn)
+                .await?;
+            }
+            None => {
+                sqlx::query(&format!("DELETE FROM {table} WHERE {record_column} = ? AND field_id = ?"))
+                    .bind(record_id)
+                    .bind(field_id)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Lists the custom field definitions.
+///
+/// **Endpoint**: `GET /api/v1/custom-fields`
+///
+/// # Query Parameters
+///
+/// * `scope` - `title` or `volume` to list only the fields of that scope
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `CustomField` objects, ordered by scope and name
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_custom_fields(
+    data: web::Data<AppState>,
+    params: web::Query<CustomFieldListParams>,
+) -> impl Responder {
+    info!("GET /api/v1/custom-fields - Fetching custom fields (scope: {:?})", params.scope);
+
+    let result = match params.scope {
+        Some(scope) => {
+            sqlx::query(&format!("{} WHERE scope = ? ORDER BY name", SELECT_CUSTOM_FIELDS))
+                .bind(scope.to_string())
+                .fetch_all(&data.db_pool)
+                .await
+        }
+        None => {
+            sqlx::query(&format!("{} ORDER BY scope, name", SELECT_CUSTOM_FIELDS))
+                .fetch_all(&data.db_pool)
+                .await
+        }
+    };
+
+    match result {
+        Ok(rows) => {
+            let fields: Vec<CustomField> = rows.iter().filter_map(custom_field_from_row).collect();
+            info!("Found {} custom fields", fields.len());
+            HttpResponse::Ok().json(fields)
+        }
+        Err(e) => database_error("Failed to fetch custom fields", e),
+    }
+}
+
+/// Retrieves a custom field definition.
+///
+/// **Endpoint**: `GET /api/v1/custom-fields/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with the `CustomField` object
+/// * `HttpResponse::NotFound` if the field does not exist
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn get_custom_field(data: web::Data<AppState>, id: web::Path<String>) -> impl Responder {
+    info!("GET /api/v1/custom-fields/{} - Fetching custom field", id);
+
+    match fetch_custom_field(&data.db_pool, &id).await {
+        Ok(Some(field)) => HttpResponse::Ok().json(field),
+        Ok(None) => {
+            warn!("Custom field {} not found", id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Custom field not found" }
+            }))
+        }
+        Err(e) => database_error("Failed to fetch custom field", e),
+    }
+}
+
+/// Defines a new custom field.
+///
+/// **Endpoint**: `POST /api/v1/custom-fields`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "name": "Reading level",
+///   "field_type": "enum",
+///   "scope": "title",
+///   "options": ["A1", "A2", "B1", "B2"]
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new field's UUID
+/// * `HttpResponse::BadRequest` (400) if the name is invalid, an enum field has no
+///   options or another type has some
+/// * `HttpResponse::Conflict` (409) if a field of the same scope already has this name
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn create_custom_field(
+    data: web::Data<AppState>,
+    req: web::Json<CreateCustomFieldRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/custom-fields - Creating {} field '{}' for {}s", req.field_type, req.name, req.scope);
+
+    let name = match validate_name(&req.name) {
+        Ok(name) => name,
+        Err(message) => return invalid_field_response(message),
+    };
+    let options = match validate_options(req.field_type, &req.options) {
+        Ok(options) => options,
+        Err(message) => return invalid_field_response(message),
+    };
+
+    let new_id = Uuid::new_v4();
+    let query = r#"
+        INSERT INTO custom_fields (id, name, field_type, scope, options, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, NOW(), NOW())
+    "#;
+
+    match sqlx::query(query)
+        .bind(new_id.to_string())
+        .bind(&name)
+        .bind(req.field_type.to_string())
+        .bind(req.scope.to_string())
+        .bind((!options.is_empty()).then(|| serde_json::to_string(&options).unwrap_or_default()))
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully created custom field {}", new_id);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": new_id.to_string(),
+                "message": "Custom field created successfully"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            duplicate_field_response(req.scope, &name)
+        }
+        Err(e) => database_error("Failed to create custom field", e),
+    }
+}
+
+/// Renames a custom field or replaces the options of an enum field.
+///
+/// **Endpoint**: `PUT /api/v1/custom-fields/{id}`
+///
+/// The type and scope of a field are fixed at creation. Options still used by
+/// a title or volume cannot be removed.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "name": "CEFR level",
+///   "options": ["A1", "A2", "B1", "B2", "C1"]
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the field was updated
+/// * `HttpResponse::BadRequest` (400) if no field was provided, the name is invalid
+///   or options are given for a field that is not an enum
+/// * `HttpResponse::NotFound` (404) if the field does not exist
+/// * `HttpResponse::Conflict` (409) if the name is taken or a removed option is in use
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn update_custom_field(
+    data: web::Data<AppState>,
+    id: web::Path<String>,
+    req: web::Json<UpdateCustomFieldRequest>,
+) -> impl Responder {
+    info!("PUT /api/v1/custom-fields/{} - Updating custom field", id);
+
+    if req.name.is_none() && req.options.is_none() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "NO_UPDATES",
+                "message": "No fields provided for update"
+            }
+        }));
+    }
+
+    let field = match fetch_custom_field(&data.db_pool, &id).await {
+        Ok(Some(field)) => field,
+        Ok(None) => {
+            warn!("Custom field {} not found", id);
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Custom field not found" }
+            }));
+        }
+        Err(e) => return database_error("Failed to fetch custom field", e),
+    };
+
+    let name = match req.name.as_deref().map(validate_name).transpose() {
+        Ok(name) => name.unwrap_or(field.name),
+        Err(message) => return invalid_field_response(message),
+    };
+    let options = match req.options.as_deref().map(|options| validate_options(field.field_type, options)).transpose() {
+        Ok(options) => options.unwrap_or(field.options),
+        Err(message) => return invalid_field_response(message),
+    };
+
+    // Values are stored in their option's spelling, so a removed option is an exact mismatch
+    if field.field_type == CustomFieldType::Enum {
+        let (table, _) = values_table(field.scope);
+        let used: Vec<String> = match sqlx::query_scalar(&format!("SELECT DISTINCT value FROM {table} WHERE field_id = ?"))
+            .bind(id.as_str())
+            .fetch_all(&data.db_pool)
+            .await
+        {
+            Ok(used) => used,
+            Err(e) => return database_error("Failed to check custom field values", e),
+        };
+        if let Some(missing) = used.iter().find(|value| !options.contains(value)) {
+            warn!("Option '{}' of custom field {} is still in use", missing, id);
+            return HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "OPTION_IN_USE",
+                    "message": format!("Option '{}' is still used and cannot be removed", missing)
+                }
+            }));
+        }
+    }
+
+    match sqlx::query("UPDATE custom_fields SET name = ?, options = ?, updated_at = NOW() WHERE id = ?")
+        .bind(&name)
+        .bind((!options.is_empty()).then(|| serde_json::to_string(&options).unwrap_or_default()))
+        .bind(id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully updated custom field {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Custom field updated successfully"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            duplicate_field_response(field.scope, &name)
+        }
+        Err(e) => database_error("Failed to update custom field", e),
+    }
+}
+
+/// Deletes a custom field and its values on every title or volume.
+///
+/// **Endpoint**: `DELETE /api/v1/custom-fields/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the field was deleted
+/// * `HttpResponse::NotFound` (404) if the field does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_custom_field(data: web::Data<AppState>, id: web::Path<String>) -> impl Responder {
+    info!("DELETE /api/v1/custom-fields/{} - Deleting custom field", id);
+
+    match sqlx::query("DELETE FROM custom_fields WHERE id = ?")
+        .bind(id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Custom field {} not found", id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Custom field not found" }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully deleted custom field {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Custom field deleted successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to delete custom field", e),
+    }
+}
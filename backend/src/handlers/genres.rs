@@ -2,13 +2,14 @@
 //!
 //! This module provides HTTP handlers for creating, reading, updating, and deleting
 //! literary genres. It also includes functionality to list genres with their associated
-//! title counts.
+//! title counts. Genres form a hierarchy through `parent_id` (e.g. "Urban Fantasy"
+//! under "Fantasy"), which can be browsed as a tree.
 
 use actix_web::{web, HttpResponse, Responder};
-use crate::models::{GenreWithTitleCount, CreateGenreRequest, UpdateGenreRequest};
+use crate::models::{GenreWithTitleCount, GenreTreeNode, CreateGenreRequest, UpdateGenreRequest};
 use crate::AppState;
 use log::{info, warn, error, debug};
-use sqlx::Row;
+use sqlx::{MySqlPool, Row};
 use uuid::Uuid;
 
 /// Lists all genres with their title counts.
@@ -16,7 +17,7 @@ use uuid::Uuid;
 /// **Endpoint**: `GET /api/v1/genres`
 ///
 /// Retrieves a list of all genres, ordered alphabetically by name.
-/// Includes a count of titles associated with each genre.
+/// Includes a count of titles associated with each genre, as primary or secondary genre.
 ///
 /// # Arguments
 ///
@@ -29,59 +30,176 @@ use uuid::Uuid;
 pub async fn list_genres(data: web::Data<AppState>) -> impl Responder {
     info!("GET /api/v1/genres - Fetching all genres with title counts");
 
+    match fetch_genres_with_counts(&data.db_pool).await {
+        Ok(genres) => {
+            info!("Successfully returning {} genres", genres.len());
+            HttpResponse::Ok().json(genres)
+        }
+        Err(e) => {
+            error!("Database error while fetching genres: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch genres",
+                    "details": {
+                        "error": e.to_string()
+                    }
+                }
+            }))
+        }
+    }
+}
+
+/// Loads all genres with the number of titles carrying each one.
+async fn fetch_genres_with_counts(pool: &MySqlPool) -> Result<Vec<GenreWithTitleCount>, sqlx::Error> {
     let query = r#"
         SELECT
             g.id,
             g.name,
             g.description,
+            g.parent_id,
             g.created_at,
             g.updated_at,
             COUNT(t.id) as title_count
         FROM genres g
-        LEFT JOIN titles t ON g.id = t.genre_id
-        GROUP BY g.id, g.name, g.description, g.created_at, g.updated_at
+        LEFT JOIN title_genres tg ON g.id = tg.genre_id
+        LEFT JOIN titles t ON tg.title_id = t.id AND t.deleted_at IS NULL
+        GROUP BY g.id, g.name, g.description, g.parent_id, g.created_at, g.updated_at
         ORDER BY g.name ASC
     "#;
 
     debug!("Executing query to fetch genres");
-    match sqlx::query(query)
-        .fetch_all(&data.db_pool)
+    let rows = sqlx::query(query).fetch_all(pool).await?;
+    debug!("Query successful, fetched {} rows", rows.len());
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            let id_str: String = row.get("id");
+            let id = match Uuid::parse_str(&id_str) {
+                Ok(uuid) => uuid,
+                Err(e) => {
+                    warn!("Failed to parse UUID '{}': {}", id_str, e);
+                    return None;
+                }
+            };
+
+            let created_at: chrono::NaiveDateTime = row.get("created_at");
+            let updated_at: chrono::NaiveDateTime = row.get("updated_at");
+
+            Some(GenreWithTitleCount {
+                genre: crate::models::Genre {
+                    id,
+                    name: row.get("name"),
+                    description: row.get("description"),
+                    parent_id: row.get("parent_id"),
+                    created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
+                    updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
+                },
+                title_count: row.get("title_count"),
+            })
+        })
+        .collect())
+}
+
+/// UUIDs of a genre and all its descendants.
+///
+/// Used by the title search so that filtering on "Fantasy" also finds
+/// "Urban Fantasy" titles. Returns just the genre itself if it has no children
+/// (or does not exist).
+pub async fn genre_with_descendants(pool: &MySqlPool, genre_id: &str) -> Result<Vec<String>, sqlx::Error> {
+    let query = r#"
+        WITH RECURSIVE descendants AS (
+            SELECT id FROM genres WHERE id = ?
+            UNION
+            SELECT g.id FROM genres g
+            INNER JOIN descendants d ON g.parent_id = d.id
+        )
+        SELECT id FROM descendants
+    "#;
+
+    let mut ids: Vec<String> = sqlx::query_scalar(query)
+        .bind(genre_id)
+        .fetch_all(pool)
+        .await?;
+    if ids.is_empty() {
+        ids.push(genre_id.to_string());
+    }
+    Ok(ids)
+}
+
+/// Checks that `parent_id` can become the parent of `genre_id` (`None` for a new genre).
+///
+/// The parent must exist, and must not be the genre itself or one of its
+/// descendants, which would create a cycle.
+async fn validate_parent(
+    pool: &MySqlPool,
+    genre_id: Option<&str>,
+    parent_id: &str,
+) -> Result<(), HttpResponse> {
+    let parent_exists = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM genres WHERE id = ?")
+        .bind(parent_id)
+        .fetch_one(pool)
         .await
     {
-        Ok(rows) => {
-            debug!("Query successful, fetched {} rows", rows.len());
-            let genres: Vec<GenreWithTitleCount> = rows
-                .into_iter()
-                .filter_map(|row| {
-                    let id_str: String = row.get("id");
-                    let id = match Uuid::parse_str(&id_str) {
-                        Ok(uuid) => uuid,
-                        Err(e) => {
-                            warn!("Failed to parse UUID '{}': {}", id_str, e);
-                            return None;
-                        }
-                    };
-
-                    let created_at: chrono::NaiveDateTime = row.get("created_at");
-                    let updated_at: chrono::NaiveDateTime = row.get("updated_at");
-
-                    debug!("Processing genre: {}", row.get::<String, _>("name"));
-                    Some(GenreWithTitleCount {
-                        genre: crate::models::Genre {
-                            id,
-                            name: row.get("name"),
-                            description: row.get("description"),
-                            created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
-                            updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
-                        },
-                        title_count: row.get("title_count"),
-                    })
-                })
-                .collect();
+        Ok(count) => count > 0,
+        Err(e) => {
+            error!("Database error while checking parent genre: {}", e);
+            return Err(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to check parent genre" }
+            })));
+        }
+    };
+    if !parent_exists {
+        warn!("Parent genre {} not found", parent_id);
+        return Err(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_PARENT",
+                "message": "Parent genre not found"
+            }
+        })));
+    }
 
-            info!("Successfully returning {} genres", genres.len());
-            HttpResponse::Ok().json(genres)
+    let Some(genre_id) = genre_id else {
+        return Ok(());
+    };
+    match genre_with_descendants(pool, genre_id).await {
+        Ok(descendants) if descendants.iter().any(|id| id == parent_id) => {
+            warn!("Rejected moving genre {} under its descendant {}", genre_id, parent_id);
+            Err(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": {
+                    "code": "CIRCULAR_HIERARCHY",
+                    "message": "A genre cannot be moved under itself or one of its sub-genres"
+                }
+            })))
         }
+        Ok(_) => Ok(()),
+        Err(e) => {
+            error!("Database error while checking genre hierarchy: {}", e);
+            Err(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to check genre hierarchy" }
+            })))
+        }
+    }
+}
+
+/// Returns the genre hierarchy as a tree.
+///
+/// **Endpoint**: `GET /api/v1/genres/tree`
+///
+/// Root genres are returned in alphabetical order, each with its sub-genres in
+/// `children`. Every node has its `full_path` (e.g. "Fiction > Fantasy"), its
+/// own `title_count` and a `total_title_count` including its descendants.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `GenreTreeNode` objects on success
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn get_genre_tree(data: web::Data<AppState>) -> impl Responder {
+    info!("GET /api/v1/genres/tree - Fetching genre tree");
+
+    match fetch_genres_with_counts(&data.db_pool).await {
+        Ok(genres) => HttpResponse::Ok().json(GenreTreeNode::build_tree(genres)),
         Err(e) => {
             error!("Database error while fetching genres: {}", e);
             HttpResponse::InternalServerError().json(serde_json::json!({
@@ -118,7 +236,7 @@ pub async fn get_genre(
     info!("GET /api/v1/genres/{} - Fetching genre", id);
 
     let query = r#"
-        SELECT id, name, description, created_at, updated_at
+        SELECT id, name, description, parent_id, created_at, updated_at
         FROM genres
         WHERE id = ?
     "#;
@@ -150,6 +268,7 @@ pub async fn get_genre(
                 id: genre_id,
                 name: row.get("name"),
                 description: row.get("description"),
+                parent_id: row.get("parent_id"),
                 created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
                 updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
             };
@@ -195,13 +314,15 @@ pub async fn get_genre(
 /// ```json
 /// {
 ///   "name": "Science Fiction",
-///   "description": "Speculative fiction dealing with imaginative concepts"
+///   "description": "Speculative fiction dealing with imaginative concepts",
+///   "parent_id": "fiction-genre-uuid"
 /// }
 /// ```
 ///
 /// # Returns
 ///
 /// * `HttpResponse::Created` (201) with new genre ID on success
+/// * `HttpResponse::BadRequest` (400) if the parent genre does not exist
 /// * `HttpResponse::InternalServerError` if database operation fails
 pub async fn create_genre(
     data: web::Data<AppState>,
@@ -209,17 +330,25 @@ pub async fn create_genre(
 ) -> impl Responder {
     info!("POST /api/v1/genres - Creating new genre: {}", req.name);
 
+    let parent_id = req.parent_id.as_deref().filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id
+        && let Err(response) = validate_parent(&data.db_pool, None, parent_id).await
+    {
+        return response;
+    }
+
     let new_id = Uuid::new_v4();
 
     let query = r#"
-        INSERT INTO genres (id, name, description, created_at, updated_at)
-        VALUES (?, ?, ?, NOW(), NOW())
+        INSERT INTO genres (id, name, description, parent_id, created_at, updated_at)
+        VALUES (?, ?, ?, ?, NOW(), NOW())
     "#;
 
     match sqlx::query(query)
         .bind(new_id.to_string())
         .bind(&req.name)
         .bind(&req.description)
+        .bind(parent_id)
         .execute(&data.db_pool)
         .await
     {
@@ -250,6 +379,7 @@ pub async fn create_genre(
 /// **Endpoint**: `PUT /api/v1/genres/{id}`
 ///
 /// Updates mutable fields of a genre. Only provided fields are updated.
+/// An empty `parent_id` moves the genre to the root of the tree.
 ///
 /// # Arguments
 ///
@@ -261,7 +391,8 @@ pub async fn create_genre(
 ///
 /// * `HttpResponse::Ok` on success
 /// * `HttpResponse::NotFound` if genre does not exist
-/// * `HttpResponse::BadRequest` if no fields provided or validation fails
+/// * `HttpResponse::BadRequest` if no fields provided, the parent genre does not exist
+///   or the new parent is the genre itself or one of its sub-genres
 /// * `HttpResponse::InternalServerError` if database operation fails
 pub async fn update_genre(
     data: web::Data<AppState>,
@@ -281,6 +412,10 @@ pub async fn update_genre(
         update_parts.push("description = ?");
         has_updates = true;
     }
+    if req.parent_id.is_some() {
+        update_parts.push("parent_id = ?");
+        has_updates = true;
+    }
 
     if !has_updates {
         warn!("No fields to update for genre {}", id);
@@ -292,6 +427,14 @@ pub async fn update_genre(
         }));
     }
 
+    // An empty parent_id moves the genre back to the root
+    let parent_id = req.parent_id.as_deref().filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id
+        && let Err(response) = validate_parent(&data.db_pool, Some(id.as_str()), parent_id).await
+    {
+        return response;
+    }
+
     update_parts.push("updated_at = NOW()");
     let update_clause = update_parts.join(", ");
     let query = format!("UPDATE genres SET {} WHERE id = ?", update_clause);
@@ -306,6 +449,9 @@ pub async fn update_genre(
     if let Some(ref description) = req.description {
         query_builder = query_builder.bind(description);
     }
+    if req.parent_id.is_some() {
+        query_builder = query_builder.bind(parent_id);
+    }
 
     query_builder = query_builder.bind(id.as_str());
 
@@ -345,8 +491,8 @@ pub async fn update_genre(
 ///
 /// **Endpoint**: `DELETE /api/v1/genres/{id}`
 ///
-/// Removes a genre record. Note that this may fail or cascade depending on foreign key constraints
-/// with `titles` (though usually handled by ON DELETE SET NULL or similar logic if configured).
+/// Removes a genre record and its links to titles. Titles for which it was the primary
+/// genre get their oldest remaining genre as new primary genre.
 ///
 /// # Arguments
 ///
@@ -357,6 +503,7 @@ pub async fn update_genre(
 ///
 /// * `HttpResponse::Ok` on success
 /// * `HttpResponse::NotFound` if genre does not exist
+/// * `HttpResponse::Conflict` if the genre has sub-genres
 /// * `HttpResponse::InternalServerError` if database operation fails
 pub async fn delete_genre(
     data: web::Data<AppState>,
@@ -364,28 +511,63 @@ pub async fn delete_genre(
 ) -> impl Responder {
     info!("DELETE /api/v1/genres/{} - Deleting genre", id);
 
-    let query = "DELETE FROM genres WHERE id = ?";
-
-    match sqlx::query(query)
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM genres WHERE parent_id = ?")
         .bind(id.as_str())
-        .execute(&data.db_pool)
+        .fetch_one(&data.db_pool)
         .await
     {
-        Ok(result) => {
-            if result.rows_affected() == 0 {
-                warn!("Genre {} not found", id);
-                HttpResponse::NotFound().json(serde_json::json!({
-                    "error": {
-                        "code": "NOT_FOUND",
-                        "message": "Genre not found"
-                    }
-                }))
-            } else {
-                info!("Successfully deleted genre {}", id);
-                HttpResponse::Ok().json(serde_json::json!({
-                    "message": "Genre deleted successfully"
-                }))
-            }
+        Ok(count) if count > 0 => {
+            warn!("Cannot delete genre {} - has {} sub-genres", id, count);
+            return HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "HAS_CHILD_GENRES",
+                    "message": format!("Cannot delete genre: it has {} sub-genre(s). Delete or move them first.", count)
+                }
+            }));
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("Database error checking sub-genres: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to check for sub-genres"
+                }
+            }));
+        }
+    }
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => {
+            error!("Failed to start transaction: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to delete genre" }
+            }));
+        }
+    };
+
+    let result = delete_genre_and_promote(&mut tx, id.as_str()).await;
+    let result = match result {
+        Ok(rows_affected) => tx.commit().await.map(|_| rows_affected),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(0) => {
+            warn!("Genre {} not found", id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": {
+                    "code": "NOT_FOUND",
+                    "message": "Genre not found"
+                }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully deleted genre {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Genre deleted successfully"
+            }))
         }
         Err(e) => {
             error!("Database error while deleting genre: {}", e);
@@ -401,3 +583,46 @@ pub async fn delete_genre(
         }
     }
 }
+
+/// Deletes a genre and gives a new primary genre to the titles it was primary for.
+///
+/// Returns the number of deleted genres (0 if it did not exist).
+async fn delete_genre_and_promote(
+    conn: &mut sqlx::MySqlConnection,
+    genre_id: &str,
+) -> Result<u64, sqlx::Error> {
+    let orphaned: Vec<String> = sqlx::query_scalar(
+        "SELECT title_id FROM title_genres WHERE genre_id = ? AND is_primary"
+    )
+    .bind(genre_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    // Cascades to title_genres and clears titles.genre_id
+    let deleted = sqlx::query("DELETE FROM genres WHERE id = ?")
+        .bind(genre_id)
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+
+    for title_id in &orphaned {
+        sqlx::query(
+            "UPDATE title_genres SET is_primary = TRUE WHERE title_id = ? ORDER BY created_at, genre_id LIMIT 1"
+        )
+        .bind(title_id)
+        .execute(&mut *conn)
+        .await?;
+        sqlx::query(
+            "UPDATE titles SET genre_id = (SELECT genre_id FROM title_genres WHERE title_id = ? AND is_primary) WHERE id = ?"
+        )
+        .bind(title_id)
+        .bind(title_id)
+        .execute(&mut *conn)
+        .await?;
+    }
+    if !orphaned.is_empty() {
+        debug!("Promoted a new primary genre for {} titles", orphaned.len());
+    }
+
+    Ok(deleted)
+}
@@ -7,6 +7,8 @@ use actix_web::{web, HttpResponse, Responder};
 use log::{info, error};
 use serde::{Deserialize, Serialize};
 use crate::google_books;
+use shared::isbn::Isbn;
+use shared::languages;
 
 /// Response structure for ISBN lookup
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,6 +20,9 @@ pub struct IsbnLookupResponse {
     pub publication_year: Option<i32>,
     pub pages: Option<i32>,
     pub language: Option<String>,
+    /// Original title and language when the edition is a translation and the provider knows it
+    pub original_title: Option<String>,
+    pub original_language: Option<String>,
     pub isbn: String,
     pub summary: Option<String>,
     pub categories: Vec<String>,
@@ -40,6 +45,7 @@ pub struct IsbnLookupResponse {
 /// # Returns
 ///
 /// * `HttpResponse::Ok` with `IsbnLookupResponse` containing book data and cover image
+/// * `HttpResponse::BadRequest` if the ISBN has a wrong length or check digit
 /// * `HttpResponse::NotFound` if the ISBN is not found in the external API
 /// * `HttpResponse::InternalServerError` if the external API request fails
 ///
@@ -58,8 +64,21 @@ pub struct IsbnLookupResponse {
 pub async fn lookup_isbn(isbn: web::Path<String>) -> impl Responder {
     info!("POST /api/v1/isbn/{} - Looking up ISBN", isbn);
 
+    // Reject typos before calling the external API
+    let parsed = match Isbn::parse(&isbn) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(serde_json::json!({
+                "error": {
+                    "code": "INVALID_ISBN",
+                    "message": format!("Invalid ISBN '{}': {}", isbn, e)
+                }
+            }));
+        }
+    };
+
     // Fetch book data from Google Books API
-    let book_data = match google_books::fetch_book_by_isbn(&isbn).await {
+    let book_data = match google_books::fetch_book_by_isbn(parsed.as_str()).await {
         Ok(data) => data,
         Err(e) => {
             error!("Failed to fetch book data for ISBN {}: {}", isbn, e);
@@ -96,7 +115,10 @@ pub async fn lookup_isbn(isbn: web::Path<String>) -> impl Responder {
         publisher: book_data.publisher,
         publication_year: book_data.publication_year,
         pages: book_data.pages,
-        language: book_data.language,
+        // Providers send codes like "en" or "zh-CN"; unknown languages are left for the user to pick
+        language: book_data.language.and_then(|language| languages::normalize(&language).ok()),
+        original_title: book_data.original_title,
+        original_language: book_data.original_language.and_then(|language| languages::normalize(&language).ok()),
         isbn: book_data.isbn,
         summary: book_data.summary,
         categories: book_data.categories,
@@ -0,0 +1,24 @@
+//! API handler for the catalogue of languages.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/languages` - List the ISO 639 languages a title can be in
+//!
+//! The catalogue is bundled with the application (see [`shared::languages`]),
+//! it is not stored in the database.
+
+use actix_web::{HttpResponse, Responder};
+use log::info;
+use shared::languages::LANGUAGES;
+
+/// Lists the languages accepted for titles, with their codes and localized names.
+///
+/// **Endpoint**: `GET /api/v1/languages`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of languages, ISO 639-1 languages in code order first
+pub async fn list_languages() -> impl Responder {
+    info!("GET /api/v1/languages - Listing {} languages", LANGUAGES.len());
+    HttpResponse::Ok().json(LANGUAGES)
+}
@@ -33,7 +33,7 @@ use crate::models::{
 };
 use crate::AppState;
 use log::{info, error};
-use sqlx::Row;
+use sqlx::{MySqlPool, Row};
 use uuid::Uuid;
 use chrono::{Utc, Duration};
 
@@ -414,7 +414,7 @@ pub async fn create_loan_by_barcode(
         SELECT b.id, b.name, COALESCE(g.loan_duration_days, 21) as loan_duration_days
         FROM borrowers b
         LEFT JOIN borrower_groups g ON b.group_id = g.id
-        WHERE b.id = ?
+        WHERE b.id = ? AND b.deleted_at IS NULL
     ";
 
     let borrower_row = match sqlx::query(borrower_query)
@@ -752,20 +752,39 @@ pub async fn extend_loan(
 ) -> impl Responder {
     info!("POST /api/v1/loans/{}/extend - Extending loan", loan_id);
 
+    apply_loan_extension(&data.db_pool, loan_id.as_str(), None).await
+}
+
+/// Whether a loan of `loan_borrower_id` may be extended by `borrower_id`; `None`
+/// (the librarian endpoint) may extend any loan.
+fn loan_belongs_to(loan_borrower_id: &str, borrower_id: Option<&str>) -> bool {
+    borrower_id.is_none_or(|borrower_id| loan_borrower_id == borrower_id)
+}
+
+/// Applies the loan extension rules described on `extend_loan`.
+///
+/// Shared by the librarian endpoint and the borrower self-service portal so both
+/// follow exactly the same policy. When `borrower_id` is set, loans belonging to
+/// another borrower are reported as not found.
+pub(crate) async fn apply_loan_extension(
+    db_pool: &MySqlPool,
+    loan_id: &str,
+    borrower_id: Option<&str>,
+) -> HttpResponse {
     // 1. Get loan details
     let loan_query = "
-        SELECT id, loan_date, due_date, extension_count, status
+        SELECT id, borrower_id, loan_date, due_date, extension_count, status
         FROM loans
         WHERE id = ?
     ";
 
     let loan_row = match sqlx::query(loan_query)
-        .bind(loan_id.as_str())
-        .fetch_optional(&data.db_pool)
+        .bind(loan_id)
+        .fetch_optional(db_pool)
         .await
     {
-        Ok(Some(row)) => row,
-        Ok(None) => {
+        Ok(Some(row)) if loan_belongs_to(&row.get::<String, _>("borrower_id"), borrower_id) => row,
+        Ok(_) => {
             error!("Loan not found: {}", loan_id);
             return HttpResponse::NotFound().json(serde_json::json!({
                 "error": "Loan not found"
@@ -815,8 +834,8 @@ pub async fn extend_loan(
 
     if let Err(e) = sqlx::query(update_query)
         .bind(new_due_date.naive_utc())
-        .bind(loan_id.as_str())
-        .execute(&data.db_pool)
+        .bind(loan_id)
+        .execute(db_pool)
         .await
     {
         error!("Failed to extend loan: {}", e);
@@ -834,3 +853,17 @@ pub async fn extend_loan(
         "original_duration_days": original_duration.num_days()
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loan_belongs_to() {
+        // Librarian endpoint
+        assert!(loan_belongs_to("borrower-1", None));
+        // Self-service portal
+        assert!(loan_belongs_to("borrower-1", Some("borrower-1")));
+        assert!(!loan_belongs_to("borrower-1", Some("borrower-2")));
+    }
+}
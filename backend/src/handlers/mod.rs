@@ -4,15 +4,32 @@
 //! Each submodule corresponds to a specific resource or feature, such as titles, authors, loans, etc.
 
 pub mod titles;
+pub mod title_identifiers;
+pub mod title_relations;
+pub mod title_contents;
+pub mod title_revisions;
 pub mod locations;
 pub mod authors;
 pub mod publishers;
 pub mod genres;
 pub mod series;
+pub mod works;
+pub mod tags;
+pub mod custom_fields;
+pub mod trash;
+pub mod wishlist;
+pub mod reading;
+pub mod reviews;
 pub mod volumes;
+pub mod bulk;
 pub mod uploads;
 pub mod isbn_lookup;
+pub mod languages;
 pub mod borrower_groups;
 pub mod borrowers;
 pub mod loans;
 pub mod statistics;
+pub mod auth;
+pub mod users;
+pub mod api_tokens;
+pub mod portal;
@@ -0,0 +1,265 @@
+//! Borrower self-service portal API handlers.
+//!
+//! This module lets a logged-in user look at the loans of the borrower linked to
+//! their account (`users.borrower_id`) and extend them without asking a librarian.
+//! It is available to every role, including readers.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/me/loans` - Active and past loans of the linked borrower
+//! - `POST /api/v1/me/loans/{id}/extend` - Extend one of those loans
+//!
+//! # Business Rules
+//!
+//! - Extensions follow exactly the same rules as `POST /api/v1/loans/{id}/extend`
+//!   (see `handlers::loans::extend_loan`).
+//! - Loans of other borrowers are reported as not found.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::AuthenticatedUser;
+use crate::handlers::loans::apply_loan_extension;
+use crate::models::{Loan, LoanDetail, LoanStatus, MyLoansResponse};
+use crate::AppState;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use log::{info, error};
+use sqlx::mysql::MySqlRow;
+use sqlx::{MySqlPool, Row};
+use uuid::Uuid;
+
+/// Borrower linked to a user account.
+struct LinkedBorrower {
+    id: String,
+    name: String,
+}
+
+/// Finds the borrower linked to the user, or builds the error response to return.
+async fn linked_borrower(
+    db_pool: &MySqlPool,
+    user: &AuthenticatedUser,
+) -> Result<LinkedBorrower, HttpResponse> {
+    let query = "
+        SELECT b.id, b.name
+        FROM users u
+        INNER JOIN borrowers b ON u.borrower_id = b.id
+        WHERE u.id = ? AND b.deleted_at IS NULL
+    ";
+
+    match sqlx::query(query).bind(&user.id).fetch_optional(db_pool).await {
+        Ok(Some(row)) => Ok(LinkedBorrower {
+            id: row.get("id"),
+            name: row.get("name"),
+        }),
+        Ok(None) => Err(HttpResponse::NotFound().json(serde_json::json!({
+            "error": {
+                "code": "NO_LINKED_BORROWER",
+                "message": "Your account is not linked to a borrower. Ask a librarian to link it."
+            }
+        }))),
+        Err(e) => {
+            error!("Failed to fetch linked borrower: {}", e);
+            Err(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch linked borrower"
+                }
+            })))
+        }
+    }
+}
+
+fn loan_detail_from_row(row: &MySqlRow, now: DateTime<Utc>) -> Option<LoanDetail> {
+    let to_utc = |value: NaiveDateTime| DateTime::from_naive_utc_and_offset(value, Utc);
+
+    let id: String = row.get("id");
+    let loan_date: NaiveDateTime = row.get("loan_date");
+    let due_date: NaiveDateTime = row.get("due_date");
+    let return_date: Option<NaiveDateTime> = row.get("return_date");
+    let created_at: NaiveDateTime = row.get("created_at");
+    let updated_at: NaiveDateTime = row.get("updated_at");
+    let status_str: String = row.get("status");
+
+    let status = match status_str.as_str() {
+        "returned" => LoanStatus::Returned,
+        "overdue" => LoanStatus::Overdue,
+        _ => LoanStatus::Active,
+    };
+    let is_overdue = status != LoanStatus::Returned && to_utc(due_date) < now;
+
+    Some(LoanDetail {
+        loan: Loan {
+            id: Uuid::parse_str(&id).ok()?,
+            title_id: row.get("title_id"),
+            volume_id: row.get("volume_id"),
+            borrower_id: row.get("borrower_id"),
+            loan_date: to_utc(loan_date),
+            due_date: to_utc(due_date),
+            extension_count: row.get("extension_count"),
+            return_date: return_date.map(to_utc),
+            status,
+            created_at: to_utc(created_at),
+            updated_at: to_utc(updated_at),
+        },
+        title: row.get("title"),
+        barcode: row.get("barcode"),
+        borrower_name: row.get("borrower_name"),
+        borrower_email: row.get("borrower_email"),
+        is_overdue,
+    })
+}
+
+/// Splits loans into the active ones, soonest due first, and the returned ones,
+/// which keep their order.
+fn split_loans(loans: Vec<LoanDetail>) -> (Vec<LoanDetail>, Vec<LoanDetail>) {
+    let (mut active_loans, past_loans): (Vec<LoanDetail>, Vec<LoanDetail>) = loans
+        .into_iter()
+        .partition(|detail| detail.loan.status != LoanStatus::Returned);
+    active_loans.sort_by_key(|detail| detail.loan.due_date);
+    (active_loans, past_loans)
+}
+
+/// Lists the active and past loans of the borrower linked to the current user.
+///
+/// **Endpoint**: `GET /api/v1/me/loans`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with a `MyLoansResponse`
+/// * `HttpResponse::NotFound` with code `NO_LINKED_BORROWER` if the account has no borrower
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_my_loans(
+    data: web::Data<AppState>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/me/loans - Fetching loans of {}", user.username);
+
+    let borrower = match linked_borrower(&data.db_pool, &user).await {
+        Ok(borrower) => borrower,
+        Err(response) => return response,
+    };
+
+    let query = "
+        SELECT
+            l.id, l.title_id, l.volume_id, l.borrower_id,
+            l.loan_date, l.due_date, l.extension_count, l.return_date, l.status,
+            l.created_at, l.updated_at,
+            t.title,
+            v.barcode,
+            b.name as borrower_name,
+            b.email as borrower_email
+        FROM loans l
+        INNER JOIN titles t ON l.title_id = t.id
+        INNER JOIN volumes v ON l.volume_id = v.id
+        INNER JOIN borrowers b ON l.borrower_id = b.id
+        WHERE l.borrower_id = ?
+        ORDER BY l.loan_date DESC
+    ";
+
+    match sqlx::query(query).bind(&borrower.id).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let now = Utc::now();
+            let (active_loans, past_loans) =
+                split_loans(rows.iter().filter_map(|row| loan_detail_from_row(row, now)).collect());
+
+            info!(
+                "Successfully fetched {} active and {} past loans for borrower {}",
+                active_loans.len(),
+                past_loans.len(),
+                borrower.id
+            );
+            HttpResponse::Ok().json(MyLoansResponse {
+                borrower_id: borrower.id,
+                borrower_name: borrower.name,
+                active_loans,
+                past_loans,
+            })
+        }
+        Err(e) => {
+            error!("Failed to fetch loans of borrower {}: {}", borrower.id, e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch loans"
+                }
+            }))
+        }
+    }
+}
+
+/// Extends one of the current user's loans.
+///
+/// **Endpoint**: `POST /api/v1/me/loans/{id}/extend`
+///
+/// Applies the same rules and returns the same responses as
+/// `POST /api/v1/loans/{id}/extend`.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with the new due date on success
+/// * `HttpResponse::NotFound` if the account has no borrower or the loan is not theirs
+/// * `HttpResponse::BadRequest` if the loan was already returned
+/// * `HttpResponse::Conflict` if the extension limit is reached
+pub async fn extend_my_loan(
+    loan_id: web::Path<String>,
+    data: web::Data<AppState>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("POST /api/v1/me/loans/{}/extend - Self-service extension by {}", loan_id, user.username);
+
+    let borrower = match linked_borrower(&data.db_pool, &user).await {
+        Ok(borrower) => borrower,
+        Err(response) => return response,
+    };
+
+    apply_loan_extension(&data.db_pool, loan_id.as_str(), Some(&borrower.id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn loan(title: &str, due_in_days: i64, status: LoanStatus) -> LoanDetail {
+        let loan_date = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        LoanDetail {
+            loan: Loan {
+                id: Uuid::new_v4(),
+                title_id: "title".to_string(),
+                volume_id: "volume".to_string(),
+                borrower_id: "borrower".to_string(),
+                loan_date,
+                due_date: loan_date + Duration::days(due_in_days),
+                extension_count: 0,
+                return_date: None,
+                status,
+                created_at: loan_date,
+                updated_at: loan_date,
+            },
+            title: title.to_string(),
+            barcode: "VOL-000001".to_string(),
+            borrower_name: "Alice".to_string(),
+            borrower_email: None,
+            is_overdue: false,
+        }
+    }
+
+    #[test]
+    fn test_split_loans() {
+        let (active, past) = split_loans(vec![
+            loan("Returned late", 30, LoanStatus::Returned),
+            loan("Due later", 21, LoanStatus::Active),
+            loan("Overdue", 7, LoanStatus::Overdue),
+            loan("Returned early", 14, LoanStatus::Returned),
+        ]);
+
+        let titles = |loans: &[LoanDetail]| loans.iter().map(|l| l.title.clone()).collect::<Vec<_>>();
+        assert_eq!(titles(&active), vec!["Overdue", "Due later"]);
+        assert_eq!(titles(&past), vec!["Returned late", "Returned early"]);
+    }
+
+    #[test]
+    fn test_split_loans_without_loans() {
+        let (active, past) = split_loans(Vec::new());
+        assert!(active.is_empty());
+        assert!(past.is_empty());
+    }
+}
@@ -41,7 +41,7 @@ pub async fn list_publishers(data: web::Data<AppState>) -> impl Responder {
             p.updated_at,
             COUNT(t.id) as title_count
         FROM publishers p
-        LEFT JOIN titles t ON p.id = t.publisher_id
+        LEFT JOIN titles t ON p.id = t.publisher_id AND t.deleted_at IS NULL
         GROUP BY p.id
         ORDER BY p.name ASC
     "#;
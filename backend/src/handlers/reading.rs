@@ -0,0 +1,483 @@
+//! API handlers for tracking who has read what.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/reading` - List reading records, by default the current user's
+//! - `GET /api/v1/reading/current` - Titles being read, with progress
+//! - `GET /api/v1/reading/finished` - Readings finished in a year (default: this year)
+//! - `POST /api/v1/reading` - Start tracking a title for the current user
+//! - `PUT /api/v1/reading/{id}` - Update one of the current user's records
+//! - `DELETE /api/v1/reading/{id}` - Delete one of the current user's records
+//!
+//! Anyone can look at the reading lists of the other users (pass `user_id`),
+//! but only their own records can be changed.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::AuthenticatedUser;
+use crate::models::{
+    CreateReadingRecordRequest, ReadingListParams, ReadingRecord, ReadingStatus, UpdateReadingRecordRequest,
+};
+use crate::AppState;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Utc};
+use log::{info, warn, error};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Reading records with their reader, title and position among the reader's readings of the title.
+const READING_QUERY: &str = r#"
+    SELECT
+        rr.id, rr.user_id, u.username, rr.title_id, t.title, t.pages, rr.status,
+        rr.started_on, rr.finished_on, rr.current_page,
+        (SELECT COUNT(*) FROM reading_records prev
+         WHERE prev.user_id = rr.user_id AND prev.title_id = rr.title_id
+           AND (prev.created_at < rr.created_at OR (prev.created_at = rr.created_at AND prev.id <= rr.id))) AS reading_number,
+        rr.created_at, rr.updated_at
+    FROM reading_records rr
+    INNER JOIN users u ON rr.user_id = u.id
+    INNER JOIN titles t ON rr.title_id = t.id
+    WHERE t.deleted_at IS NULL
+"#;
+
+fn reading_record_from_row(row: &MySqlRow) -> Option<ReadingRecord> {
+    let status: String = row.get("status");
+    let status = ReadingStatus::from_db(&status)?;
+    let pages: Option<i32> = row.get("pages");
+    let current_page: Option<i32> = row.get("current_page");
+    let created_at: NaiveDateTime = row.get("created_at");
+    let updated_at: NaiveDateTime = row.get("updated_at");
+
+    Some(ReadingRecord {
+        id: row.get("id"),
+        user_id: row.get("user_id"),
+        username: row.get("username"),
+        title_id: row.get("title_id"),
+        title: row.get("title"),
+        pages,
+        status,
+        started_on: row.get("started_on"),
+        finished_on: row.get("finished_on"),
+        current_page,
+        progress_percent: ReadingRecord::progress(status, current_page, pages),
+        reading_number: row.get("reading_number"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+        updated_at: DateTime::from_naive_utc_and_offset(updated_at, Utc),
+    })
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn record_not_found(id: &str) -> HttpResponse {
+    warn!("Reading record {} not found", id);
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": { "code": "NOT_FOUND", "message": "Reading record not found" }
+    }))
+}
+
+/// Fills in the start and finish dates implied by a status.
+///
+/// Starting to read sets `started_on` and finishing sets `finished_on` to today,
+/// unless the client gave a date.
+fn fill_dates(status: ReadingStatus, started_on: &mut Option<NaiveDate>, finished_on: &mut Option<NaiveDate>) {
+    let today = Utc::now().date_naive();
+    match status {
+        ReadingStatus::ToRead => {}
+        ReadingStatus::Reading => {
+            started_on.get_or_insert(today);
+        }
+        ReadingStatus::Read => {
+            finished_on.get_or_insert(today);
+        }
+    }
+}
+
+/// Checks the current page against the title's page count and the order of the dates,
+/// returning the 400 response if either is wrong.
+fn record_error(
+    started_on: Option<NaiveDate>,
+    finished_on: Option<NaiveDate>,
+    current_page: Option<i32>,
+    pages: Option<i32>,
+) -> Option<HttpResponse> {
+    if let Some(page) = current_page
+        && (page < 0 || pages.is_some_and(|pages| page > pages))
+    {
+        warn!("Rejected current page {} for a title of {:?} pages", page, pages);
+        return Some(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_PROGRESS",
+                "message": "The current page must be between 0 and the title's page count",
+                "details": { "current_page": page, "pages": pages }
+            }
+        })));
+    }
+    if let (Some(started_on), Some(finished_on)) = (started_on, finished_on)
+        && finished_on < started_on
+    {
+        return Some(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_DATES",
+                "message": "A reading cannot finish before it starts"
+            }
+        })));
+    }
+    None
+}
+
+/// Runs `READING_QUERY` with extra conditions, each binding one value.
+async fn fetch_records(
+    data: &AppState,
+    conditions: &[&str],
+    values: &[String],
+    order_by: &str,
+) -> Result<Vec<ReadingRecord>, sqlx::Error> {
+    let mut query = READING_QUERY.to_string();
+    for condition in conditions {
+        query.push_str(" AND ");
+        query.push_str(condition);
+    }
+    query.push_str(" ORDER BY ");
+    query.push_str(order_by);
+
+    let mut query_builder = sqlx::query(&query);
+    for value in values {
+        query_builder = query_builder.bind(value);
+    }
+    let rows = query_builder.fetch_all(&data.db_pool).await?;
+    Ok(rows.iter().filter_map(reading_record_from_row).collect())
+}
+
+/// Lists reading records, most recently updated first.
+///
+/// **Endpoint**: `GET /api/v1/reading`
+///
+/// # Query Parameters
+///
+/// * `user_id` - Reader to list (default: the current user)
+/// * `status` - Only records with this status (`to_read`, `reading`, `read`)
+/// * `title_id` - Only the readings of this title, e.g. to see its re-reads
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `ReadingRecord` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_reading_records(
+    data: web::Data<AppState>,
+    params: web::Query<ReadingListParams>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/reading - Fetching reading records");
+
+    let mut conditions = vec!["rr.user_id = ?"];
+    let mut values = vec![params.user_id.clone().unwrap_or_else(|| user.id.clone())];
+    if let Some(status) = params.status {
+        conditions.push("rr.status = ?");
+        values.push(status.to_string());
+    }
+    if let Some(ref title_id) = params.title_id {
+        conditions.push("rr.title_id = ?");
+        values.push(title_id.clone());
+    }
+
+    match fetch_records(&data, &conditions, &values, "rr.updated_at DESC").await {
+        Ok(records) => {
+            info!("Found {} reading records", records.len());
+            HttpResponse::Ok().json(records)
+        }
+        Err(e) => database_error("Failed to fetch reading records", e),
+    }
+}
+
+/// Lists the titles being read, most recently started first.
+///
+/// **Endpoint**: `GET /api/v1/reading/current`
+///
+/// # Query Parameters
+///
+/// * `user_id` - Reader to list (default: the current user)
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `ReadingRecord` objects, with their `progress_percent`
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn currently_reading(
+    data: web::Data<AppState>,
+    params: web::Query<ReadingListParams>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/reading/current - Fetching titles being read");
+
+    let reader = params.user_id.clone().unwrap_or_else(|| user.id.clone());
+    match fetch_records(
+        &data,
+        &["rr.user_id = ?", "rr.status = 'reading'"],
+        &[reader],
+        "rr.started_on DESC, rr.updated_at DESC",
+    )
+    .await
+    {
+        Ok(records) => HttpResponse::Ok().json(records),
+        Err(e) => database_error("Failed to fetch reading records", e),
+    }
+}
+
+/// Lists the readings finished during a year, most recent first.
+///
+/// **Endpoint**: `GET /api/v1/reading/finished`
+///
+/// # Query Parameters
+///
+/// * `user_id` - Reader to list (default: the current user)
+/// * `year` - Year of the finish date (default: the current year)
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `ReadingRecord` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn finished_readings(
+    data: web::Data<AppState>,
+    params: web::Query<ReadingListParams>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    let year = params.year.unwrap_or_else(|| Utc::now().year());
+    info!("GET /api/v1/reading/finished - Fetching readings finished in {}", year);
+
+    let reader = params.user_id.clone().unwrap_or_else(|| user.id.clone());
+    match fetch_records(
+        &data,
+        &["rr.user_id = ?", "rr.status = 'read'", "YEAR(rr.finished_on) = ?"],
+        &[reader, year.to_string()],
+        "rr.finished_on DESC, rr.updated_at DESC",
+    )
+    .await
+    {
+        Ok(records) => {
+            info!("Found {} readings finished in {}", records.len(), year);
+            HttpResponse::Ok().json(records)
+        }
+        Err(e) => database_error("Failed to fetch reading records", e),
+    }
+}
+
+/// Starts tracking a title for the current user.
+///
+/// **Endpoint**: `POST /api/v1/reading`
+///
+/// To record a re-read, create a new record once the previous one is `read`.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "title_id": "uuid-string",
+///   "status": "reading",
+///   "started_on": "2026-10-01",
+///   "current_page": 42
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new record's UUID
+/// * `HttpResponse::BadRequest` (400) if the current page or the dates are invalid
+/// * `HttpResponse::NotFound` (404) if the title does not exist or is in the trash
+/// * `HttpResponse::Conflict` (409) if the user already has an unfinished record for the title
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn create_reading_record(
+    data: web::Data<AppState>,
+    req: web::Json<CreateReadingRecordRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("POST /api/v1/reading - {} tracks title {} ({})", user.username, req.title_id, req.status);
+
+    let pages = match sqlx::query_scalar::<_, Option<i32>>("SELECT pages FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(&req.title_id)
+        .fetch_optional(&data.db_pool)
+        .await
+    {
+        Ok(Some(pages)) => pages,
+        Ok(None) => {
+            warn!("Title {} not found", req.title_id);
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Title not found" }
+            }));
+        }
+        Err(e) => return database_error("Failed to check title", e),
+    };
+
+    let mut started_on = req.started_on;
+    let mut finished_on = req.finished_on;
+    fill_dates(req.status, &mut started_on, &mut finished_on);
+    if let Some(response) = record_error(started_on, finished_on, req.current_page, pages) {
+        return response;
+    }
+
+    match sqlx::query_scalar::<_, String>(
+        "SELECT id FROM reading_records WHERE user_id = ? AND title_id = ? AND status <> 'read' LIMIT 1"
+    )
+    .bind(&user.id)
+    .bind(&req.title_id)
+    .fetch_optional(&data.db_pool)
+    .await
+    {
+        Ok(Some(record_id)) => {
+            warn!("{} already tracks title {} in record {}", user.username, req.title_id, record_id);
+            return HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "ALREADY_TRACKED",
+                    "message": "You have an unfinished reading of this title, update it instead",
+                    "details": { "record_id": record_id }
+                }
+            }));
+        }
+        Ok(None) => {}
+        Err(e) => return database_error("Failed to check reading records", e),
+    }
+
+    let id = Uuid::new_v4().to_string();
+    match sqlx::query(
+        "INSERT INTO reading_records (id, user_id, title_id, status, started_on, finished_on, current_page, created_at, updated_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, NOW(), NOW())"
+    )
+    .bind(&id)
+    .bind(&user.id)
+    .bind(&req.title_id)
+    .bind(req.status.to_string())
+    .bind(started_on)
+    .bind(finished_on)
+    .bind(req.current_page)
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Created reading record {}", id);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": id,
+                "message": "Reading record created successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to create reading record", e),
+    }
+}
+
+/// Updates one of the current user's reading records.
+///
+/// **Endpoint**: `PUT /api/v1/reading/{id}`
+///
+/// Moving to `reading` fills in `started_on` and moving to `read` fills in
+/// `finished_on` with today's date, unless the request gives one.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the record was updated
+/// * `HttpResponse::BadRequest` (400) if no field was given, or the current page or the dates are invalid
+/// * `HttpResponse::NotFound` (404) if the record does not exist or belongs to someone else
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn update_reading_record(
+    data: web::Data<AppState>,
+    id: web::Path<String>,
+    req: web::Json<UpdateReadingRecordRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("PUT /api/v1/reading/{} - Updating reading record", id);
+
+    if req.status.is_none() && req.started_on.is_none() && req.finished_on.is_none() && req.current_page.is_none() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "NO_UPDATES",
+                "message": "No fields provided for update"
+            }
+        }));
+    }
+
+    let current = match sqlx::query(
+        "SELECT rr.status, rr.started_on, rr.finished_on, rr.current_page, t.pages \
+         FROM reading_records rr INNER JOIN titles t ON rr.title_id = t.id \
+         WHERE rr.id = ? AND rr.user_id = ?"
+    )
+    .bind(id.as_str())
+    .bind(&user.id)
+    .fetch_optional(&data.db_pool)
+    .await
+    {
+        Ok(Some(row)) => row,
+        Ok(None) => return record_not_found(&id),
+        Err(e) => return database_error("Failed to fetch reading record", e),
+    };
+
+    let current_status: String = current.get("status");
+    let current_status = ReadingStatus::from_db(&current_status).unwrap_or_default();
+    let status = req.status.unwrap_or(current_status);
+    let mut started_on = req.started_on.or(current.get("started_on"));
+    let mut finished_on = req.finished_on.or(current.get("finished_on"));
+    let current_page = req.current_page.or(current.get("current_page"));
+    if status != current_status {
+        fill_dates(status, &mut started_on, &mut finished_on);
+    }
+    if let Some(response) = record_error(started_on, finished_on, current_page, current.get("pages")) {
+        return response;
+    }
+
+    match sqlx::query(
+        "UPDATE reading_records SET status = ?, started_on = ?, finished_on = ?, current_page = ?, updated_at = NOW() \
+         WHERE id = ?"
+    )
+    .bind(status.to_string())
+    .bind(started_on)
+    .bind(finished_on)
+    .bind(current_page)
+    .bind(id.as_str())
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Updated reading record {} ({})", id, status);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Reading record updated successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to update reading record", e),
+    }
+}
+
+/// Deletes one of the current user's reading records.
+///
+/// **Endpoint**: `DELETE /api/v1/reading/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the record was deleted
+/// * `HttpResponse::NotFound` (404) if the record does not exist or belongs to someone else
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_reading_record(
+    data: web::Data<AppState>,
+    id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("DELETE /api/v1/reading/{} - Deleting reading record", id);
+
+    match sqlx::query("DELETE FROM reading_records WHERE id = ? AND user_id = ?")
+        .bind(id.as_str())
+        .bind(&user.id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => record_not_found(&id),
+        Ok(_) => {
+            info!("Deleted reading record {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Reading record deleted successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to delete reading record", e),
+    }
+}
@@ -0,0 +1,507 @@
+//! API handlers for ratings, reviews and personal notes on titles.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/titles/{title_id}/reviews` - List the ratings and reviews of a title
+//! - `PUT /api/v1/titles/{title_id}/reviews` - Rate and/or review a title as the current user
+//! - `DELETE /api/v1/titles/{title_id}/reviews` - Remove the current user's review
+//! - `GET /api/v1/titles/{title_id}/notes` - List the current user's notes on a title
+//! - `POST /api/v1/titles/{title_id}/notes` - Add a note or quote
+//! - `PUT /api/v1/titles/{title_id}/notes/{id}` - Replace a note
+//! - `DELETE /api/v1/titles/{title_id}/notes/{id}` - Delete a note
+//!
+//! Reviews are visible to every user; notes are private to their author.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::AuthenticatedUser;
+use crate::models::{SetTitleReviewRequest, TitleNote, TitleNoteKind, TitleNoteRequest, TitleReview};
+use crate::AppState;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use log::{info, warn, error};
+use shared::models::reviews::{MAX_RATING, MIN_RATING};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+fn review_from_row(row: &MySqlRow) -> TitleReview {
+    let created_at: NaiveDateTime = row.get("created_at");
+    let updated_at: NaiveDateTime = row.get("updated_at");
+    TitleReview {
+        id: row.get("id"),
+        title_id: row.get("title_id"),
+        user_id: row.get("user_id"),
+        username: row.get("username"),
+        rating: row.get::<Option<i8>, _>("rating").map(i32::from),
+        review: row.get("review"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+        updated_at: DateTime::from_naive_utc_and_offset(updated_at, Utc),
+    }
+}
+
+fn note_from_row(row: &MySqlRow) -> Option<TitleNote> {
+    let kind: String = row.get("kind");
+    let created_at: NaiveDateTime = row.get("created_at");
+    let updated_at: NaiveDateTime = row.get("updated_at");
+    Some(TitleNote {
+        id: row.get("id"),
+        title_id: row.get("title_id"),
+        kind: TitleNoteKind::from_db(&kind)?,
+        content: row.get("content"),
+        page: row.get("page"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+        updated_at: DateTime::from_naive_utc_and_offset(updated_at, Utc),
+    })
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn validation_error(message: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "VALIDATION_ERROR",
+            "message": message
+        }
+    }))
+}
+
+fn not_found(message: &str) -> HttpResponse {
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": { "code": "NOT_FOUND", "message": message }
+    }))
+}
+
+/// Returns the page count of a title, or the 404 response if it does not exist.
+async fn fetch_title_pages(data: &AppState, title_id: &str) -> Result<Option<i32>, HttpResponse> {
+    match sqlx::query_scalar::<_, Option<i32>>("SELECT pages FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(title_id)
+        .fetch_optional(&data.db_pool)
+        .await
+    {
+        Ok(Some(pages)) => Ok(pages),
+        Ok(None) => {
+            warn!("Title {} not found", title_id);
+            Err(not_found("Title not found"))
+        }
+        Err(e) => Err(database_error("Failed to check title", e)),
+    }
+}
+
+/// Validates a note and returns its trimmed content, or builds the 400 response.
+///
+/// The page must lie within the title when its page count is known.
+fn validate_note(req: &TitleNoteRequest, pages: Option<i32>) -> Result<String, Box<HttpResponse>> {
+    let content = req.content.trim();
+    if content.is_empty() {
+        return Err(Box::new(validation_error("A note cannot be empty")));
+    }
+    if let Some(page) = req.page
+        && (page < 1 || pages.is_some_and(|pages| page > pages))
+    {
+        warn!("Rejected note on page {} of a title of {:?} pages", page, pages);
+        return Err(Box::new(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_PAGE",
+                "message": "The page must be between 1 and the title's page count",
+                "details": { "page": page, "pages": pages }
+            }
+        }))));
+    }
+    Ok(content.to_string())
+}
+
+/// Validates a review and returns its trimmed text, or builds the 400 response.
+///
+/// A review needs a rating in range, some text, or both.
+fn validate_review(req: &SetTitleReviewRequest) -> Result<Option<&str>, Box<HttpResponse>> {
+    if let Some(rating) = req.rating
+        && !(MIN_RATING..=MAX_RATING).contains(&rating)
+    {
+        warn!("Rejected rating {}", rating);
+        return Err(Box::new(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_RATING",
+                "message": format!("A rating must be between {} and {} stars", MIN_RATING, MAX_RATING)
+            }
+        }))));
+    }
+    let review = req.review.as_deref().map(str::trim).filter(|review| !review.is_empty());
+    if req.rating.is_none() && review.is_none() {
+        return Err(Box::new(validation_error("A review needs a rating or some text")));
+    }
+    Ok(review)
+}
+
+/// Lists the ratings and reviews of a title, most recent first.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/reviews`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TitleReview` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_reviews(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/reviews - Fetching reviews", title_id);
+
+    let query = r#"
+        SELECT rv.id, rv.title_id, rv.user_id, u.username, rv.rating, rv.review, rv.created_at, rv.updated_at
+        FROM title_reviews rv
+        INNER JOIN users u ON rv.user_id = u.id
+        WHERE rv.title_id = ?
+        ORDER BY rv.updated_at DESC
+    "#;
+
+    match sqlx::query(query).bind(title_id.as_str()).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let reviews: Vec<TitleReview> = rows.iter().map(review_from_row).collect();
+            info!("Found {} reviews for title {}", reviews.len(), title_id);
+            HttpResponse::Ok().json(reviews)
+        }
+        Err(e) => database_error("Failed to fetch reviews", e),
+    }
+}
+
+/// Rates and/or reviews a title as the current user, replacing their previous review.
+///
+/// **Endpoint**: `PUT /api/v1/titles/{title_id}/reviews`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "rating": 4,
+///   "review": "Slow start, wonderful ending."
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the review was saved
+/// * `HttpResponse::BadRequest` (400) if the rating is out of range or both fields are empty
+/// * `HttpResponse::NotFound` (404) if the title does not exist or is in the trash
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn set_title_review(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<SetTitleReviewRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("PUT /api/v1/titles/{}/reviews - Saving review of {}", title_id, user.username);
+
+    let review = match validate_review(&req) {
+        Ok(review) => review,
+        Err(response) => return *response,
+    };
+
+    if let Err(response) = fetch_title_pages(&data, &title_id).await {
+        return response;
+    }
+
+    let query = r#"
+        INSERT INTO title_reviews (id, title_id, user_id, rating, review, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, NOW(), NOW())
+        ON DUPLICATE KEY UPDATE
+            rating = VALUES(rating),
+            review = VALUES(review),
+            updated_at = NOW()
+    "#;
+
+    match sqlx::query(query)
+        .bind(Uuid::new_v4().to_string())
+        .bind(title_id.as_str())
+        .bind(&user.id)
+        .bind(req.rating)
+        .bind(review)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Saved review of title {} by {}", title_id, user.username);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Review saved"
+            }))
+        }
+        Err(e) => database_error("Failed to save review", e),
+    }
+}
+
+/// Removes the current user's rating and review of a title.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/reviews`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the review was removed
+/// * `HttpResponse::NotFound` (404) if the user has not reviewed the title
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_title_review(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("DELETE /api/v1/titles/{}/reviews - Removing review of {}", title_id, user.username);
+
+    match sqlx::query("DELETE FROM title_reviews WHERE title_id = ? AND user_id = ?")
+        .bind(title_id.as_str())
+        .bind(&user.id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("{} has not reviewed title {}", user.username, title_id);
+            not_found("You have not reviewed this title")
+        }
+        Ok(_) => HttpResponse::Ok().json(serde_json::json!({
+            "message": "Review removed"
+        })),
+        Err(e) => database_error("Failed to remove review", e),
+    }
+}
+
+/// Lists the current user's notes and quotes on a title, in page order.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/notes`
+///
+/// Notes without a page come last, oldest first.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TitleNote` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_notes(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/notes - Fetching notes of {}", title_id, user.username);
+
+    let query = r#"
+        SELECT id, title_id, kind, content, page, created_at, updated_at
+        FROM title_notes
+        WHERE title_id = ? AND user_id = ?
+        ORDER BY page IS NULL, page, created_at
+    "#;
+
+    match sqlx::query(query)
+        .bind(title_id.as_str())
+        .bind(&user.id)
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(rows) => {
+            let notes: Vec<TitleNote> = rows.iter().filter_map(note_from_row).collect();
+            HttpResponse::Ok().json(notes)
+        }
+        Err(e) => database_error("Failed to fetch notes", e),
+    }
+}
+
+/// Adds a note or favourite quote to a title for the current user.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/notes`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "kind": "quote",
+///   "content": "All we have to decide is what to do with the time that is given us.",
+///   "page": 64
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new note's UUID
+/// * `HttpResponse::BadRequest` (400) if the content is empty or the page is outside the title
+/// * `HttpResponse::NotFound` (404) if the title does not exist or is in the trash
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn create_title_note(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<TitleNoteRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("POST /api/v1/titles/{}/notes - Adding {} for {}", title_id, req.kind, user.username);
+
+    let pages = match fetch_title_pages(&data, &title_id).await {
+        Ok(pages) => pages,
+        Err(response) => return response,
+    };
+    let content = match validate_note(&req, pages) {
+        Ok(content) => content,
+        Err(response) => return *response,
+    };
+
+    let id = Uuid::new_v4().to_string();
+    match sqlx::query(
+        "INSERT INTO title_notes (id, title_id, user_id, kind, content, page, created_at, updated_at) \
+         VALUES (?, ?, ?, ?, ?, ?, NOW(), NOW())"
+    )
+    .bind(&id)
+    .bind(title_id.as_str())
+    .bind(&user.id)
+    .bind(req.kind.to_string())
+    .bind(&content)
+    .bind(req.page)
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Created note {} on title {}", id, title_id);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": id,
+                "message": "Note created successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to create note", e),
+    }
+}
+
+/// Replaces the kind, content and page of one of the current user's notes.
+///
+/// **Endpoint**: `PUT /api/v1/titles/{title_id}/notes/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the note was updated
+/// * `HttpResponse::BadRequest` (400) if the content is empty or the page is outside the title
+/// * `HttpResponse::NotFound` (404) if the title or the note does not exist, or the note belongs to someone else
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn update_title_note(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    req: web::Json<TitleNoteRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("PUT /api/v1/titles/{}/notes/{} - Updating note", title_id, id);
+
+    let pages = match fetch_title_pages(&data, &title_id).await {
+        Ok(pages) => pages,
+        Err(response) => return response,
+    };
+    let content = match validate_note(&req, pages) {
+        Ok(content) => content,
+        Err(response) => return *response,
+    };
+
+    match sqlx::query(
+        "UPDATE title_notes SET kind = ?, content = ?, page = ?, updated_at = NOW() \
+         WHERE id = ? AND title_id = ? AND user_id = ?"
+    )
+    .bind(req.kind.to_string())
+    .bind(&content)
+    .bind(req.page)
+    .bind(&id)
+    .bind(&title_id)
+    .bind(&user.id)
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Note {} of title {} not found for {}", id, title_id, user.username);
+            not_found("Note not found")
+        }
+        Ok(_) => HttpResponse::Ok().json(serde_json::json!({
+            "message": "Note updated successfully"
+        })),
+        Err(e) => database_error("Failed to update note", e),
+    }
+}
+
+/// Deletes one of the current user's notes.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/notes/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the note was deleted
+/// * `HttpResponse::NotFound` (404) if the note does not exist or belongs to someone else
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_title_note(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("DELETE /api/v1/titles/{}/notes/{} - Deleting note", title_id, id);
+
+    match sqlx::query("DELETE FROM title_notes WHERE id = ? AND title_id = ? AND user_id = ?")
+        .bind(&id)
+        .bind(&title_id)
+        .bind(&user.id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Note {} of title {} not found for {}", id, title_id, user.username);
+            not_found("Note not found")
+        }
+        Ok(_) => HttpResponse::Ok().json(serde_json::json!({
+            "message": "Note deleted successfully"
+        })),
+        Err(e) => database_error("Failed to delete note", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+
+    fn review(rating: Option<i32>, review: Option<&str>) -> SetTitleReviewRequest {
+        SetTitleReviewRequest { rating, review: review.map(str::to_string) }
+    }
+
+    fn note(content: &str, page: Option<i32>) -> TitleNoteRequest {
+        TitleNoteRequest { kind: TitleNoteKind::default(), content: content.to_string(), page }
+    }
+
+    async fn error_code(response: Box<HttpResponse>) -> String {
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = actix_web::body::to_bytes(response.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        json["error"]["code"].as_str().unwrap().to_string()
+    }
+
+    #[actix_web::test]
+    async fn test_validate_review() {
+        assert_eq!(validate_review(&review(Some(MIN_RATING), None)).unwrap(), None);
+        assert_eq!(validate_review(&review(Some(MAX_RATING), Some("  Great  "))).unwrap(), Some("Great"));
+        assert_eq!(validate_review(&review(None, Some("No stars"))).unwrap(), Some("No stars"));
+
+        for rating in [MIN_RATING - 1, MAX_RATING + 1] {
+            let response = validate_review(&review(Some(rating), Some("Text"))).unwrap_err();
+            assert_eq!(error_code(response).await, "INVALID_RATING");
+        }
+        let response = validate_review(&review(None, Some("   "))).unwrap_err();
+        assert_eq!(error_code(response).await, "VALIDATION_ERROR");
+    }
+
+    #[actix_web::test]
+    async fn test_validate_note() {
+        assert_eq!(validate_note(&note("  Chapter 3 twist ", Some(120)), Some(300)).unwrap(), "Chapter 3 twist");
+        assert_eq!(validate_note(&note("Last page", Some(300)), Some(300)).unwrap(), "Last page");
+        // Any positive page is accepted when the page count is unknown
+        assert!(validate_note(&note("Somewhere", Some(5000)), None).is_ok());
+
+        let response = validate_note(&note(" ", None), Some(300)).unwrap_err();
+        assert_eq!(error_code(response).await, "VALIDATION_ERROR");
+        for page in [0, 301] {
+            let response = validate_note(&note("Out of range", Some(page)), Some(300)).unwrap_err();
+            assert_eq!(error_code(response).await, "INVALID_PAGE");
+        }
+    }
+}
@@ -5,8 +5,10 @@
 //!
 //! # Endpoints
 //!
-//! - `GET /api/v1/series` - List all series with title counts
-//! - `GET /api/v1/series/{id}` - Get a single series by ID
+//! - `GET /api/v1/series` - List all series with their path and title counts
+//! - `GET /api/v1/series/tree` - Get the series hierarchy as a tree
+//! - `GET /api/v1/series/missing` - List the series with missing volumes
+//! - `GET /api/v1/series/{id}` - Get a single series by ID, with its missing numbers
 //! - `POST /api/v1/series` - Create a new series
 //! - `PUT /api/v1/series/{id}` - Update an existing series
 //! - `DELETE /api/v1/series/{id}` - Delete a series (only if no titles associated)
@@ -16,15 +18,69 @@
 //! - Series can contain multiple titles (one-to-many relationship)
 //! - Each title can belong to at most one series
 //! - Series with associated titles cannot be deleted (delete protection)
-//! - Series are ordered alphabetically by name
+//! - Series can be nested through `parent_id` (universe > series > sub-series)
+//! - Series with sub-series cannot be deleted, and a series cannot be moved under
+//!   one of its own sub-series
+//! - Series are ordered alphabetically by name, sub-series after their parent
+//! - A number is missing when no title of the series with at least one volume carries it
+//!   (see `shared::models::series::missing_series_numbers`)
 
 use actix_web::{web, HttpResponse, Responder};
 
+use shared::models::series::{missing_series_numbers, MAX_SERIES_VOLUMES};
+use sqlx::{MySqlPool, Row};
 use uuid::Uuid;
 
-use crate::models::{CreateSeriesRequest, Series, SeriesWithTitleCount, UpdateSeriesRequest};
+use crate::models::{
+    CreateSeriesRequest, Series, SeriesDetail, SeriesListParams, SeriesTreeNode, SeriesWithTitleCount,
+    UpdateSeriesRequest,
+};
 use crate::AppState;
 
+/// Loads the series numbers of the titles of the given series (all series when `None`),
+/// with whether each title has at least one volume.
+async fn fetch_series_numbers(
+    pool: &MySqlPool,
+    series_id: Option<&str>,
+) -> Result<Vec<(String, Option<String>, bool)>, sqlx::Error> {
+    let query = format!(
+        r#"
+        SELECT t.series_id, t.series_number,
+               EXISTS (SELECT 1 FROM volumes v WHERE v.title_id = t.id) as owned
+        FROM titles t
+        WHERE t.deleted_at IS NULL AND {}
+        "#,
+        if series_id.is_some() { "t.series_id = ?" } else { "t.series_id IS NOT NULL" }
+    );
+
+    let rows = sqlx::query(&query).bind(series_id).fetch_all(pool).await?;
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let owned: i64 = row.get("owned");
+            (row.get("series_id"), row.get("series_number"), owned != 0)
+        })
+        .collect())
+}
+
+/// Builds the completeness details of a series from the rows of [`fetch_series_numbers`].
+fn series_detail(series: Series, numbers: &[(String, Option<String>, bool)]) -> SeriesDetail {
+    let series_id = series.id.to_string();
+    let titles: Vec<_> = numbers.iter().filter(|(id, _, _)| *id == series_id).collect();
+    let owned: Vec<&str> = titles
+        .iter()
+        .filter(|(_, _, owned)| *owned)
+        .filter_map(|(_, number, _)| number.as_deref())
+        .collect();
+
+    SeriesDetail {
+        title_count: titles.len() as i64,
+        owned_count: titles.iter().filter(|(_, _, owned)| *owned).count() as i64,
+        missing_numbers: missing_series_numbers(owned, series.total_volumes),
+        series,
+    }
+}
+
 /// Lists all series with their associated title counts.
 ///
 /// # Endpoint
@@ -33,15 +89,14 @@ use crate::AppState;
 ///
 /// # Description
 ///
-/// Retrieves all series ordered alphabetically by name, with a count of how many
-/// titles belong to each series. Uses a LEFT JOIN to include series even if they
-/// have no titles yet.
+/// Retrieves all series with a count of how many titles belong to each series,
+/// including series without titles yet. Sub-series follow their parent series, so
+/// the list is ordered by full path ("Discworld", "Discworld > City Watch", ...).
 ///
-/// # Query Details
+/// # Query Parameters
 ///
-/// - Uses LEFT JOIN to include series with 0 titles
-/// - Groups by series fields to aggregate title counts
-/// - Orders results by series name (A-Z)
+/// * `include_descendants` - When `true`, `title_count` also counts the titles of
+///   sub-series at any depth
 ///
 /// # Returns
 ///
@@ -56,8 +111,13 @@ use crate::AppState;
 ///     "id": "series-uuid",
 ///     "name": "Asterix",
 ///     "description": "French comic book series about Gaulish warriors",
+///     "parent_id": null,
+///     "total_volumes": 40,
+///     "reading_order": null,
 ///     "created_at": 1699564800,
 ///     "updated_at": 1699564800,
+///     "full_path": "Asterix",
+///     "level": 0,
 ///     "title_count": 38
 ///   }
 /// ]
@@ -68,48 +128,152 @@ use crate::AppState;
 /// - Display series list in management UI
 /// - Show collection statistics
 /// - Populate series dropdown for title assignment
-pub async fn list_series(data: web::Data<AppState>) -> impl Responder {
-    // Query to get all series with their title counts
+pub async fn list_series(
+    data: web::Data<AppState>,
+    params: web::Query<SeriesListParams>,
+) -> impl Responder {
+    match fetch_series_with_counts(&data.db_pool).await {
+        Ok(series) => {
+            let tree = SeriesTreeNode::build_tree(series);
+            let include_descendants = params.include_descendants.unwrap_or(false);
+            HttpResponse::Ok().json(SeriesTreeNode::flatten(tree, include_descendants))
+        }
+        Err(e) => {
+            eprintln!("Failed to fetch series: {:?}", e);
+            HttpResponse::InternalServerError().body("Failed to fetch series")
+        }
+    }
+}
+
+/// Returns the series hierarchy as a tree.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/series/tree`
+///
+/// # Description
+///
+/// Root series are returned in alphabetical order, each with its sub-series in
+/// `children`. Every node has its `full_path` (e.g. "Star Wars > The High Republic"),
+/// its own `title_count` and a `total_title_count` including its sub-series.
+///
+/// # Returns
+///
+/// * `200 OK` - Array of SeriesTreeNode objects
+/// * `500 Internal Server Error` - Database query failed
+pub async fn get_series_tree(data: web::Data<AppState>) -> impl Responder {
+    match fetch_series_with_counts(&data.db_pool).await {
+        Ok(series) => HttpResponse::Ok().json(SeriesTreeNode::build_tree(series)),
+        Err(e) => {
+            eprintln!("Failed to fetch series: {:?}", e);
+            HttpResponse::InternalServerError().body("Failed to fetch series")
+        }
+    }
+}
+
+/// Loads all series with the number of titles in each one, not counting sub-series.
+async fn fetch_series_with_counts(pool: &MySqlPool) -> Result<Vec<SeriesWithTitleCount>, sqlx::Error> {
+    // LEFT JOIN so that series without titles are listed too
     let query = r#"
         SELECT
             s.id,
             s.name,
             s.description,
+            s.parent_id,
+            s.total_volumes,
+            s.reading_order,
             s.created_at,
             s.updated_at,
             COUNT(t.id) as title_count
         FROM series s
-        LEFT JOIN titles t ON s.id = t.series_id
-        GROUP BY s.id, s.name, s.description, s.created_at, s.updated_at
-        ORDER BY s.name ASC
+        LEFT JOIN titles t ON s.id = t.series_id AND t.deleted_at IS NULL
+        GROUP BY s.id, s.name, s.description, s.parent_id, s.total_volumes, s.reading_order, s.created_at, s.updated_at
     "#;
 
-    match sqlx::query_as::<_, (String, String, Option<String>, chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>, i64)>(query)
-        .fetch_all(&data.db_pool)
+    let rows = sqlx::query(query).fetch_all(pool).await?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let id: String = row.get("id");
+            Some(SeriesWithTitleCount {
+                series: Series {
+                    id: Uuid::parse_str(&id).ok()?,
+                    name: row.get("name"),
+                    description: row.get("description"),
+                    parent_id: row.get("parent_id"),
+                    total_volumes: row.get("total_volumes"),
+                    reading_order: row.get("reading_order"),
+                    created_at: row.get("created_at"),
+                    updated_at: row.get("updated_at"),
+                },
+                full_path: String::new(),
+                level: 0,
+                title_count: row.get("title_count"),
+            })
+        })
+        .collect())
+}
+
+/// UUIDs of a series and all its sub-series, at any depth.
+async fn series_with_descendants(pool: &MySqlPool, series_id: &str) -> Result<Vec<String>, sqlx::Error> {
+    let query = r#"
+        WITH RECURSIVE descendants AS (
+            SELECT id FROM series WHERE id = ?
+            UNION
+            SELECT s.id FROM series s
+            INNER JOIN descendants d ON s.parent_id = d.id
+        )
+        SELECT id FROM descendants
+    "#;
+
+    sqlx::query_scalar(query).bind(series_id).fetch_all(pool).await
+}
+
+/// Checks that `parent_id` can become the parent of `series_id` (`None` for a new series).
+///
+/// The parent must exist, and must not be the series itself or one of its
+/// sub-series, which would create a cycle.
+async fn validate_parent(
+    pool: &MySqlPool,
+    series_id: Option<&str>,
+    parent_id: &str,
+) -> Result<(), HttpResponse> {
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM series WHERE id = ?")
+        .bind(parent_id)
+        .fetch_one(pool)
         .await
     {
-        Ok(rows) => {
-            let series_with_counts: Vec<SeriesWithTitleCount> = rows
-                .into_iter()
-                .map(|(id, name, description, created_at, updated_at, title_count)| {
-                    SeriesWithTitleCount {
-                        series: Series {
-                            id: Uuid::parse_str(&id).unwrap(),
-                            name,
-                            description,
-                            created_at,
-                            updated_at,
-                        },
-                        title_count,
-                    }
-                })
-                .collect();
+        Ok(0) => {
+            return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": {
+                    "code": "INVALID_PARENT",
+                    "message": "Parent series not found"
+                }
+            })));
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to check parent series: {:?}", e);
+            return Err(HttpResponse::InternalServerError().body("Failed to check parent series"));
+        }
+    }
 
-            HttpResponse::Ok().json(series_with_counts)
+    let Some(series_id) = series_id else {
+        return Ok(());
+    };
+    match series_with_descendants(pool, series_id).await {
+        Ok(descendants) if descendants.iter().any(|id| id == parent_id) => {
+            Err(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": {
+                    "code": "CIRCULAR_HIERARCHY",
+                    "message": "A series cannot be moved under itself or one of its sub-series"
+                }
+            })))
         }
+        Ok(_) => Ok(()),
         Err(e) => {
-            eprintln!("Failed to fetch series: {:?}", e);
-            HttpResponse::InternalServerError().body("Failed to fetch series")
+            eprintln!("Failed to check series hierarchy: {:?}", e);
+            Err(HttpResponse::InternalServerError().body("Failed to check series hierarchy"))
         }
     }
 }
@@ -126,7 +290,7 @@ pub async fn list_series(data: web::Data<AppState>) -> impl Responder {
 ///
 /// # Returns
 ///
-/// * `200 OK` - Series object
+/// * `200 OK` - SeriesDetail object
 /// * `404 Not Found` - Series doesn't exist
 /// * `500 Internal Server Error` - Database query failed
 ///
@@ -137,11 +301,22 @@ pub async fn list_series(data: web::Data<AppState>) -> impl Responder {
 ///   "id": "series-uuid",
 ///   "name": "Harry Potter",
 ///   "description": "Fantasy series by J.K. Rowling",
+///   "total_volumes": 7,
+///   "reading_order": null,
 ///   "created_at": 1699564800,
-///   "updated_at": 1699564800
+///   "updated_at": 1699564800,
+///   "title_count": 6,
+///   "owned_count": 5,
+///   "missing_numbers": [4, 7]
 /// }
 /// ```
 ///
+/// # Missing Numbers
+///
+/// Whole numbers from 1 to `total_volumes` that no owned title of the series carries.
+/// Titles without volumes (e.g. on the wishlist) don't count as owned. When
+/// `total_volumes` is unknown, the highest owned number ends the range.
+///
 /// # Use Cases
 ///
 /// - Display series details page
@@ -150,18 +325,81 @@ pub async fn list_series(data: web::Data<AppState>) -> impl Responder {
 pub async fn get_series(data: web::Data<AppState>, series_id: web::Path<String>) -> impl Responder {
     let series_id = series_id.into_inner();
 
-    match sqlx::query_as::<_, Series>("SELECT * FROM series WHERE id = ?")
+    let series = match sqlx::query_as::<_, Series>("SELECT * FROM series WHERE id = ?")
         .bind(&series_id)
         .fetch_one(&data.db_pool)
         .await
     {
-        Ok(series) => HttpResponse::Ok().json(series),
+        Ok(series) => series,
         Err(sqlx::Error::RowNotFound) => {
-            HttpResponse::NotFound().body(format!("Series with id {} not found", series_id))
+            return HttpResponse::NotFound().body(format!("Series with id {} not found", series_id));
         }
         Err(e) => {
             eprintln!("Failed to fetch series: {:?}", e);
-            HttpResponse::InternalServerError().body("Failed to fetch series")
+            return HttpResponse::InternalServerError().body("Failed to fetch series");
+        }
+    };
+
+    match fetch_series_numbers(&data.db_pool, Some(&series_id)).await {
+        Ok(numbers) => HttpResponse::Ok().json(series_detail(series, &numbers)),
+        Err(e) => {
+            eprintln!("Failed to fetch series titles: {:?}", e);
+            HttpResponse::InternalServerError().body("Failed to fetch series titles")
+        }
+    }
+}
+
+/// Lists the series with missing volumes, to drive purchases.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/series/missing`
+///
+/// # Returns
+///
+/// * `200 OK` - Array of SeriesDetail objects with at least one missing number, ordered by name
+/// * `500 Internal Server Error` - Database query failed
+///
+/// # Success Response
+///
+/// ```json
+/// [
+///   {
+///     "id": "series-uuid",
+///     "name": "Asterix",
+///     "total_volumes": 40,
+///     "title_count": 37,
+///     "owned_count": 36,
+///     "missing_numbers": [12, 29, 33, 40],
+///     ...
+///   }
+/// ]
+/// ```
+pub async fn list_missing_volumes(data: web::Data<AppState>) -> impl Responder {
+    let series = match sqlx::query_as::<_, Series>("SELECT * FROM series ORDER BY name ASC")
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(series) => series,
+        Err(e) => {
+            eprintln!("Failed to fetch series: {:?}", e);
+            return HttpResponse::InternalServerError().body("Failed to fetch series");
+        }
+    };
+
+    match fetch_series_numbers(&data.db_pool, None).await {
+        Ok(numbers) => {
+            let incomplete: Vec<SeriesDetail> = series
+                .into_iter()
+                .map(|series| series_detail(series, &numbers))
+                .filter(|detail| !detail.missing_numbers.is_empty())
+                .collect();
+
+            HttpResponse::Ok().json(incomplete)
+        }
+        Err(e) => {
+            eprintln!("Failed to fetch series titles: {:?}", e);
+            HttpResponse::InternalServerError().body("Failed to fetch series titles")
         }
     }
 }
@@ -177,19 +415,25 @@ pub async fn get_series(data: web::Data<AppState>, series_id: web::Path<String>)
 /// ```json
 /// {
 ///   "name": "Lord of the Rings",
-///   "description": "Epic fantasy trilogy by J.R.R. Tolkien"
+///   "description": "Epic fantasy trilogy by J.R.R. Tolkien",
+///   "parent_id": null,
+///   "total_volumes": 3,
+///   "reading_order": null
 /// }
 /// ```
 ///
 /// # Validation
 ///
 /// - Name is required and must not be empty
-/// - Description is optional
+/// - Description, parent series, total volumes and reading order are optional
+/// - Total volumes must be between 1 and `MAX_SERIES_VOLUMES` (10 000)
+/// - The parent series must exist
 /// - No duplicate name validation (multiple series can have similar names)
 ///
 /// # Returns
 ///
 /// * `201 Created` - Series created successfully with new ID
+/// * `400 Bad Request` - Total volumes not between 1 and `MAX_SERIES_VOLUMES`, or the parent series does not exist
 /// * `500 Internal Server Error` - Database insert failed
 ///
 /// # Success Response
@@ -208,17 +452,32 @@ pub async fn create_series(
     data: web::Data<AppState>,
     request: web::Json<CreateSeriesRequest>,
 ) -> impl Responder {
+    if request.total_volumes.is_some_and(|total| !(1..=MAX_SERIES_VOLUMES).contains(&total)) {
+        return HttpResponse::BadRequest()
+            .body(format!("Total volumes must be between 1 and {}", MAX_SERIES_VOLUMES));
+    }
+
+    let parent_id = request.parent_id.as_deref().filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id
+        && let Err(response) = validate_parent(&data.db_pool, None, parent_id).await
+    {
+        return response;
+    }
+
     let series_id = Uuid::new_v4().to_string();
 
     let query = r#"
-        INSERT INTO series (id, name, description)
-        VALUES (?, ?, ?)
+        INSERT INTO series (id, name, description, parent_id, total_volumes, reading_order)
+        VALUES (?, ?, ?, ?, ?, ?)
     "#;
 
     match sqlx::query(query)
         .bind(&series_id)
         .bind(&request.name)
         .bind(&request.description)
+        .bind(parent_id)
+        .bind(request.total_volumes)
+        .bind(&request.reading_order)
         .execute(&data.db_pool)
         .await
     {
@@ -247,7 +506,8 @@ pub async fn create_series(
 /// ```json
 /// {
 ///   "name": "Updated Series Name",
-///   "description": "Updated description"
+///   "description": "Updated description",
+///   "total_volumes": 12
 /// }
 /// ```
 ///
@@ -255,11 +515,15 @@ pub async fn create_series(
 ///
 /// - At least one field must be provided
 /// - Returns 400 Bad Request if request body is empty
+/// - Total volumes must be between 1 and `MAX_SERIES_VOLUMES` (10 000)
+/// - An empty `parent_id` moves the series to the root; otherwise the parent must
+///   exist and must not be the series itself or one of its sub-series
 ///
 /// # Returns
 ///
 /// * `200 OK` - Series updated successfully
-/// * `400 Bad Request` - No fields provided to update
+/// * `400 Bad Request` - No fields provided to update, total volumes out of range, or an
+///   invalid parent series (`INVALID_PARENT`, `CIRCULAR_HIERARCHY`)
 /// * `404 Not Found` - Series doesn't exist
 /// * `500 Internal Server Error` - Database update failed
 ///
@@ -292,10 +556,38 @@ pub async fn update_series(
         has_updates = true;
     }
 
+    if request.parent_id.is_some() {
+        query_parts.push("parent_id = ?");
+        has_updates = true;
+    }
+
+    if request.total_volumes.is_some() {
+        query_parts.push("total_volumes = ?");
+        has_updates = true;
+    }
+
+    if request.reading_order.is_some() {
+        query_parts.push("reading_order = ?");
+        has_updates = true;
+    }
+
     if !has_updates {
         return HttpResponse::BadRequest().body("No fields to update");
     }
 
+    if request.total_volumes.is_some_and(|total| !(1..=MAX_SERIES_VOLUMES).contains(&total)) {
+        return HttpResponse::BadRequest()
+            .body(format!("Total volumes must be between 1 and {}", MAX_SERIES_VOLUMES));
+    }
+
+    // An empty parent_id moves the series back to the root
+    let parent_id = request.parent_id.as_deref().filter(|id| !id.is_empty());
+    if let Some(parent_id) = parent_id
+        && let Err(response) = validate_parent(&data.db_pool, Some(&series_id), parent_id).await
+    {
+        return response;
+    }
+
     let query = format!(
         "UPDATE series SET {} WHERE id = ?",
         query_parts.join(", ")
@@ -311,6 +603,18 @@ pub async fn update_series(
         query_builder = query_builder.bind(description);
     }
 
+    if request.parent_id.is_some() {
+        query_builder = query_builder.bind(parent_id);
+    }
+
+    if let Some(total_volumes) = request.total_volumes {
+        query_builder = query_builder.bind(total_volumes);
+    }
+
+    if let Some(ref reading_order) = request.reading_order {
+        query_builder = query_builder.bind(reading_order);
+    }
+
     query_builder = query_builder.bind(&series_id);
 
     match query_builder.execute(&data.db_pool).await {
@@ -347,12 +651,14 @@ pub async fn update_series(
 ///
 /// 1. **Check Title Count**: Query how many titles reference this series
 /// 2. **Validate Deletion**: If count > 0, reject with 400 Bad Request
-/// 3. **Delete Series**: Only if no titles are associated
+/// 3. **Check Sub-series**: Reject with 409 Conflict if other series are nested in it
+/// 4. **Delete Series**: Only if no titles or sub-series are associated
 ///
 /// # Returns
 ///
 /// * `200 OK` - Series deleted successfully
 /// * `400 Bad Request` - Series has associated titles (cannot delete)
+/// * `409 Conflict` - Series has sub-series (`HAS_CHILD_SERIES`)
 /// * `404 Not Found` - Series doesn't exist
 /// * `500 Internal Server Error` - Database query failed
 ///
@@ -397,6 +703,27 @@ pub async fn delete_series(data: web::Data<AppState>, series_id: web::Path<Strin
         _ => {}
     }
 
+    // Check if other series are nested in it
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM series WHERE parent_id = ?")
+        .bind(&series_id)
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(count) if count > 0 => {
+            return HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "HAS_CHILD_SERIES",
+                    "message": format!("Cannot delete series: it has {} sub-series. Delete or move them first.", count)
+                }
+            }));
+        }
+        Err(e) => {
+            eprintln!("Failed to check sub-series: {:?}", e);
+            return HttpResponse::InternalServerError().body("Failed to check sub-series");
+        }
+        _ => {}
+    }
+
     // Delete the series
     match sqlx::query("DELETE FROM series WHERE id = ?")
         .bind(&series_id)
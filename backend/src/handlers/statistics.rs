@@ -9,6 +9,8 @@ use log::{debug, error};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::auth::AuthenticatedUser;
+use crate::models::ReadingListParams;
 use crate::AppState;
 
 /// Statistics for volumes per genre
@@ -16,6 +18,8 @@ use crate::AppState;
 pub struct GenreStatistic {
     pub genre_id: Option<String>,
     pub genre_name: String,
+    /// Parent genre, `None` for a root genre
+    pub parent_id: Option<String>,
     pub volume_count: i64,
     pub title_count: i64,
 }
@@ -36,10 +40,22 @@ pub struct LoanStatistic {
     pub count: i64,
 }
 
+/// Finished readings per original language, read in the original or in translation
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct TranslationStatistic {
+    /// Language the work was written in: the original language of a translation,
+    /// the title's own language otherwise
+    pub original_language: String,
+    pub original_count: i64,
+    pub translated_count: i64,
+}
+
 /// Overall library statistics
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LibraryStatistics {
     pub total_titles: i64,
+    /// Distinct works; a title not attached to a work counts as one work
+    pub total_works: i64,
     pub total_volumes: i64,
     pub total_authors: i64,
     pub total_publishers: i64,
@@ -57,6 +73,11 @@ pub struct LibraryStatistics {
 /// Returns a list of genres with their volume and title counts, ordered by volume count descending.
 /// Useful for visualizing the collection distribution across genres.
 ///
+/// Only primary genres are counted, so every volume is counted once per genre.
+/// Counts roll up the hierarchy: a parent genre includes the titles and volumes
+/// of all its sub-genres, so the shares of the root genres (`parent_id` null)
+/// add up to the whole collection.
+///
 /// # Arguments
 ///
 /// * `data` - Application state containing the database connection pool
@@ -69,15 +90,27 @@ pub async fn get_volumes_per_genre(data: web::Data<AppState>) -> impl Responder
     debug!("Fetching volumes per genre statistics");
 
     let query = r#"
+        WITH RECURSIVE genre_tree AS (
+            -- Every genre paired with itself and each of its descendants
+            SELECT id as ancestor_id, id as genre_id
+            FROM genres
+            UNION ALL
+            SELECT gt.ancestor_id, g.id
+            FROM genres g
+            INNER JOIN genre_tree gt ON g.parent_id = gt.genre_id
+        )
         SELECT
             g.id as genre_id,
             g.name as genre_name,
+            g.parent_id,
             COUNT(DISTINCT t.id) as title_count,
-            COUNT(v.id) as volume_count
+            COUNT(DISTINCT v.id) as volume_count
         FROM genres g
-        LEFT JOIN titles t ON t.genre_id = g.id
+        INNER JOIN genre_tree gt ON gt.ancestor_id = g.id
+        LEFT JOIN title_genres tg ON tg.genre_id = gt.genre_id AND tg.is_primary
+        LEFT JOIN titles t ON t.id = tg.title_id AND t.deleted_at IS NULL
         LEFT JOIN volumes v ON v.title_id = t.id
-        GROUP BY g.id, g.name
+        GROUP BY g.id, g.name, g.parent_id
         ORDER BY volume_count DESC, title_count DESC
     "#;
 
@@ -233,17 +266,24 @@ pub async fn get_library_statistics(data: web::Data<AppState>) -> impl Responder
     debug!("Fetching library statistics");
 
     // Get counts for all entities
-    let total_titles = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles")
+    let total_titles = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE deleted_at IS NULL")
         .fetch_one(&data.db_pool)
         .await
         .unwrap_or(0);
 
+    let total_works = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(DISTINCT COALESCE(work_id, id)) FROM titles WHERE deleted_at IS NULL"
+    )
+    .fetch_one(&data.db_pool)
+    .await
+    .unwrap_or(0);
+
     let total_volumes = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM volumes")
         .fetch_one(&data.db_pool)
         .await
         .unwrap_or(0);
 
-    let total_authors = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM authors")
+    let total_authors = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM authors WHERE deleted_at IS NULL")
         .fetch_one(&data.db_pool)
         .await
         .unwrap_or(0);
@@ -263,7 +303,7 @@ pub async fn get_library_statistics(data: web::Data<AppState>) -> impl Responder
         .await
         .unwrap_or(0);
 
-    let total_borrowers = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM borrowers")
+    let total_borrowers = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM borrowers WHERE deleted_at IS NULL")
         .fetch_one(&data.db_pool)
         .await
         .unwrap_or(0);
@@ -284,6 +324,7 @@ pub async fn get_library_statistics(data: web::Data<AppState>) -> impl Responder
 
     let stats = LibraryStatistics {
         total_titles,
+        total_works,
         total_volumes,
         total_authors,
         total_publishers,
@@ -297,3 +338,66 @@ pub async fn get_library_statistics(data: web::Data<AppState>) -> impl Responder
     debug!("Library statistics: {:?}", stats);
     HttpResponse::Ok().json(stats)
 }
+
+/// Retrieves finished readings split between originals and translations.
+///
+/// **Endpoint**: `GET /api/v1/statistics/translations`
+///
+/// A reading counts as translated when the title has an `original_language`
+/// different from its `language`. Readings are grouped by the language the
+/// work was written in, most read first.
+///
+/// # Query Parameters
+///
+/// * `user_id` - Reader to count (default: the current user)
+/// * `year` - Only readings finished during this year (default: all years)
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TranslationStatistic` objects on success
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn get_translation_statistics(
+    data: web::Data<AppState>,
+    params: web::Query<ReadingListParams>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    debug!("Fetching translation reading statistics");
+
+    let user_id = params.user_id.clone().unwrap_or_else(|| user.id.clone());
+    let year_filter = if params.year.is_some() { "AND YEAR(rr.finished_on) = ?" } else { "" };
+    let query = format!(
+        r#"
+        SELECT
+            COALESCE(t.original_language, t.language) as original_language,
+            CAST(SUM(CASE WHEN t.original_language IS NULL OR t.original_language = t.language
+                     THEN 1 ELSE 0 END) AS SIGNED) as original_count,
+            CAST(SUM(CASE WHEN t.original_language IS NOT NULL AND t.original_language <> t.language
+                     THEN 1 ELSE 0 END) AS SIGNED) as translated_count
+        FROM reading_records rr
+        INNER JOIN titles t ON rr.title_id = t.id
+        WHERE rr.user_id = ? AND rr.status = 'read' AND t.deleted_at IS NULL {}
+        GROUP BY COALESCE(t.original_language, t.language)
+        ORDER BY COUNT(*) DESC, original_language
+    "#,
+        year_filter
+    );
+
+    let mut db_query = sqlx::query_as::<_, TranslationStatistic>(&query).bind(&user_id);
+    if let Some(year) = params.year {
+        db_query = db_query.bind(year);
+    }
+
+    match db_query.fetch_all(&data.db_pool).await {
+        Ok(stats) => {
+            debug!("Found {} translation statistics", stats.len());
+            HttpResponse::Ok().json(stats)
+        }
+        Err(e) => {
+            error!("Failed to fetch translation statistics: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": "Failed to fetch translation statistics",
+                "details": e.to_string()
+            }))
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! API handlers for free-form tags on titles.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/tags` - List all tags with usage counts (tag cloud)
+//! - `PUT /api/v1/tags/{id}` - Rename a tag
+//! - `DELETE /api/v1/tags/{id}` - Delete a tag and remove it from all titles
+//! - `GET /api/v1/titles/{title_id}/tags` - List the tags of a title
+//! - `POST /api/v1/titles/{title_id}/tags` - Tag a title, creating the tag if needed
+//! - `DELETE /api/v1/titles/{title_id}/tags/{tag_id}` - Remove a tag from a title
+//!
+//! Tag names are normalized with `shared::models::tags::normalize_tag_name` and
+//! compared case-insensitively by the database collation.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{AddTagToTitleRequest, RenameTagRequest, Tag, TagWithTitleCount};
+use crate::AppState;
+use log::{info, warn, error};
+use shared::models::tags::{normalize_tag_name, MAX_TAG_LENGTH};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+fn tag_from_row(row: &MySqlRow) -> Option<Tag> {
+    let id: String = row.get("id");
+    Some(Tag {
+        id: Uuid::parse_str(&id).ok()?,
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+/// Builds the 400 response for a tag name [`normalize_tag_name`] rejected.
+fn invalid_tag_response(name: &str) -> HttpResponse {
+    warn!("Rejected invalid tag name '{}'", name);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "INVALID_TAG",
+            "message": format!("A tag name must contain 1 to {} characters", MAX_TAG_LENGTH)
+        }
+    }))
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+/// Lists all tags with the number of titles carrying each one.
+///
+/// **Endpoint**: `GET /api/v1/tags`
+///
+/// Tags no longer used by any title are included with a count of 0.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TagWithTitleCount` objects, ordered by name
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_tags(data: web::Data<AppState>) -> impl Responder {
+    info!("GET /api/v1/tags - Fetching tags with usage counts");
+
+    let query = r#"
+        SELECT tg.id, tg.name, tg.created_at, tg.updated_at, COUNT(t.id) as title_count
+        FROM tags tg
+        LEFT JOIN title_tags tt ON tg.id = tt.tag_id
+        LEFT JOIN titles t ON tt.title_id = t.id AND t.deleted_at IS NULL
+        GROUP BY tg.id, tg.name, tg.created_at, tg.updated_at
+        ORDER BY tg.name ASC
+    "#;
+
+    match sqlx::query(query).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let tags: Vec<TagWithTitleCount> = rows
+                .iter()
+                .filter_map(|row| {
+                    Some(TagWithTitleCount {
+                        tag: tag_from_row(row)?,
+                        title_count: row.get("title_count"),
+                    })
+                })
+                .collect();
+            info!("Found {} tags", tags.len());
+            HttpResponse::Ok().json(tags)
+        }
+        Err(e) => database_error("Failed to fetch tags", e),
+    }
+}
+
+/// Renames a tag on every title carrying it.
+///
+/// **Endpoint**: `PUT /api/v1/tags/{id}`
+///
+/// # Request Body
+///
+/// ```json
+/// { "name": "to-read-2027" }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the tag was renamed
+/// * `HttpResponse::BadRequest` (400) if the name is empty or too long
+/// * `HttpResponse::NotFound` (404) if the tag does not exist
+/// * `HttpResponse::Conflict` (409) if another tag already has this name
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn rename_tag(
+    data: web::Data<AppState>,
+    tag_id: web::Path<String>,
+    req: web::Json<RenameTagRequest>,
+) -> impl Responder {
+    info!("PUT /api/v1/tags/{} - Renaming tag to '{}'", tag_id, req.name);
+
+    let Some(name) = normalize_tag_name(&req.name) else {
+        return invalid_tag_response(&req.name);
+    };
+
+    let exists = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM tags WHERE id = ?")
+        .bind(tag_id.as_str())
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(count) => count > 0,
+        Err(e) => return database_error("Failed to check tag", e),
+    };
+    if !exists {
+        warn!("Tag {} not found", tag_id);
+        return HttpResponse::NotFound().json(serde_json::json!({
+            "error": { "code": "NOT_FOUND", "message": "Tag not found" }
+        }));
+    }
+
+    match sqlx::query("UPDATE tags SET name = ? WHERE id = ?")
+        .bind(&name)
+        .bind(tag_id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully renamed tag {} to '{}'", tag_id, name);
+            HttpResponse::Ok().json(serde_json::json!({
+                "name": name,
+                "message": "Tag renamed successfully"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            warn!("Tag '{}' already exists", name);
+            HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "DUPLICATE_TAG",
+                    "message": format!("A tag named '{}' already exists", name)
+                }
+            }))
+        }
+        Err(e) => database_error("Failed to rename tag", e),
+    }
+}
+
+/// Deletes a tag and removes it from all titles.
+///
+/// **Endpoint**: `DELETE /api/v1/tags/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the tag was deleted
+/// * `HttpResponse::NotFound` (404) if the tag does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_tag(data: web::Data<AppState>, tag_id: web::Path<String>) -> impl Responder {
+    info!("DELETE /api/v1/tags/{} - Deleting tag", tag_id);
+
+    match sqlx::query("DELETE FROM tags WHERE id = ?")
+        .bind(tag_id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Tag {} not found", tag_id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Tag not found" }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully deleted tag {}", tag_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Tag deleted successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to delete tag", e),
+    }
+}
+
+/// Lists the tags of a title.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/tags`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `Tag` objects, ordered by name
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_tags(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/tags - Fetching tags", title_id);
+
+    let query = r#"
+        SELECT tg.id, tg.name, tg.created_at, tg.updated_at
+        FROM tags tg
+        INNER JOIN title_tags tt ON tg.id = tt.tag_id
+        WHERE tt.title_id = ?
+        ORDER BY tg.name ASC
+    "#;
+
+    match sqlx::query(query)
+        .bind(title_id.as_str())
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(rows) => {
+            let tags: Vec<Tag> = rows.iter().filter_map(tag_from_row).collect();
+            info!("Found {} tags for title {}", tags.len(), title_id);
+            HttpResponse::Ok().json(tags)
+        }
+        Err(e) => database_error("Failed to fetch title tags", e),
+    }
+}
+
+/// Tags a title, creating the tag if no tag with this name exists yet.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/tags`
+///
+/// # Request Body
+///
+/// ```json
+/// { "name": "gift from grandma" }
+/// ```
+///
+/// Tagging a title with a tag it already has is not an error.
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the `Tag`
+/// * `HttpResponse::BadRequest` (400) if the name is empty or too long
+/// * `HttpResponse::NotFound` (404) if the title does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn add_tag_to_title(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<AddTagToTitleRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/titles/{}/tags - Adding tag '{}'", title_id, req.name);
+
+    let Some(name) = normalize_tag_name(&req.name) else {
+        return invalid_tag_response(&req.name);
+    };
+
+    let title_exists = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(title_id.as_str())
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(count) => count > 0,
+        Err(e) => return database_error("Failed to check title", e),
+    };
+    if !title_exists {
+        warn!("Title {} not found", title_id);
+        return HttpResponse::NotFound().json(serde_json::json!({
+            "error": { "code": "NOT_FOUND", "message": "Title not found" }
+        }));
+    }
+
+    // Create the tag unless a tag with the same name (ignoring case) exists
+    if let Err(e) = sqlx::query("INSERT IGNORE INTO tags (id, name) VALUES (?, ?)")
+        .bind(Uuid::new_v4().to_string())
+        .bind(&name)
+        .execute(&data.db_pool)
+        .await
+    {
+        return database_error("Failed to create tag", e);
+    }
+
+    let tag = match sqlx::query("SELECT id, name, created_at, updated_at FROM tags WHERE name = ?")
+        .bind(&name)
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(row) => match tag_from_row(&row) {
+            Some(tag) => tag,
+            None => {
+                error!("Tag '{}' has an invalid id", name);
+                return HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": { "code": "DATABASE_ERROR", "message": "Failed to load tag" }
+                }));
+            }
+        },
+        Err(e) => return database_error("Failed to load tag", e),
+    };
+
+    match sqlx::query("INSERT IGNORE INTO title_tags (title_id, tag_id) VALUES (?, ?)")
+        .bind(title_id.as_str())
+        .bind(tag.id.to_string())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully tagged title {} with '{}'", title_id, tag.name);
+            HttpResponse::Created().json(tag)
+        }
+        Err(e) => database_error("Failed to tag title", e),
+    }
+}
+
+/// Removes a tag from a title. The tag itself is kept.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/tags/{tag_id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the tag was removed from the title
+/// * `HttpResponse::NotFound` (404) if the title does not carry this tag
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn remove_tag_from_title(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (title_id, tag_id) = path.into_inner();
+    info!("DELETE /api/v1/titles/{}/tags/{} - Removing tag", title_id, tag_id);
+
+    match sqlx::query("DELETE FROM title_tags WHERE title_id = ? AND tag_id = ?")
+        .bind(&title_id)
+        .bind(&tag_id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Title {} is not tagged with {}", title_id, tag_id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Title does not have this tag" }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully removed tag {} from title {}", tag_id, title_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Tag removed from title successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to remove tag from title", e),
+    }
+}
@@ -0,0 +1,552 @@
+//! API handlers for the contents of anthologies and collections.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/titles/{title_id}/contents` - List the contents of a title
+//! - `POST /api/v1/titles/{title_id}/contents` - Add a story to the contents
+//! - `PUT /api/v1/titles/{title_id}/contents/{id}` - Replace a contents entry
+//! - `DELETE /api/v1/titles/{title_id}/contents/{id}` - Remove a contents entry
+//!
+//! Each entry has its own authors, independent of the authors of the title.
+//! Title searches look into the contents: `q` matches story titles and the names
+//! of their authors, and `author_id` also finds the titles containing a story
+//! by that author.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{AuthorRole, TitleContent, TitleContentAuthor, TitleContentRequest};
+use crate::AppState;
+use log::{info, warn, error};
+use sqlx::{MySqlConnection, MySqlPool, Row};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Maximum length of the title of a contents entry, as in the `title_contents.title` column.
+const MAX_CONTENT_TITLE_LENGTH: usize = 500;
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn not_found(message: &str) -> HttpResponse {
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": { "code": "NOT_FOUND", "message": message }
+    }))
+}
+
+fn validation_error(message: &str) -> HttpResponse {
+    warn!("Rejected contents entry: {}", message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": { "code": "VALIDATION_ERROR", "message": message }
+    }))
+}
+
+/// A contents entry checked against its title and the authors.
+struct ValidContent {
+    title: String,
+    authors: Vec<(String, AuthorRole)>,
+}
+
+/// Returns the page count of a title, or the 404 response if it does not exist.
+async fn fetch_title_pages(pool: &MySqlPool, title_id: &str) -> Result<Option<i32>, HttpResponse> {
+    match sqlx::query_scalar::<_, Option<i32>>("SELECT pages FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(title_id)
+        .fetch_optional(pool)
+        .await
+    {
+        Ok(Some(pages)) => Ok(pages),
+        Ok(None) => {
+            warn!("Title {} not found", title_id);
+            Err(not_found("Title not found"))
+        }
+        Err(e) => Err(database_error("Failed to check title", e)),
+    }
+}
+
+/// Checks the fields of a contents entry that need no database, or builds the 400 response.
+///
+/// The page must lie within the title when its page count is known. Repeated
+/// author/role pairs are dropped.
+fn check_content(req: &TitleContentRequest, pages: Option<i32>) -> Result<ValidContent, Box<HttpResponse>> {
+    let title = req.title.trim();
+    if title.is_empty() || title.chars().count() > MAX_CONTENT_TITLE_LENGTH {
+        return Err(Box::new(validation_error(&format!(
+            "The title of a contents entry must contain 1 to {} characters",
+            MAX_CONTENT_TITLE_LENGTH
+        ))));
+    }
+    if let Some(page) = req.page
+        && (page < 1 || pages.is_some_and(|pages| page > pages))
+    {
+        warn!("Rejected contents entry on page {} of a title of {:?} pages", page, pages);
+        return Err(Box::new(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_PAGE",
+                "message": "The page must be between 1 and the title's page count",
+                "details": { "page": page, "pages": pages }
+            }
+        }))));
+    }
+
+    let mut seen = HashSet::new();
+    let authors: Vec<(String, AuthorRole)> = req
+        .authors
+        .iter()
+        .map(|author| (author.author_id.trim().to_string(), author.role.clone()))
+        .filter(|(author_id, role)| seen.insert((author_id.clone(), role.to_string())))
+        .collect();
+
+    Ok(ValidContent { title: title.to_string(), authors })
+}
+
+/// Validates a contents entry, or builds the 400 response.
+///
+/// On top of [`check_content`], every author must exist outside the trash.
+async fn validate_content(
+    pool: &MySqlPool,
+    req: &TitleContentRequest,
+    pages: Option<i32>,
+) -> Result<ValidContent, HttpResponse> {
+    let content = check_content(req, pages).map_err(|response| *response)?;
+
+    let author_ids: HashSet<&str> = content.authors.iter().map(|(author_id, _)| author_id.as_str()).collect();
+    if !author_ids.is_empty() {
+        let query = format!(
+            "SELECT COUNT(*) FROM authors WHERE id IN ({}) AND deleted_at IS NULL",
+            vec!["?"; author_ids.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query_scalar::<_, i64>(&query);
+        for author_id in &author_ids {
+            query_builder = query_builder.bind(*author_id);
+        }
+        match query_builder.fetch_one(pool).await {
+            Ok(count) if count == author_ids.len() as i64 => {}
+            Ok(_) => {
+                warn!("Rejected unknown author among {:?}", author_ids);
+                return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": { "code": "INVALID_AUTHOR", "message": "One of the authors does not exist" }
+                })));
+            }
+            Err(e) => return Err(database_error("Failed to check authors", e)),
+        }
+    }
+
+    Ok(content)
+}
+
+/// Loads the contents of a title in order, or a single entry when `content_id` is given.
+async fn fetch_contents(
+    pool: &MySqlPool,
+    title_id: &str,
+    content_id: Option<&str>,
+) -> Result<Vec<TitleContent>, sqlx::Error> {
+    let entry_filter = if content_id.is_some() { " AND tc.id = ?" } else { "" };
+
+    let query = format!(
+        "SELECT tc.id, tc.position, tc.title, tc.page, tc.original_year FROM title_contents tc \
+         WHERE tc.title_id = ?{} ORDER BY tc.position",
+        entry_filter
+    );
+    let mut query_builder = sqlx::query(&query).bind(title_id);
+    if let Some(content_id) = content_id {
+        query_builder = query_builder.bind(content_id);
+    }
+    let rows = query_builder.fetch_all(pool).await?;
+
+    let query = format!(
+        "SELECT tca.content_id, tca.author_id, CONCAT(a.first_name, ' ', a.last_name) AS name, tca.role \
+         FROM title_content_authors tca \
+         INNER JOIN title_contents tc ON tca.content_id = tc.id \
+         INNER JOIN authors a ON tca.author_id = a.id \
+         WHERE tc.title_id = ?{} AND a.deleted_at IS NULL \
+         ORDER BY tca.display_order, a.last_name",
+        entry_filter
+    );
+    let mut query_builder = sqlx::query(&query).bind(title_id);
+    if let Some(content_id) = content_id {
+        query_builder = query_builder.bind(content_id);
+    }
+    let mut authors: HashMap<String, Vec<TitleContentAuthor>> = HashMap::new();
+    for row in query_builder.fetch_all(pool).await? {
+        let author_id: String = row.get("author_id");
+        let role: String = row.get("role");
+        let (Ok(author_id), Some(role)) = (Uuid::parse_str(&author_id), AuthorRole::from_db(&role)) else {
+            continue;
+        };
+        authors.entry(row.get("content_id")).or_default().push(TitleContentAuthor {
+            author_id,
+            name: row.get("name"),
+            role,
+        });
+    }
+
+    let title_uuid = Uuid::parse_str(title_id).unwrap_or_default();
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            let id: String = row.get("id");
+            Some(TitleContent {
+                id: Uuid::parse_str(&id).ok()?,
+                title_id: title_uuid,
+                position: row.get("position"),
+                title: row.get("title"),
+                page: row.get("page"),
+                original_year: row.get("original_year"),
+                authors: authors.remove(&id).unwrap_or_default(),
+            })
+        })
+        .collect())
+}
+
+/// Replaces the authors of a contents entry, keeping their order for display.
+async fn save_content_authors(
+    conn: &mut MySqlConnection,
+    content_id: &str,
+    authors: &[(String, AuthorRole)],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM title_content_authors WHERE content_id = ?")
+        .bind(content_id)
+        .execute(&mut *conn)
+        .await?;
+    for (display_order, (author_id, role)) in authors.iter().enumerate() {
+        sqlx::query("INSERT INTO title_content_authors (content_id, author_id, role, display_order) VALUES (?, ?, ?, ?)")
+            .bind(content_id)
+            .bind(author_id)
+            .bind(role.to_string())
+            .bind(display_order as i32 + 1)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Number of entries in the contents of a title, locking them until the end of the transaction.
+async fn lock_contents(conn: &mut MySqlConnection, title_id: &str) -> Result<i32, sqlx::Error> {
+    let positions: Vec<i32> = sqlx::query_scalar("SELECT position FROM title_contents WHERE title_id = ? FOR UPDATE")
+        .bind(title_id)
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(positions.len() as i32)
+}
+
+/// Lists the contents of a title, in order.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/contents`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TitleContent` objects, ordered by position
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_contents(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/contents - Fetching contents", title_id);
+
+    match fetch_contents(&data.db_pool, &title_id, None).await {
+        Ok(contents) => {
+            info!("Found {} contents entries for title {}", contents.len(), title_id);
+            HttpResponse::Ok().json(contents)
+        }
+        Err(e) => database_error("Failed to fetch contents", e),
+    }
+}
+
+/// Adds a story to the contents of a title.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/contents`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "title": "The Fall of the House of Usher",
+///   "page": 42,
+///   "original_year": 1839,
+///   "authors": [{ "author_id": "author-uuid", "role": "main_author" }]
+/// }
+/// ```
+///
+/// Without `position` the entry goes last; otherwise the following entries move down.
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the `TitleContent`
+/// * `HttpResponse::BadRequest` (400) if the title is empty, the page is outside the title or an author does not exist
+/// * `HttpResponse::NotFound` (404) if the title does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn add_title_content(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<TitleContentRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/titles/{}/contents - Adding '{}'", title_id, req.title);
+
+    let pages = match fetch_title_pages(&data.db_pool, &title_id).await {
+        Ok(pages) => pages,
+        Err(response) => return response,
+    };
+    let content = match validate_content(&data.db_pool, &req, pages).await {
+        Ok(content) => content,
+        Err(response) => return response,
+    };
+
+    let new_id = Uuid::new_v4().to_string();
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to start transaction", e),
+    };
+    let result = async {
+        let count = lock_contents(&mut tx, &title_id).await?;
+        let position = req.position.map_or(count + 1, |position| position.clamp(1, count + 1));
+        sqlx::query("UPDATE title_contents SET position = position + 1 WHERE title_id = ? AND position >= ?")
+            .bind(title_id.as_str())
+            .bind(position)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO title_contents (id, title_id, position, title, page, original_year) VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&new_id)
+        .bind(title_id.as_str())
+        .bind(position)
+        .bind(&content.title)
+        .bind(req.page)
+        .bind(req.original_year)
+        .execute(&mut *tx)
+        .await?;
+        save_content_authors(&mut tx, &new_id, &content.authors).await?;
+        tx.commit().await
+    }
+    .await;
+    if let Err(e) = result {
+        return database_error("Failed to add contents entry", e);
+    }
+
+    match fetch_contents(&data.db_pool, &title_id, Some(&new_id)).await {
+        Ok(mut contents) if !contents.is_empty() => {
+            info!("Successfully added contents entry {} to title {}", new_id, title_id);
+            HttpResponse::Created().json(contents.remove(0))
+        }
+        Ok(_) => database_error("Failed to load contents entry", sqlx::Error::RowNotFound),
+        Err(e) => database_error("Failed to load contents entry", e),
+    }
+}
+
+/// Replaces a contents entry, including its authors.
+///
+/// **Endpoint**: `PUT /api/v1/titles/{title_id}/contents/{id}`
+///
+/// The body is the same as for [`add_title_content`]. Without `position` the entry
+/// keeps its place; otherwise the entries in between shift to make room.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) with the updated `TitleContent`
+/// * `HttpResponse::BadRequest` (400) if the title is empty, the page is outside the title or an author does not exist
+/// * `HttpResponse::NotFound` (404) if the title or the entry does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn update_title_content(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    req: web::Json<TitleContentRequest>,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("PUT /api/v1/titles/{}/contents/{} - Updating contents entry", title_id, id);
+
+    let pages = match fetch_title_pages(&data.db_pool, &title_id).await {
+        Ok(pages) => pages,
+        Err(response) => return response,
+    };
+    let content = match validate_content(&data.db_pool, &req, pages).await {
+        Ok(content) => content,
+        Err(response) => return response,
+    };
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to start transaction", e),
+    };
+    let result: Result<bool, sqlx::Error> = async {
+        let count = lock_contents(&mut tx, &title_id).await?;
+        let current: Option<i32> = sqlx::query_scalar("SELECT position FROM title_contents WHERE id = ? AND title_id = ?")
+            .bind(&id)
+            .bind(&title_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(current) = current else {
+            return Ok(false);
+        };
+
+        let position = req.position.map_or(current, |position| position.clamp(1, count));
+        if position < current {
+            sqlx::query(
+                "UPDATE title_contents SET position = position + 1 WHERE title_id = ? AND position >= ? AND position < ?"
+            )
+            .bind(&title_id)
+            .bind(position)
+            .bind(current)
+            .execute(&mut *tx)
+            .await?;
+        } else if position > current {
+            sqlx::query(
+                "UPDATE title_contents SET position = position - 1 WHERE title_id = ? AND position > ? AND position <= ?"
+            )
+            .bind(&title_id)
+            .bind(current)
+            .bind(position)
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query("UPDATE title_contents SET position = ?, title = ?, page = ?, original_year = ? WHERE id = ?")
+            .bind(position)
+            .bind(&content.title)
+            .bind(req.page)
+            .bind(req.original_year)
+            .bind(&id)
+            .execute(&mut *tx)
+            .await?;
+        save_content_authors(&mut tx, &id, &content.authors).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+    .await;
+    match result {
+        Ok(true) => {}
+        Ok(false) => {
+            warn!("Contents entry {} of title {} not found", id, title_id);
+            return not_found("Contents entry not found");
+        }
+        Err(e) => return database_error("Failed to update contents entry", e),
+    }
+
+    match fetch_contents(&data.db_pool, &title_id, Some(&id)).await {
+        Ok(mut contents) if !contents.is_empty() => {
+            info!("Successfully updated contents entry {}", id);
+            HttpResponse::Ok().json(contents.remove(0))
+        }
+        Ok(_) => database_error("Failed to load contents entry", sqlx::Error::RowNotFound),
+        Err(e) => database_error("Failed to load contents entry", e),
+    }
+}
+
+/// Removes an entry from the contents of a title; the following entries move up.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/contents/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the entry was removed
+/// * `HttpResponse::NotFound` (404) if the title has no such entry
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_title_content(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("DELETE /api/v1/titles/{}/contents/{} - Removing contents entry", title_id, id);
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to start transaction", e),
+    };
+    let result: Result<bool, sqlx::Error> = async {
+        lock_contents(&mut tx, &title_id).await?;
+        let position: Option<i32> = sqlx::query_scalar("SELECT position FROM title_contents WHERE id = ? AND title_id = ?")
+            .bind(&id)
+            .bind(&title_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some(position) = position else {
+            return Ok(false);
+        };
+        sqlx::query("DELETE FROM title_contents WHERE id = ?")
+            .bind(&id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE title_contents SET position = position - 1 WHERE title_id = ? AND position > ?")
+            .bind(&title_id)
+            .bind(position)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+    .await;
+
+    match result {
+        Ok(true) => {
+            info!("Successfully removed contents entry {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Contents entry removed successfully"
+            }))
+        }
+        Ok(false) => {
+            warn!("Contents entry {} of title {} not found", id, title_id);
+            not_found("Contents entry not found")
+        }
+        Err(e) => database_error("Failed to remove contents entry", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use shared::dtos::titles::TitleContentAuthorRequest;
+
+    fn entry(title: &str, page: Option<i32>, authors: &[(&str, AuthorRole)]) -> TitleContentRequest {
+        TitleContentRequest {
+            title: title.to_string(),
+            page,
+            original_year: None,
+            position: None,
+            authors: authors
+                .iter()
+                .map(|(author_id, role)| TitleContentAuthorRequest { author_id: author_id.to_string(), role: role.clone() })
+                .collect(),
+        }
+    }
+
+    async fn error_code(result: Result<ValidContent, Box<HttpResponse>>) -> String {
+        let Err(response) = result else { panic!("the entry should be rejected") };
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = actix_web::body::to_bytes(response.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        json["error"]["code"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_check_content_drops_repeated_authors() {
+        let req = entry(
+            "  The Nine Billion Names of God ",
+            Some(12),
+            &[("a1", AuthorRole::MainAuthor), (" a1 ", AuthorRole::MainAuthor), ("a1", AuthorRole::Translator)],
+        );
+        let Ok(content) = check_content(&req, Some(300)) else { panic!("the entry should be valid") };
+
+        assert_eq!(content.title, "The Nine Billion Names of God");
+        assert_eq!(
+            content.authors,
+            vec![("a1".to_string(), AuthorRole::MainAuthor), ("a1".to_string(), AuthorRole::Translator)]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_check_content_rejects_invalid_entries() {
+        assert_eq!(error_code(check_content(&entry("  ", None, &[]), None)).await, "VALIDATION_ERROR");
+        let long_title = "x".repeat(MAX_CONTENT_TITLE_LENGTH + 1);
+        assert_eq!(error_code(check_content(&entry(&long_title, None, &[]), None)).await, "VALIDATION_ERROR");
+
+        for page in [0, 301] {
+            assert_eq!(error_code(check_content(&entry("Story", Some(page), &[]), Some(300))).await, "INVALID_PAGE");
+        }
+        assert!(check_content(&entry("Story", Some(301), &[]), None).is_ok());
+    }
+}
@@ -0,0 +1,325 @@
+//! API handlers for managing the additional identifiers of a title.
+//!
+//! A title keeps its main `isbn` column; this module manages the other
+//! identifiers a book may have (hardback and paperback ISBNs, EAN, ISSN of the
+//! series, LCCN or OCLC numbers from catalogue records). Values are validated
+//! and normalized with `shared::identifiers` before being stored.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{CreateTitleIdentifierRequest, IdentifierType, TitleIdentifier, UpdateTitleIdentifierRequest};
+use crate::AppState;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use log::{info, warn, error};
+use shared::identifiers;
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+fn identifier_from_row(row: &MySqlRow) -> Option<TitleIdentifier> {
+    let id: String = row.get("id");
+    let title_id: String = row.get("title_id");
+    let identifier_type: String = row.get("identifier_type");
+    let created_at: NaiveDateTime = row.get("created_at");
+
+    Some(TitleIdentifier {
+        id: Uuid::parse_str(&id).ok()?,
+        title_id: Uuid::parse_str(&title_id).ok()?,
+        identifier_type: IdentifierType::from_db(&identifier_type)?,
+        value: row.get("value"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+    })
+}
+
+/// Builds the 400 response for an identifier [`identifiers::normalize`] rejected.
+fn invalid_identifier_response(kind: IdentifierType, value: &str, message: &str) -> HttpResponse {
+    warn!("Rejected invalid {} identifier {}: {}", kind, value, message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "INVALID_IDENTIFIER",
+            "message": format!("Invalid {} '{}': {}", kind, value, message)
+        }
+    }))
+}
+
+fn duplicate_identifier_response(kind: IdentifierType, value: &str) -> HttpResponse {
+    HttpResponse::Conflict().json(serde_json::json!({
+        "error": {
+            "code": "DUPLICATE_IDENTIFIER",
+            "message": format!("This title already has {} {}", kind, value)
+        }
+    }))
+}
+
+/// Lists the additional identifiers of a title.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/identifiers`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TitleIdentifier` objects, ordered by type and value
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_identifiers(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/identifiers - Fetching identifiers", title_id);
+
+    let query = r#"
+        SELECT id, title_id, identifier_type, value, created_at
+        FROM title_identifiers
+        WHERE title_id = ?
+        ORDER BY identifier_type, value
+    "#;
+
+    match sqlx::query(query)
+        .bind(title_id.as_str())
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(rows) => {
+            let identifiers: Vec<TitleIdentifier> = rows.iter().filter_map(identifier_from_row).collect();
+            info!("Found {} identifiers for title {}", identifiers.len(), title_id);
+            HttpResponse::Ok().json(identifiers)
+        }
+        Err(e) => {
+            error!("Database error while fetching title identifiers: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch title identifiers",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Adds an identifier to a title.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/identifiers`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "identifier_type": "isbn13",
+///   "value": "978-2-07-036822-8"
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new identifier's UUID and normalized value
+/// * `HttpResponse::BadRequest` (400) if the value is not a valid identifier of that type
+/// * `HttpResponse::NotFound` (404) if the title does not exist
+/// * `HttpResponse::Conflict` (409) if the title already has this identifier
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn add_title_identifier(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<CreateTitleIdentifierRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/titles/{}/identifiers - Adding {} {}", title_id, req.identifier_type, req.value);
+
+    let value = match identifiers::normalize(req.identifier_type, &req.value) {
+        Ok(value) => value,
+        Err(message) => return invalid_identifier_response(req.identifier_type, &req.value, &message),
+    };
+
+    let title_exists = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(title_id.as_str())
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(count) => count > 0,
+        Err(e) => {
+            error!("Database error while checking title: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to check title" }
+            }));
+        }
+    };
+    if !title_exists {
+        warn!("Title {} not found", title_id);
+        return HttpResponse::NotFound().json(serde_json::json!({
+            "error": { "code": "NOT_FOUND", "message": "Title not found" }
+        }));
+    }
+
+    let new_id = Uuid::new_v4();
+    let query = r#"
+        INSERT INTO title_identifiers (id, title_id, identifier_type, value, created_at)
+        VALUES (?, ?, ?, ?, NOW())
+    "#;
+
+    match sqlx::query(query)
+        .bind(new_id.to_string())
+        .bind(title_id.as_str())
+        .bind(req.identifier_type.to_string())
+        .bind(&value)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully added identifier {} to title {}", new_id, title_id);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": new_id.to_string(),
+                "value": value,
+                "message": "Identifier added to title successfully"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            warn!("Title {} already has {} {}", title_id, req.identifier_type, value);
+            duplicate_identifier_response(req.identifier_type, &value)
+        }
+        Err(e) => {
+            error!("Database error while adding identifier: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to add identifier",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Updates the type and/or value of a title identifier.
+///
+/// **Endpoint**: `PUT /api/v1/titles/{title_id}/identifiers/{id}`
+///
+/// Missing fields keep their current value. The resulting identifier is validated
+/// again, so changing only the type re-checks the existing value.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the identifier was updated
+/// * `HttpResponse::BadRequest` (400) if no field was provided or the value is invalid
+/// * `HttpResponse::NotFound` (404) if the identifier does not belong to this title
+/// * `HttpResponse::Conflict` (409) if the title already has the resulting identifier
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn update_title_identifier(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    req: web::Json<UpdateTitleIdentifierRequest>,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("PUT /api/v1/titles/{}/identifiers/{} - Updating identifier", title_id, id);
+
+    if req.identifier_type.is_none() && req.value.is_none() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "NO_UPDATES",
+                "message": "No fields provided for update"
+            }
+        }));
+    }
+
+    let current = match sqlx::query(
+        "SELECT id, title_id, identifier_type, value, created_at FROM title_identifiers WHERE id = ? AND title_id = ?"
+    )
+    .bind(&id)
+    .bind(&title_id)
+    .fetch_optional(&data.db_pool)
+    .await
+    {
+        Ok(Some(row)) => identifier_from_row(&row),
+        Ok(None) => None,
+        Err(e) => {
+            error!("Database error while fetching identifier: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to fetch identifier" }
+            }));
+        }
+    };
+    let Some(current) = current else {
+        warn!("Identifier {} of title {} not found", id, title_id);
+        return HttpResponse::NotFound().json(serde_json::json!({
+            "error": { "code": "NOT_FOUND", "message": "Identifier not found" }
+        }));
+    };
+
+    let kind = req.identifier_type.unwrap_or(current.identifier_type);
+    let value = req.value.as_deref().unwrap_or(&current.value);
+    let value = match identifiers::normalize(kind, value) {
+        Ok(normalized) => normalized,
+        Err(message) => return invalid_identifier_response(kind, value, &message),
+    };
+
+    match sqlx::query("UPDATE title_identifiers SET identifier_type = ?, value = ? WHERE id = ?")
+        .bind(kind.to_string())
+        .bind(&value)
+        .bind(&id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully updated identifier {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Identifier updated successfully"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            warn!("Title {} already has {} {}", title_id, kind, value);
+            duplicate_identifier_response(kind, &value)
+        }
+        Err(e) => {
+            error!("Database error while updating identifier: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to update identifier",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Removes an identifier from a title.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/identifiers/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the identifier was removed
+/// * `HttpResponse::NotFound` (404) if the identifier does not belong to this title
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_title_identifier(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("DELETE /api/v1/titles/{}/identifiers/{} - Removing identifier", title_id, id);
+
+    match sqlx::query("DELETE FROM title_identifiers WHERE id = ? AND title_id = ?")
+        .bind(&id)
+        .bind(&title_id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Identifier {} of title {} not found", id, title_id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Identifier not found" }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully removed identifier {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Identifier removed successfully"
+            }))
+        }
+        Err(e) => {
+            error!("Database error while removing identifier: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to remove identifier",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
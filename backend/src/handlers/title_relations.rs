@@ -0,0 +1,299 @@
+//! API handlers for the typed relations between titles.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/titles/{title_id}/relations` - List the titles related to a title
+//! - `POST /api/v1/titles/{title_id}/relations` - Relate a title to another one
+//! - `DELETE /api/v1/titles/{title_id}/relations/{id}` - Remove a relation
+//!
+//! A relation is stored once, in the direction given by
+//! [`TitleRelationType::is_stored`], and listed on both titles: a translation
+//! shows `translation_of` its original, and the original `has_translation`.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{CreateTitleRelationRequest, TitleRelation, TitleRelationType};
+use crate::AppState;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use log::{info, warn, error};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Orients a new relation so it is stored from the side its type is stored on.
+///
+/// Returns the stored `title_id`, `related_title_id` and type.
+fn stored_relation<'a>(
+    title_id: &'a str,
+    related_title_id: &'a str,
+    relation_type: TitleRelationType,
+) -> (&'a str, &'a str, TitleRelationType) {
+    if relation_type.is_stored() {
+        (title_id, related_title_id, relation_type)
+    } else {
+        (related_title_id, title_id, relation_type.inverse())
+    }
+}
+
+/// The type of a stored relation as seen from the listed title, which is on the
+/// other side of the relation when `inverse` is set.
+fn listed_relation_type(stored_type: TitleRelationType, inverse: bool) -> TitleRelationType {
+    if inverse { stored_type.inverse() } else { stored_type }
+}
+
+/// Builds a relation from a row of [`list_title_relations`], where `relation_type`
+/// is the stored type and `inverse` tells whether the listed title is on the other side.
+fn relation_from_row(row: &MySqlRow) -> Option<TitleRelation> {
+    let id: String = row.get("id");
+    let relation_type: String = row.get("relation_type");
+    let inverse: i64 = row.get("inverse");
+    let related_title_id: String = row.get("related_title_id");
+    let created_at: NaiveDateTime = row.get("created_at");
+
+    let relation_type = TitleRelationType::from_db(&relation_type)?;
+    Some(TitleRelation {
+        id: Uuid::parse_str(&id).ok()?,
+        relation_type: listed_relation_type(relation_type, inverse != 0),
+        related_title_id: Uuid::parse_str(&related_title_id).ok()?,
+        related_title: row.get("related_title"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+    })
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn duplicate_relation_response(relation_type: TitleRelationType) -> HttpResponse {
+    HttpResponse::Conflict().json(serde_json::json!({
+        "error": {
+            "code": "DUPLICATE_RELATION",
+            "message": format!("These titles are already related as {}", relation_type)
+        }
+    }))
+}
+
+/// Lists the titles related to a title, with the relation seen from this title.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/relations`
+///
+/// Related titles in the trash are left out.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TitleRelation` objects, ordered by type and related title
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_relations(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/relations - Fetching relations", title_id);
+
+    let query = r#"
+        SELECT r.id, r.relation_type, 0 as inverse, r.related_title_id, t.title as related_title, r.created_at
+        FROM title_relations r
+        INNER JOIN titles t ON r.related_title_id = t.id
+        WHERE r.title_id = ? AND t.deleted_at IS NULL
+        UNION ALL
+        SELECT r.id, r.relation_type, 1 as inverse, r.title_id as related_title_id, t.title as related_title, r.created_at
+        FROM title_relations r
+        INNER JOIN titles t ON r.title_id = t.id
+        WHERE r.related_title_id = ? AND t.deleted_at IS NULL
+        ORDER BY relation_type, inverse, related_title
+    "#;
+
+    match sqlx::query(query)
+        .bind(title_id.as_str())
+        .bind(title_id.as_str())
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(rows) => {
+            let relations: Vec<TitleRelation> = rows.iter().filter_map(relation_from_row).collect();
+            info!("Found {} relations for title {}", relations.len(), title_id);
+            HttpResponse::Ok().json(relations)
+        }
+        Err(e) => database_error("Failed to fetch title relations", e),
+    }
+}
+
+/// Relates a title to another one.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/relations`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "relation_type": "translation_of",
+///   "related_title_id": "original-title-uuid"
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new relation's UUID
+/// * `HttpResponse::BadRequest` (400) if a title is related to itself
+/// * `HttpResponse::NotFound` (404) if either title does not exist or is in the trash
+/// * `HttpResponse::Conflict` (409) if the titles are already related this way
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn add_title_relation(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<CreateTitleRelationRequest>,
+) -> impl Responder {
+    info!(
+        "POST /api/v1/titles/{}/relations - Adding {} {}",
+        title_id, req.relation_type, req.related_title_id
+    );
+
+    if title_id.as_str() == req.related_title_id {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_RELATION",
+                "message": "A title cannot be related to itself"
+            }
+        }));
+    }
+
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id IN (?, ?) AND deleted_at IS NULL")
+        .bind(title_id.as_str())
+        .bind(&req.related_title_id)
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(2) => {}
+        Ok(_) => {
+            warn!("Title {} or {} not found", title_id, req.related_title_id);
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "One or both titles not found" }
+            }));
+        }
+        Err(e) => return database_error("Failed to check titles", e),
+    }
+
+    let (from_id, to_id, stored_type) = stored_relation(&title_id, &req.related_title_id, req.relation_type);
+
+    // The unique key only sees one direction; symmetric types can exist the other way round
+    let existing = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM title_relations \
+         WHERE (title_id = ? AND related_title_id = ? AND relation_type = ?) \
+            OR (title_id = ? AND related_title_id = ? AND relation_type = ?)"
+    )
+    .bind(from_id)
+    .bind(to_id)
+    .bind(stored_type.to_string())
+    .bind(to_id)
+    .bind(from_id)
+    .bind(stored_type.inverse().to_string())
+    .fetch_one(&data.db_pool)
+    .await;
+    match existing {
+        Ok(0) => {}
+        Ok(_) => {
+            warn!("Titles {} and {} are already related as {}", from_id, to_id, stored_type);
+            return duplicate_relation_response(req.relation_type);
+        }
+        Err(e) => return database_error("Failed to check existing relations", e),
+    }
+
+    let new_id = Uuid::new_v4();
+    match sqlx::query(
+        "INSERT INTO title_relations (id, title_id, related_title_id, relation_type, created_at) \
+         VALUES (?, ?, ?, ?, NOW())"
+    )
+    .bind(new_id.to_string())
+    .bind(from_id)
+    .bind(to_id)
+    .bind(stored_type.to_string())
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Successfully added relation {} ({} {} {})", new_id, from_id, stored_type, to_id);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": new_id.to_string(),
+                "message": "Relation added successfully"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            duplicate_relation_response(req.relation_type)
+        }
+        Err(e) => database_error("Failed to add relation", e),
+    }
+}
+
+/// Removes a relation, from either of its titles.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/relations/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the relation was removed
+/// * `HttpResponse::NotFound` (404) if the relation does not involve this title
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_title_relation(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (title_id, id) = path.into_inner();
+    info!("DELETE /api/v1/titles/{}/relations/{} - Removing relation", title_id, id);
+
+    match sqlx::query("DELETE FROM title_relations WHERE id = ? AND (title_id = ? OR related_title_id = ?)")
+        .bind(&id)
+        .bind(&title_id)
+        .bind(&title_id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Relation {} of title {} not found", id, title_id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Relation not found" }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully removed relation {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Relation removed successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to remove relation", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translation_is_stored_from_the_translation() {
+        assert_eq!(
+            stored_relation("original", "translation", TitleRelationType::HasTranslation),
+            ("translation", "original", TitleRelationType::TranslationOf)
+        );
+        assert_eq!(
+            stored_relation("translation", "original", TitleRelationType::TranslationOf),
+            ("translation", "original", TitleRelationType::TranslationOf)
+        );
+    }
+
+    #[test]
+    fn test_relations_are_listed_from_both_titles() {
+        for relation_type in TitleRelationType::ALL {
+            let (from_id, _, stored_type) = stored_relation("a", "b", relation_type);
+            assert!(stored_type.is_stored());
+
+            // Title "a" sees the type it was added with, title "b" its inverse
+            let seen_from = |title_id: &str| listed_relation_type(stored_type, from_id != title_id);
+            assert_eq!(seen_from("a"), relation_type);
+            assert_eq!(seen_from("b"), relation_type.inverse());
+        }
+    }
+}
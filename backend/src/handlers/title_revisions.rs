@@ -0,0 +1,539 @@
+//! API handlers for the revision history of titles.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/titles/{title_id}/revisions` - List the revisions of a title, newest first
+//! - `GET /api/v1/titles/{title_id}/revisions/diff?from=&to=` - Compare two revisions
+//! - `POST /api/v1/titles/{title_id}/revisions/{revision}/revert` - Restore the metadata of a revision
+//!
+//! Every update of a title records a revision listing the fields it changed, with
+//! their old and new values, the user and the time (see [`record_revision`]).
+//! Revision 0 stands for the title as it was before its first recorded revision.
+//! Authors, tags, identifiers and custom field values have their own endpoints
+//! and are not part of the history.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::AuthenticatedUser;
+use crate::handlers::titles::{parse_genre_ids, replace_title_genres, GENRE_IDS_SQL};
+use crate::models::{TitleFieldChange, TitleRevision, TitleRevisionDiff, TitleRevisionDiffParams};
+use crate::AppState;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use log::{info, warn, error, debug};
+use sqlx::{MySqlConnection, Row};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Title fields tracked by the revision history: the field name, the SQL expression
+/// reading it as text, and the column a revert writes (`None` for the genres, which
+/// live in `title_genres`).
+const TRACKED_FIELDS: [(&str, &str, Option<&str>); 17] = [
+    ("title", "t.title", Some("title")),
+    ("subtitle", "t.subtitle", Some("subtitle")),
+    ("isbn", "t.isbn", Some("isbn")),
+    ("publisher", "t.publisher_old", Some("publisher_old")),
+    ("publisher_id", "t.publisher_id", Some("publisher_id")),
+    ("publication_year", "CAST(t.publication_year AS CHAR)", Some("publication_year")),
+    ("pages", "CAST(t.pages AS CHAR)", Some("pages")),
+    ("language", "t.language", Some("language")),
+    ("original_title", "t.original_title", Some("original_title")),
+    ("original_language", "t.original_language", Some("original_language")),
+    ("translation_notes", "t.translation_notes", Some("translation_notes")),
+    ("dewey_code", "t.dewey_code", Some("dewey_code")),
+    ("genre_ids", GENRE_IDS_SQL, None),
+    ("series_id", "t.series_id", Some("series_id")),
+    ("series_number", "t.series_number", Some("series_number")),
+    ("summary", "t.summary", Some("summary")),
+    ("cover_url", "t.cover_url", Some("cover_url")),
+];
+
+/// The value of each tracked field of a title, in `TRACKED_FIELDS` order.
+pub(crate) type TitleSnapshot = Vec<Option<String>>;
+
+/// Reads the tracked fields of a title, locking its row until the end of the transaction.
+///
+/// Returns `None` if the title does not exist or is in the trash.
+pub(crate) async fn fetch_title_snapshot(
+    conn: &mut MySqlConnection,
+    title_id: &str,
+) -> Result<Option<TitleSnapshot>, sqlx::Error> {
+    let columns = TRACKED_FIELDS
+        .iter()
+        .map(|(field, expression, _)| format!("{} AS {}", expression, field))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!("SELECT {} FROM titles t WHERE t.id = ? AND t.deleted_at IS NULL FOR UPDATE", columns);
+
+    let row = sqlx::query(&query).bind(title_id).fetch_optional(&mut *conn).await?;
+    Ok(row.map(|row| {
+        TRACKED_FIELDS
+            .iter()
+            .map(|(field, _, _)| row.get::<Option<String>, _>(*field))
+            .collect()
+    }))
+}
+
+/// The value of one tracked field in a snapshot, `None` for an empty or unknown field.
+pub(crate) fn snapshot_field<'a>(snapshot: &'a TitleSnapshot, field: &str) -> Option<&'a str> {
+    let position = TRACKED_FIELDS.iter().position(|(name, _, _)| *name == field)?;
+    snapshot.get(position)?.as_deref()
+}
+
+/// The fields that differ between two snapshots of a title.
+pub(crate) fn snapshot_changes(before: &TitleSnapshot, after: &TitleSnapshot) -> Vec<TitleFieldChange> {
+    TRACKED_FIELDS
+        .iter()
+        .zip(before.iter().zip(after))
+        .filter(|(_, (old_value, new_value))| old_value != new_value)
+        .map(|((field, _, _), (old_value, new_value))| TitleFieldChange {
+            field: field.to_string(),
+            old_value: old_value.clone(),
+            new_value: new_value.clone(),
+        })
+        .collect()
+}
+
+/// Records a revision with the fields that differ between two snapshots of a title.
+///
+/// Nothing is recorded when the snapshots are identical.
+pub(crate) async fn record_revision(
+    conn: &mut MySqlConnection,
+    title_id: &str,
+    user_id: &str,
+    before: &TitleSnapshot,
+    after: &TitleSnapshot,
+) -> Result<(), sqlx::Error> {
+    let changes = snapshot_changes(before, after);
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let revision_id = Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO title_revisions (id, title_id, revision_number, user_id, created_at) \
+         SELECT ?, ?, COALESCE(MAX(revision_number), 0) + 1, ?, NOW() FROM title_revisions WHERE title_id = ?"
+    )
+    .bind(&revision_id)
+    .bind(title_id)
+    .bind(user_id)
+    .bind(title_id)
+    .execute(&mut *conn)
+    .await?;
+
+    for change in &changes {
+        sqlx::query("INSERT INTO title_revision_changes (revision_id, field_name, old_value, new_value) VALUES (?, ?, ?, ?)")
+            .bind(&revision_id)
+            .bind(&change.field)
+            .bind(&change.old_value)
+            .bind(&change.new_value)
+            .execute(&mut *conn)
+            .await?;
+    }
+    debug!("Recorded {} changed field(s) for title {}", changes.len(), title_id);
+    Ok(())
+}
+
+/// The value of every field changed in the history, right after revision `revision_number`.
+///
+/// `changes` must be ordered by revision number. A field takes the new value of its
+/// last change up to that revision, or else the old value of its first change after it.
+fn state_at(changes: &[(i32, TitleFieldChange)], revision_number: i32) -> BTreeMap<String, Option<String>> {
+    let mut state = BTreeMap::new();
+    for (number, change) in changes {
+        if *number <= revision_number {
+            state.insert(change.field.clone(), change.new_value.clone());
+        } else {
+            state.entry(change.field.clone()).or_insert_with(|| change.old_value.clone());
+        }
+    }
+    state
+}
+
+/// Loads the field changes of a title, ordered by revision number.
+async fn fetch_changes(
+    conn: &mut MySqlConnection,
+    title_id: &str,
+) -> Result<Vec<(i32, TitleFieldChange)>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT tr.revision_number, trc.field_name, trc.old_value, trc.new_value \
+         FROM title_revision_changes trc \
+         INNER JOIN title_revisions tr ON trc.revision_id = tr.id \
+         WHERE tr.title_id = ? \
+         ORDER BY tr.revision_number"
+    )
+    .bind(title_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            (
+                row.get("revision_number"),
+                TitleFieldChange {
+                    field: row.get("field_name"),
+                    old_value: row.get("old_value"),
+                    new_value: row.get("new_value"),
+                },
+            )
+        })
+        .collect())
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn title_not_found(title_id: &str) -> HttpResponse {
+    warn!("Title {} not found", title_id);
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": { "code": "NOT_FOUND", "message": "Title not found" }
+    }))
+}
+
+fn revision_not_found(title_id: &str, revision_number: i32) -> HttpResponse {
+    warn!("Title {} has no revision {}", title_id, revision_number);
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": {
+            "code": "REVISION_NOT_FOUND",
+            "message": format!("This title has no revision {}", revision_number)
+        }
+    }))
+}
+
+/// Lists the revisions of a title with the fields each one changed, newest first.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/revisions`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TitleRevision` objects
+/// * `HttpResponse::NotFound` if the title does not exist
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_title_revisions(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/revisions - Fetching revision history", title_id);
+
+    let mut conn = match data.db_pool.acquire().await {
+        Ok(conn) => conn,
+        Err(e) => return database_error("Failed to fetch title revisions", e),
+    };
+    match fetch_title_snapshot(&mut conn, &title_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return title_not_found(&title_id),
+        Err(e) => return database_error("Failed to fetch title", e),
+    }
+
+    let rows = match sqlx::query(
+        "SELECT tr.id, tr.title_id, tr.revision_number, tr.user_id, u.username, tr.created_at \
+         FROM title_revisions tr \
+         LEFT JOIN users u ON tr.user_id = u.id \
+         WHERE tr.title_id = ? \
+         ORDER BY tr.revision_number DESC"
+    )
+    .bind(title_id.as_str())
+    .fetch_all(&mut *conn)
+    .await
+    {
+        Ok(rows) => rows,
+        Err(e) => return database_error("Failed to fetch title revisions", e),
+    };
+    let mut changes_by_revision: BTreeMap<i32, Vec<TitleFieldChange>> = BTreeMap::new();
+    match fetch_changes(&mut conn, &title_id).await {
+        Ok(changes) => {
+            for (number, change) in changes {
+                changes_by_revision.entry(number).or_default().push(change);
+            }
+        }
+        Err(e) => return database_error("Failed to fetch title revisions", e),
+    }
+
+    let revisions: Vec<TitleRevision> = rows
+        .iter()
+        .filter_map(|row| {
+            let id: String = row.get("id");
+            let title_id: String = row.get("title_id");
+            let revision_number: i32 = row.get("revision_number");
+            let created_at: NaiveDateTime = row.get("created_at");
+            Some(TitleRevision {
+                id: Uuid::parse_str(&id).ok()?,
+                title_id: Uuid::parse_str(&title_id).ok()?,
+                revision_number,
+                user_id: row.get("user_id"),
+                username: row.get("username"),
+                changes: changes_by_revision.remove(&revision_number).unwrap_or_default(),
+                created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+            })
+        })
+        .collect();
+    info!("Found {} revisions for title {}", revisions.len(), title_id);
+    HttpResponse::Ok().json(revisions)
+}
+
+/// Compares two revisions of a title.
+///
+/// **Endpoint**: `GET /api/v1/titles/{title_id}/revisions/diff?from=2&to=5`
+///
+/// Lists the fields whose value after revision `from` differs from their value
+/// after revision `to`. Either number may be 0 for the title before its first revision.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with a `TitleRevisionDiff` object
+/// * `HttpResponse::NotFound` if the title or one of the revisions does not exist
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn diff_title_revisions(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    params: web::Query<TitleRevisionDiffParams>,
+) -> impl Responder {
+    info!("GET /api/v1/titles/{}/revisions/diff - Comparing revisions {} and {}", title_id, params.from, params.to);
+
+    let mut conn = match data.db_pool.acquire().await {
+        Ok(conn) => conn,
+        Err(e) => return database_error("Failed to compare title revisions", e),
+    };
+    match fetch_title_snapshot(&mut conn, &title_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return title_not_found(&title_id),
+        Err(e) => return database_error("Failed to fetch title", e),
+    }
+    let changes = match fetch_changes(&mut conn, &title_id).await {
+        Ok(changes) => changes,
+        Err(e) => return database_error("Failed to compare title revisions", e),
+    };
+
+    let latest = changes.last().map(|(number, _)| *number).unwrap_or(0);
+    for number in [params.from, params.to] {
+        if !(0..=latest).contains(&number) {
+            return revision_not_found(&title_id, number);
+        }
+    }
+
+    let from_state = state_at(&changes, params.from);
+    let to_state = state_at(&changes, params.to);
+    let differences: Vec<TitleFieldChange> = TRACKED_FIELDS
+        .iter()
+        .filter_map(|(field, _, _)| {
+            let old_value = from_state.get(*field)?;
+            let new_value = to_state.get(*field)?;
+            (old_value != new_value).then(|| TitleFieldChange {
+                field: field.to_string(),
+                old_value: old_value.clone(),
+                new_value: new_value.clone(),
+            })
+        })
+        .collect();
+
+    HttpResponse::Ok().json(TitleRevisionDiff {
+        from_revision: params.from,
+        to_revision: params.to,
+        changes: differences,
+    })
+}
+
+/// Restores the metadata a title had right after one of its revisions.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/revisions/{revision}/revert`
+///
+/// The revert is itself recorded as a new revision, so it can be undone in turn.
+/// Revision 0 restores the title as it was before its first revision.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the title was reverted
+/// * `HttpResponse::NotFound` (404) if the title or the revision does not exist
+/// * `HttpResponse::Conflict` (409) if the revision references a publisher, series or genre that was deleted since
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn revert_title_revision(
+    data: web::Data<AppState>,
+    path: web::Path<(String, i32)>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    let (title_id, revision_number) = path.into_inner();
+    info!("POST /api/v1/titles/{}/revisions/{}/revert - Reverting title", title_id, revision_number);
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to revert title", e),
+    };
+    let before = match fetch_title_snapshot(&mut tx, &title_id).await {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => return title_not_found(&title_id),
+        Err(e) => return database_error("Failed to fetch title", e),
+    };
+    let changes = match fetch_changes(&mut tx, &title_id).await {
+        Ok(changes) => changes,
+        Err(e) => return database_error("Failed to fetch title revisions", e),
+    };
+    let latest = changes.last().map(|(number, _)| *number).unwrap_or(0);
+    if !(0..=latest).contains(&revision_number) {
+        return revision_not_found(&title_id, revision_number);
+    }
+
+    // Only write the fields whose current value differs from the target
+    let target = state_at(&changes, revision_number);
+    let mut columns = Vec::new();
+    let mut values = Vec::new();
+    let mut genre_ids = None;
+    for ((field, _, column), current) in TRACKED_FIELDS.iter().zip(&before) {
+        let Some(value) = target.get(*field) else { continue };
+        if value == current {
+            continue;
+        }
+        match column {
+            Some(column) => {
+                columns.push(format!("{} = ?", column));
+                values.push(value.clone());
+            }
+            None => {
+                let ids = parse_genre_ids(value.clone());
+                columns.push("genre_id = ?".to_string());
+                values.push(ids.first().cloned());
+                genre_ids = Some(ids);
+            }
+        }
+    }
+
+    let result = async {
+        if columns.is_empty() {
+            return Ok::<(), sqlx::Error>(());
+        }
+        let query = format!("UPDATE titles SET {}, updated_at = NOW() WHERE id = ?", columns.join(", "));
+        let mut query_builder = sqlx::query(&query);
+        for value in &values {
+            query_builder = query_builder.bind(value);
+        }
+        query_builder.bind(&title_id).execute(&mut *tx).await?;
+        if let Some(ref genre_ids) = genre_ids {
+            replace_title_genres(&mut tx, &title_id, genre_ids).await?;
+        }
+        if let Some(after) = fetch_title_snapshot(&mut tx, &title_id).await? {
+            record_revision(&mut tx, &title_id, &user.id, &before, &after).await?;
+        }
+        Ok(())
+    }
+    .await;
+    let result = match result {
+        Ok(()) => tx.commit().await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
+            info!("Reverted title {} to revision {} ({} field(s) changed)", title_id, revision_number, columns.len());
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": format!("Title reverted to revision {}", revision_number),
+                "fields_changed": columns.len()
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_foreign_key_violation() => {
+            warn!("Cannot revert title {} to revision {}: {}", title_id, revision_number, db_err);
+            HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "REVERT_CONFLICT",
+                    "message": format!(
+                        "Revision {} references a publisher, series or genre that no longer exists",
+                        revision_number
+                    )
+                }
+            }))
+        }
+        Err(e) => database_error("Failed to revert title", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(number: i32, field: &str, old_value: Option<&str>, new_value: Option<&str>) -> (i32, TitleFieldChange) {
+        (
+            number,
+            TitleFieldChange {
+                field: field.to_string(),
+                old_value: old_value.map(str::to_string),
+                new_value: new_value.map(str::to_string),
+            },
+        )
+    }
+
+    /// History of a title: the title is renamed twice, the page count set in revision 2.
+    fn history() -> Vec<(i32, TitleFieldChange)> {
+        vec![
+            change(1, "title", Some("Dune"), Some("Dune I")),
+            change(2, "title", Some("Dune I"), Some("Dune (1965)")),
+            change(2, "pages", None, Some("412")),
+            change(3, "title", Some("Dune (1965)"), Some("Dune")),
+        ]
+    }
+
+    #[test]
+    fn test_state_at_revision_zero() {
+        let state = state_at(&history(), 0);
+        assert_eq!(state["title"].as_deref(), Some("Dune"));
+        assert_eq!(state["pages"], None);
+    }
+
+    #[test]
+    fn test_state_at_field_changed_in_several_revisions() {
+        let history = history();
+        assert_eq!(state_at(&history, 1)["title"].as_deref(), Some("Dune I"));
+        assert_eq!(state_at(&history, 2)["title"].as_deref(), Some("Dune (1965)"));
+        assert_eq!(state_at(&history, 3)["title"].as_deref(), Some("Dune"));
+    }
+
+    #[test]
+    fn test_state_at_field_first_changed_after_revision() {
+        let state = state_at(&history(), 1);
+        // The page count was first set in revision 2, so it was still empty after revision 1
+        assert!(state.contains_key("pages"));
+        assert_eq!(state["pages"], None);
+        assert_eq!(state_at(&history(), 2)["pages"].as_deref(), Some("412"));
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let before: TitleSnapshot = TRACKED_FIELDS.iter().map(|_| None).collect();
+        let mut after = before.clone();
+        after[0] = Some("Dune".to_string());
+        let pages = TRACKED_FIELDS.iter().position(|(field, _, _)| *field == "pages").unwrap();
+        after[pages] = Some("412".to_string());
+
+        let changes = snapshot_changes(&before, &after);
+        let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(fields, vec!["title", "pages"]);
+        assert_eq!(changes[1].old_value, None);
+        assert_eq!(changes[1].new_value.as_deref(), Some("412"));
+
+        assert!(snapshot_changes(&after, &after).is_empty());
+        assert_eq!(snapshot_field(&after, "pages"), Some("412"));
+        assert_eq!(snapshot_field(&after, "unknown"), None);
+    }
+
+    #[test]
+    fn test_translation_fields_are_tracked() {
+        let before: TitleSnapshot = TRACKED_FIELDS.iter().map(|_| None).collect();
+        let mut after = before.clone();
+        for (field, value) in [("original_title", "Der Zauberberg"), ("original_language", "de")] {
+            let position = TRACKED_FIELDS.iter().position(|(name, _, _)| *name == field).unwrap();
+            after[position] = Some(value.to_string());
+        }
+
+        let fields: Vec<String> = snapshot_changes(&before, &after).into_iter().map(|change| change.field).collect();
+        assert_eq!(fields, vec!["original_title", "original_language"]);
+
+        // Reverting a revision writes the fields back to their own columns
+        for field in ["original_title", "original_language", "translation_notes"] {
+            assert!(TRACKED_FIELDS.contains(&(field, &format!("t.{}", field), Some(field))), "{}", field);
+        }
+    }
+}
@@ -4,13 +4,263 @@
 //! book titles. It includes functionality for searching, duplicate detection, and merging.
 
 use actix_web::{web, HttpResponse, Responder};
-use crate::models::{TitleWithCount, CreateTitleRequest, UpdateTitleRequest, AddAuthorToTitleRequest, Author, AuthorRole, TitleSearchParams, DuplicatePair, DuplicateDetectionResponse, DuplicateConfidence, MergeTitlesRequest, MergeTitlesResponse};
+use crate::auth::AuthenticatedUser;
+use crate::handlers::custom_fields::{parse_custom_values, save_custom_values, validate_custom_values};
+use crate::handlers::title_revisions::{fetch_title_snapshot, record_revision};
+use crate::models::{CustomFieldScope, CustomFieldType, IdentifierType, WishlistPriority, TitleWithCount, CreateTitleRequest, UpdateTitleRequest, AddAuthorToTitleRequest, Author, AuthorRole, TitleSearchParams, DuplicatePair, DuplicateDetectionResponse, DuplicateConfidence, MergeTitlesRequest, MergeTitlesResponse};
 use crate::AppState;
 use log::{info, warn, error, debug};
-use sqlx::Row;
+use shared::identifiers;
+use shared::isbn::{self, Isbn};
+use shared::languages;
+use sqlx::{MySqlPool, Row};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 use strsim::jaro_winkler;
 
+/// SQL expression for a title's ISBN without hyphens or spaces, whatever format it was stored in.
+const COMPACT_ISBN_SQL: &str = "UPPER(REPLACE(REPLACE(t.isbn, '-', ''), ' ', ''))";
+
+/// SQL expression for the comma-separated genre UUIDs of a title, primary genre first.
+pub(crate) const GENRE_IDS_SQL: &str = "(SELECT GROUP_CONCAT(tgen.genre_id ORDER BY tgen.is_primary DESC, gen.name SEPARATOR ',') \
+     FROM title_genres tgen INNER JOIN genres gen ON tgen.genre_id = gen.id WHERE tgen.title_id = t.id)";
+
+/// SQL expression for the custom field values of a title, as a JSON object keyed by field UUID.
+const CUSTOM_FIELDS_SQL: &str = "(SELECT CAST(JSON_OBJECTAGG(tcv.field_id, tcv.value) AS CHAR) \
+     FROM title_custom_values tcv WHERE tcv.title_id = t.id)";
+
+/// SQL expression for the wishlist priority of a title, NULL if it is not on the wishlist.
+const WISHLIST_PRIORITY_SQL: &str = "(SELECT wi.priority FROM wishlist_items wi WHERE wi.title_id = t.id)";
+
+/// SQL expression for the average star rating of a title, NULL if nobody rated it.
+const AVERAGE_RATING_SQL: &str = "(SELECT CAST(AVG(rv.rating) AS DOUBLE) FROM title_reviews rv WHERE rv.title_id = t.id)";
+
+/// SQL expression for the number of users who rated a title.
+const RATING_COUNT_SQL: &str = "(SELECT COUNT(rv.rating) FROM title_reviews rv WHERE rv.title_id = t.id)";
+
+/// SQL expression for the position of a title in its series: the first number of
+/// `series_number` ("Vol. 2" is 2, "3,5" is 3.5), the same parse as
+/// `shared::models::series::parse_series_number`. NULL when there is no number.
+const SERIES_POSITION_SQL: &str = "CAST(REGEXP_SUBSTR(REPLACE(t.series_number, ',', '.'), '[0-9]+([.][0-9]+)?') AS DECIMAL(12, 3))";
+
+/// Validates the ISBN of a create/update request.
+///
+/// Valid ISBNs (ISBN-10 or ISBN-13, with or without hyphens) are returned as their
+/// compact ISBN-13 so that the same book is always stored the same way. An empty
+/// string is passed through unchanged so clients can clear the field.
+///
+/// # Returns
+///
+/// * `Ok(isbn)` - The value to store
+/// * `Err(message)` - The ISBN has a wrong length, character or check digit
+fn validate_isbn(isbn: Option<&str>) -> Result<Option<String>, String> {
+    match isbn {
+        Some(value) if !value.trim().is_empty() => match Isbn::parse(value) {
+            Ok(parsed) => Ok(Some(parsed.as_str().to_string())),
+            Err(e) => Err(format!("Invalid ISBN '{}': {}", value, e)),
+        },
+        other => Ok(other.map(str::to_string)),
+    }
+}
+
+fn invalid_isbn_response(message: String) -> HttpResponse {
+    warn!("Rejected ISBN: {}", message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": { "code": "INVALID_ISBN", "message": message }
+    }))
+}
+
+/// Builds the 400 response for a language [`languages::normalize`] rejected.
+///
+/// Requests store languages as their ISO 639 code, so "FR", "fre" and "French"
+/// are all stored as `fr`; anything else is refused with this response.
+pub(crate) fn invalid_language_response(field: &str, language: &str, message: &str) -> HttpResponse {
+    warn!("Rejected invalid {} {}: {}", field, language, message);
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "INVALID_LANGUAGE",
+            "message": format!("Invalid {}: {}", field, message),
+            "details": { "field": field }
+        }
+    }))
+}
+
+/// Rewrites the stored title languages that are not catalogue codes yet ("French",
+/// "FR", "pt-BR") as their code.
+///
+/// Runs at startup so that titles saved before languages were validated are cleaned
+/// up by [`languages::normalize`] itself, not a second copy of the catalogue. Values
+/// that are not a known language are left as they are. Returns the number of titles
+/// updated.
+pub async fn normalize_stored_languages(pool: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let mut updated = 0;
+    for column in ["language", "original_language"] {
+        // Binary collation, so that "FR" is not folded into "fr"
+        let values: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT DISTINCT {column} COLLATE utf8mb4_bin FROM titles WHERE {column} IS NOT NULL"
+        ))
+        .fetch_all(pool)
+        .await?;
+
+        for value in values {
+            let Ok(code) = languages::normalize(&value) else {
+                continue;
+            };
+            if code != value {
+                // Keep updated_at: this is a cleanup, not an edit of the titles
+                updated += sqlx::query(&format!(
+                    "UPDATE titles SET {column} = ?, updated_at = updated_at WHERE {column} COLLATE utf8mb4_bin = ?"
+                ))
+                .bind(&code)
+                .bind(&value)
+                .execute(pool)
+                .await?
+                .rows_affected();
+            }
+        }
+    }
+    Ok(updated)
+}
+
+/// SQL condition matching titles whose ISBN, or one of whose additional identifiers,
+/// is among the normalized `values`, and the values to bind to it.
+fn identifier_match_clause(values: &[String]) -> (String, Vec<String>) {
+    let placeholders = vec!["?"; values.len()].join(", ");
+    (
+        format!(
+            "({isbn} IN ({p}) OR t.id IN (SELECT title_id FROM title_identifiers WHERE value IN ({p})))",
+            isbn = COMPACT_ISBN_SQL,
+            p = placeholders
+        ),
+        values.iter().chain(values).cloned().collect(),
+    )
+}
+
+/// Splits the result of `GENRE_IDS_SQL`.
+pub(crate) fn parse_genre_ids(genre_ids: Option<String>) -> Vec<String> {
+    genre_ids
+        .map(|ids| ids.split(',').map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Drops blank and repeated genre UUIDs, keeping the first occurrence so the
+/// primary genre stays first.
+pub(crate) fn dedup_genre_ids(genre_ids: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    genre_ids
+        .iter()
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty() && seen.insert(id.to_string()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Trims an optional text field, turning a blank value into `None` (stored as NULL).
+fn non_blank(value: Option<&str>) -> Option<String> {
+    value.map(str::trim).filter(|value| !value.is_empty()).map(str::to_string)
+}
+
+/// Normalizes an optional original language; a blank value is `None` (stored as NULL).
+///
+/// On error, returns the rejected value with the message of [`languages::normalize`].
+fn parse_original_language(value: Option<&str>) -> Result<Option<String>, (String, String)> {
+    match non_blank(value) {
+        Some(language) => languages::normalize(&language).map(Some).map_err(|e| (language, e)),
+        None => Ok(None),
+    }
+}
+
+/// Replaces the genres of a title; the first genre is flagged as primary.
+///
+/// The caller keeps `titles.genre_id` in sync with the primary genre.
+pub(crate) async fn replace_title_genres(
+    conn: &mut sqlx::MySqlConnection,
+    title_id: &str,
+    genre_ids: &[String],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM title_genres WHERE title_id = ?")
+        .bind(title_id)
+        .execute(&mut *conn)
+        .await?;
+    for (position, genre_id) in genre_ids.iter().enumerate() {
+        sqlx::query("INSERT INTO title_genres (title_id, genre_id, is_primary) VALUES (?, ?, ?)")
+            .bind(title_id)
+            .bind(genre_id)
+            .bind(position == 0)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Checks that every genre UUID exists, or builds the error response.
+pub(crate) async fn check_genres_exist(pool: &sqlx::MySqlPool, genre_ids: &[String]) -> Result<(), HttpResponse> {
+    if genre_ids.is_empty() {
+        return Ok(());
+    }
+    let query = format!(
+        "SELECT COUNT(*) FROM genres WHERE id IN ({})",
+        vec!["?"; genre_ids.len()].join(", ")
+    );
+    let mut query_builder = sqlx::query_scalar::<_, i64>(&query);
+    for genre_id in genre_ids {
+        query_builder = query_builder.bind(genre_id);
+    }
+
+    match query_builder.fetch_one(pool).await {
+        Ok(count) if count == genre_ids.len() as i64 => Ok(()),
+        Ok(_) => {
+            warn!("Rejected unknown genre among {:?}", genre_ids);
+            Err(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": {
+                    "code": "INVALID_GENRE",
+                    "message": "One of the genres does not exist"
+                }
+            })))
+        }
+        Err(e) => {
+            error!("Database error while checking genres: {}", e);
+            Err(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to check genres" }
+            })))
+        }
+    }
+}
+
+/// The ISBN-13 and, if it exists, ISBN-10 form of `isbn`.
+fn isbn_forms(isbn: &Isbn) -> Vec<String> {
+    let mut forms = vec![isbn.as_str().to_string()];
+    forms.extend(isbn.to_isbn10());
+    forms
+}
+
+/// Keys identifying the same edition, used by duplicate detection.
+///
+/// ISBN-10, ISBN-13 and ISBN-like EANs all become `ISBN <13 digits>`, so a title
+/// whose main ISBN is the ISBN-10 of another title's extra identifier is caught.
+/// ISSNs are left out: they identify a whole series, not one book.
+fn edition_keys(isbn: Option<&str>, identifiers: &[(IdentifierType, String)]) -> HashSet<String> {
+    let mut keys: HashSet<String> = isbn
+        .and_then(|isbn| Isbn::parse(isbn).ok())
+        .map(|isbn| format!("ISBN {}", isbn.as_str()))
+        .into_iter()
+        .collect();
+
+    for (kind, value) in identifiers {
+        let key = match kind {
+            IdentifierType::Isbn10 | IdentifierType::Isbn13 | IdentifierType::Ean => match Isbn::parse(value) {
+                Ok(isbn) => format!("ISBN {}", isbn.as_str()),
+                Err(_) => format!("EAN {}", value),
+            },
+            IdentifierType::Lccn => format!("LCCN {}", value),
+            IdentifierType::Oclc => format!("OCLC {}", value),
+            IdentifierType::Issn => continue,
+        };
+        keys.insert(key);
+    }
+    keys
+}
+
 /// Lists all titles with their volume counts.
 ///
 /// **Endpoint**: `GET /api/v1/titles`
@@ -42,6 +292,7 @@ use strsim::jaro_winkler;
 ///     "pages": 350,
 ///     "language": "en",
 ///     "genre_id": "genre-uuid",
+///     "genre_ids": ["genre-uuid", "other-genre-uuid"],
 ///     "volume_count": 3,
 ///     ...
 ///   }
@@ -58,7 +309,7 @@ use strsim::jaro_winkler;
 pub async fn list_titles(data: web::Data<AppState>) -> impl Responder {
     info!("GET /api/v1/titles - Fetching all titles with volume counts");
     // Query to get all titles with their volume counts
-    let query = r#"
+    let query = format!(r#"
         SELECT
             t.id,
             t.title,
@@ -69,30 +320,41 @@ pub async fn list_titles(data: web::Data<AppState>) -> impl Responder {
             t.publication_year,
             t.pages,
             t.language,
+            t.original_title,
+            t.original_language,
+            t.translation_notes,
             t.dewey_code,
             t.genre_old as genre,
             t.genre_id,
+            {genre_ids} as genre_ids,
+            {custom_fields} as custom_fields,
+            {wishlist_priority} as wishlist_priority,
             s.name as series_name,
             t.series_id,
             t.series_number,
+            t.work_id,
             t.summary,
             t.cover_url,
             t.image_mime_type,
             t.image_filename,
             t.created_at,
             t.updated_at,
+            {average_rating} as average_rating,
+            {rating_count} as rating_count,
             COUNT(v.id) as volume_count
         FROM titles t
         LEFT JOIN volumes v ON t.id = v.title_id
         LEFT JOIN series s ON t.series_id = s.id
+        WHERE t.deleted_at IS NULL
         GROUP BY t.id, t.title, t.subtitle, t.isbn, t.publisher_old, t.publisher_id, t.publication_year,
-                 t.pages, t.language, t.dewey_code, t.genre_old, t.genre_id, s.name,
-                 t.series_id, t.series_number, t.summary, t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at
+                 t.pages, t.language, t.original_title, t.original_language, t.translation_notes, t.dewey_code, t.genre_old, t.genre_id, s.name,
+                 t.series_id, t.series_number, t.work_id, t.summary, t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at
         ORDER BY t.title ASC
-    "#;
+    "#, genre_ids = GENRE_IDS_SQL, custom_fields = CUSTOM_FIELDS_SQL, wishlist_priority = WISHLIST_PRIORITY_SQL,
+        average_rating = AVERAGE_RATING_SQL, rating_count = RATING_COUNT_SQL);
 
     debug!("Executing query to fetch titles");
-    match sqlx::query(query)
+    match sqlx::query(&query)
         .fetch_all(&data.db_pool)
         .await
     {
@@ -128,12 +390,19 @@ pub async fn list_titles(data: web::Data<AppState>) -> impl Responder {
                             publication_year: row.get("publication_year"),
                             pages: row.get("pages"),
                             language: row.get("language"),
+                            original_title: row.get("original_title"),
+                            original_language: row.get("original_language"),
+                            translation_notes: row.get("translation_notes"),
                             dewey_code: row.get("dewey_code"),
                             genre: row.get("genre"),
                             genre_id: row.get("genre_id"),
+                            genre_ids: parse_genre_ids(row.get("genre_ids")),
                             series_name: row.get("series_name"),
                             series_id: row.get("series_id"),
                             series_number: row.get("series_number"),
+                            work_id: row.get("work_id"),
+                            custom_fields: parse_custom_values(row.get("custom_fields")),
+                            wishlist_priority: row.get::<Option<String>, _>("wishlist_priority").as_deref().and_then(WishlistPriority::from_db),
                             summary: row.get("summary"),
                             cover_url: row.get("cover_url"),
                             // Don't fetch image_data in list queries for performance
@@ -144,6 +413,8 @@ pub async fn list_titles(data: web::Data<AppState>) -> impl Responder {
                             updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
                         },
                         volume_count: row.get("volume_count"),
+                        average_rating: row.get("average_rating"),
+                        rating_count: row.get("rating_count"),
                     })
                 })
                 .collect();
@@ -191,15 +462,19 @@ pub async fn list_titles(data: web::Data<AppState>) -> impl Responder {
 ///   "pages": 350,
 ///   "language": "en (required)",
 ///   "dewey_code": "000.00",
-///   "genre_id": "genre-uuid",
+///   "genre_ids": ["primary-genre-uuid", "other-genre-uuid"],
 ///   "summary": "Book description",
-///   "cover_url": "https://example.com/cover.jpg"
+///   "cover_url": "https://example.com/cover.jpg",
+///   "custom_fields": { "reading-level-field-uuid": "B2" }
 /// }
 /// ```
 ///
 /// # Returns
 ///
 /// * `HttpResponse::Created` (201) with the new title's UUID on success
+/// * `HttpResponse::BadRequest` (400) if the ISBN is invalid (see `validate_isbn`), a language is not
+///   a known ISO 639 language (see `invalid_language_response`), a genre does not exist
+///   or a custom field value does not fit its field
 /// * `HttpResponse::InternalServerError` (500) if the database insertion fails
 ///
 /// # Response Format
@@ -223,36 +498,87 @@ pub async fn create_title(
     info!("POST /api/v1/titles - Creating new title: {}", req.title);
     info!("Dewey code: {:?} (length: {})", req.dewey_code, req.dewey_code.as_ref().map(|s| s.len()).unwrap_or(0));
 
+    let isbn = match validate_isbn(req.isbn.as_deref()) {
+        Ok(isbn) => isbn,
+        Err(message) => return invalid_isbn_response(message),
+    };
+
+    let language = match languages::normalize(&req.language) {
+        Ok(language) => language,
+        Err(e) => return invalid_language_response("language", &req.language, &e),
+    };
+    let original_language = match parse_original_language(req.original_language.as_deref()) {
+        Ok(original_language) => original_language,
+        Err((language, e)) => return invalid_language_response("original_language", &language, &e),
+    };
+
+    let genre_ids = dedup_genre_ids(&req.genre_ids);
+    if let Err(response) = check_genres_exist(&data.db_pool, &genre_ids).await {
+        return response;
+    }
+    let custom_values = match validate_custom_values(&data.db_pool, CustomFieldScope::Title, &req.custom_fields).await {
+        Ok(values) => values,
+        Err(response) => return response,
+    };
+
     // Generate new UUID
     let new_id = Uuid::new_v4();
 
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => {
+            error!("Failed to start transaction: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to create title" }
+            }));
+        }
+    };
+
     let query = r#"
         INSERT INTO titles (id, title, subtitle, isbn, publisher_old, publisher_id, publication_year, pages,
-                           language, dewey_code, genre_id, series_id, series_number, summary, cover_url,
+                           language, original_title, original_language, translation_notes,
+                           dewey_code, genre_id, series_id, series_number, summary, cover_url,
                            created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, NOW(), NOW())
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, NOW(), NOW())
     "#;
 
-    match sqlx::query(query)
+    let result = sqlx::query(query)
         .bind(new_id.to_string())
         .bind(&req.title)
         .bind(&req.subtitle)
-        .bind(&req.isbn)
+        .bind(&isbn)
         .bind(&req.publisher)
         .bind(&req.publisher_id)
         .bind(req.publication_year)
         .bind(req.pages)
-        .bind(&req.language)
+        .bind(&language)
+        .bind(non_blank(req.original_title.as_deref()))
+        .bind(&original_language)
+        .bind(non_blank(req.translation_notes.as_deref()))
         .bind(&req.dewey_code)
-        .bind(&req.genre_id)
+        .bind(genre_ids.first())
         .bind(&req.series_id)
         .bind(&req.series_number)
         .bind(&req.summary)
         .bind(&req.cover_url)
-        .execute(&data.db_pool)
-        .await
-    {
-        Ok(_) => {
+        .execute(&mut *tx)
+        .await;
+
+    let result = match result {
+        Ok(_) => replace_title_genres(&mut tx, &new_id.to_string(), &genre_ids).await,
+        Err(e) => Err(e),
+    };
+    let result = match result {
+        Ok(()) => save_custom_values(&mut tx, CustomFieldScope::Title, &new_id.to_string(), &custom_values).await,
+        Err(e) => Err(e),
+    };
+    let result = match result {
+        Ok(()) => tx.commit().await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
             info!("Successfully created title with ID: {}", new_id);
             HttpResponse::Created().json(serde_json::json!({
                 "id": new_id.to_string(),
@@ -301,18 +627,29 @@ pub async fn create_title(
 ///   "publication_year": 2021,
 ///   "pages": 400,
 ///   "language": "en",
+///   "original_title": "Der Zauberberg",
+///   "original_language": "de",
 ///   "dewey_code": "100.00",
-///   "genre_id": "new-genre-uuid",
+///   "genre_ids": ["new-primary-genre-uuid", "other-genre-uuid"],
 ///   "summary": "Updated description",
-///   "cover_url": "https://example.com/new-cover.jpg"
+///   "cover_url": "https://example.com/new-cover.jpg",
+///   "custom_fields": { "reading-level-field-uuid": "C1", "box-field-uuid": "" }
 /// }
 /// ```
 ///
+/// `genre_ids` replaces all genres of the title, the first one being the primary
+/// genre. To change only the primary genre and keep the others, send
+/// `primary_genre_id` instead.
+///
+/// `custom_fields` only changes the listed fields; a blank value clears the field.
+/// A blank `original_title`, `original_language` or `translation_notes` clears it too.
+///
 /// # Returns
 ///
 /// * `HttpResponse::Ok` (200) if the title was found and updated successfully
 /// * `HttpResponse::NotFound` (404) if no title exists with the given ID
-/// * `HttpResponse::BadRequest` (400) if no fields were provided for update
+/// * `HttpResponse::BadRequest` (400) if no fields were provided for update, the ISBN or a language is invalid,
+///   a genre does not exist or a custom field value does not fit its field
 /// * `HttpResponse::InternalServerError` (500) if the database update fails
 ///
 /// # Response Format
@@ -330,13 +667,79 @@ pub async fn create_title(
 /// - Automatically updates the `updated_at` timestamp
 /// - Uses parameterized queries to prevent SQL injection
 /// - Returns the number of rows affected to detect if title exists
+/// - Records the changed fields as a new revision (see `handlers::title_revisions`)
 pub async fn update_title(
     data: web::Data<AppState>,
     id: web::Path<String>,
     req: web::Json<UpdateTitleRequest>,
+    user: AuthenticatedUser,
 ) -> impl Responder {
     info!("PUT /api/v1/titles/{} - Updating title", id);
 
+    let isbn = match validate_isbn(req.isbn.as_deref()) {
+        Ok(isbn) => isbn,
+        Err(message) => return invalid_isbn_response(message),
+    };
+    let language = match req.language.as_deref() {
+        Some(language) => match languages::normalize(language) {
+            Ok(code) => Some(code),
+            Err(e) => return invalid_language_response("language", language, &e),
+        },
+        None => None,
+    };
+    // Outer `None` leaves the original language alone, `Some(None)` clears it
+    let original_language = match req
+        .original_language
+        .as_deref()
+        .map(|language| parse_original_language(Some(language)))
+        .transpose()
+    {
+        Ok(original_language) => original_language,
+        Err((language, e)) => return invalid_language_response("original_language", &language, &e),
+    };
+
+    // Resolve the new genre list when the genres change; the first one is primary
+    let genre_ids = match (&req.genre_ids, &req.primary_genre_id) {
+        (None, None) => None,
+        (Some(ids), primary) => {
+            let mut ids = ids.clone();
+            if let Some(primary) = primary {
+                ids.insert(0, primary.clone());
+            }
+            Some(dedup_genre_ids(&ids))
+        }
+        (None, Some(primary)) => {
+            // Keep the current genres behind the new primary genre
+            let query = format!("SELECT {} FROM titles t WHERE t.id = ?", GENRE_IDS_SQL);
+            match sqlx::query_scalar::<_, Option<String>>(&query)
+                .bind(id.as_str())
+                .fetch_optional(&data.db_pool)
+                .await
+            {
+                Ok(current) => {
+                    let mut ids = vec![primary.clone()];
+                    ids.extend(parse_genre_ids(current.flatten()));
+                    Some(dedup_genre_ids(&ids))
+                }
+                Err(e) => {
+                    error!("Database error while fetching title genres: {}", e);
+                    return HttpResponse::InternalServerError().json(serde_json::json!({
+                        "error": { "code": "DATABASE_ERROR", "message": "Failed to fetch title genres" }
+                    }));
+                }
+            }
+        }
+    };
+    if let Some(ref genre_ids) = genre_ids
+        && let Err(response) = check_genres_exist(&data.db_pool, genre_ids).await
+    {
+        return response;
+    }
+    let custom_values = match validate_custom_values(&data.db_pool, CustomFieldScope::Title, &req.custom_fields).await {
+        Ok(values) => values,
+        Err(response) => return response,
+    };
+
     // Build dynamic UPDATE query based on provided fields
     let mut update_parts = Vec::new();
     let mut has_updates = false;
@@ -349,7 +752,7 @@ pub async fn update_title(
         update_parts.push("subtitle = ?");
         has_updates = true;
     }
-    if req.isbn.is_some() {
+    if isbn.is_some() {
         update_parts.push("isbn = ?");
         has_updates = true;
     }
@@ -369,15 +772,27 @@ pub async fn update_title(
         update_parts.push("pages = ?");
         has_updates = true;
     }
-    if req.language.is_some() {
+    if language.is_some() {
         update_parts.push("language = ?");
         has_updates = true;
     }
+    if req.original_title.is_some() {
+        update_parts.push("original_title = ?");
+        has_updates = true;
+    }
+    if original_language.is_some() {
+        update_parts.push("original_language = ?");
+        has_updates = true;
+    }
+    if req.translation_notes.is_some() {
+        update_parts.push("translation_notes = ?");
+        has_updates = true;
+    }
     if req.dewey_code.is_some() {
         update_parts.push("dewey_code = ?");
         has_updates = true;
     }
-    if req.genre_id.is_some() {
+    if genre_ids.is_some() {
         update_parts.push("genre_id = ?");
         has_updates = true;
     }
@@ -397,6 +812,9 @@ pub async fn update_title(
         update_parts.push("cover_url = ?");
         has_updates = true;
     }
+    if !custom_values.is_empty() {
+        has_updates = true;
+    }
 
     if !has_updates {
         warn!("No fields to update for title {}", id);
@@ -410,7 +828,7 @@ pub async fn update_title(
 
     update_parts.push("updated_at = NOW()");
     let update_clause = update_parts.join(", ");
-    let query = format!("UPDATE titles SET {} WHERE id = ?", update_clause);
+    let query = format!("UPDATE titles SET {} WHERE id = ? AND deleted_at IS NULL", update_clause);
 
     debug!("Update query: {}", query);
 
@@ -423,7 +841,7 @@ pub async fn update_title(
     if let Some(ref subtitle) = req.subtitle {
         query_builder = query_builder.bind(subtitle);
     }
-    if let Some(ref isbn) = req.isbn {
+    if let Some(ref isbn) = isbn {
         query_builder = query_builder.bind(isbn);
     }
     if let Some(ref publisher) = req.publisher {
@@ -438,14 +856,23 @@ pub async fn update_title(
     if let Some(pages) = req.pages {
         query_builder = query_builder.bind(pages);
     }
-    if let Some(ref language) = req.language {
+    if let Some(ref language) = language {
         query_builder = query_builder.bind(language);
     }
+    if let Some(ref original_title) = req.original_title {
+        query_builder = query_builder.bind(non_blank(Some(original_title)));
+    }
+    if let Some(ref original_language) = original_language {
+        query_builder = query_builder.bind(original_language);
+    }
+    if let Some(ref translation_notes) = req.translation_notes {
+        query_builder = query_builder.bind(non_blank(Some(translation_notes)));
+    }
     if let Some(ref dewey_code) = req.dewey_code {
         query_builder = query_builder.bind(dewey_code);
     }
-    if let Some(ref genre_id) = req.genre_id {
-        query_builder = query_builder.bind(genre_id);
+    if let Some(ref genre_ids) = genre_ids {
+        query_builder = query_builder.bind(genre_ids.first());
     }
     if let Some(ref series_id) = req.series_id {
         query_builder = query_builder.bind(series_id);
@@ -462,7 +889,63 @@ pub async fn update_title(
 
     query_builder = query_builder.bind(id.as_str());
 
-    match query_builder.execute(&data.db_pool).await {
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => {
+            error!("Failed to start transaction: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to update title" }
+            }));
+        }
+    };
+
+    // Snapshot the tracked fields around the update for the revision history
+    let before = match fetch_title_snapshot(&mut tx, &id).await {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => {
+            warn!("Title {} not found", id);
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": {
+                    "code": "NOT_FOUND",
+                    "message": "Title not found"
+                }
+            }));
+        }
+        Err(e) => {
+            error!("Database error while fetching title: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to update title" }
+            }));
+        }
+    };
+
+    let result = query_builder.execute(&mut *tx).await;
+    let result = match (result, &genre_ids) {
+        (Ok(result), Some(genre_ids)) if result.rows_affected() > 0 => {
+            replace_title_genres(&mut tx, &id, genre_ids).await.map(|_| result)
+        }
+        (result, _) => result,
+    };
+    let result = match result {
+        Ok(result) if result.rows_affected() > 0 => {
+            save_custom_values(&mut tx, CustomFieldScope::Title, &id, &custom_values).await.map(|_| result)
+        }
+        result => result,
+    };
+    let result = match result {
+        Ok(result) if result.rows_affected() > 0 => match fetch_title_snapshot(&mut tx, &id).await {
+            Ok(Some(after)) => record_revision(&mut tx, &id, &user.id, &before, &after).await.map(|_| result),
+            Ok(None) => Ok(result),
+            Err(e) => Err(e),
+        },
+        result => result,
+    };
+    let result = match result {
+        Ok(result) => tx.commit().await.map(|_| result),
+        Err(e) => Err(e),
+    };
+
+    match result {
         Ok(result) => {
             if result.rows_affected() == 0 {
                 warn!("Title {} not found", id);
@@ -494,13 +977,15 @@ pub async fn update_title(
     }
 }
 
-/// Deletes a title from the library.
+/// Moves a title to the trash.
 ///
 /// **Endpoint**: `DELETE /api/v1/titles/{id}`
 ///
-/// This handler deletes a title from the database. A title can only be deleted if it has
-/// no physical volumes (copies) associated with it. This business rule ensures that
-/// physical inventory is not lost by accidentally deleting a title that still has copies.
+/// The title is only marked with `deleted_at`: it disappears from listings and searches
+/// but can be restored from the trash until it is purged (see `handlers::trash`).
+/// A title can only be deleted if it has no physical volumes (copies) associated with it.
+/// This business rule ensures that physical inventory is not lost by accidentally
+/// deleting a title that still has copies.
 ///
 /// # Arguments
 ///
@@ -509,8 +994,8 @@ pub async fn update_title(
 ///
 /// # Returns
 ///
-/// * `HttpResponse::Ok` (200) if the title was successfully deleted
-/// * `HttpResponse::NotFound` (404) if no title exists with the given ID
+/// * `HttpResponse::Ok` (200) if the title was moved to the trash
+/// * `HttpResponse::NotFound` (404) if no title exists with the given ID, or it is already in the trash
 /// * `HttpResponse::Conflict` (409) if the title has volumes and cannot be deleted
 /// * `HttpResponse::InternalServerError` (500) if the database operation fails
 ///
@@ -519,7 +1004,7 @@ pub async fn update_title(
 /// Success:
 /// ```json
 /// {
-///   "message": "Title deleted successfully"
+///   "message": "Title moved to the trash"
 /// }
 /// ```
 ///
@@ -552,7 +1037,7 @@ pub async fn delete_title(
         SELECT COUNT(v.id) as volume_count
         FROM titles t
         LEFT JOIN volumes v ON t.id = v.title_id
-        WHERE t.id = ?
+        WHERE t.id = ? AND t.deleted_at IS NULL
         GROUP BY t.id
     "#;
 
@@ -604,8 +1089,8 @@ pub async fn delete_title(
         }
     }
 
-    // Delete the title
-    let delete_query = "DELETE FROM titles WHERE id = ?";
+    // Move the title to the trash
+    let delete_query = "UPDATE titles SET deleted_at = UTC_TIMESTAMP() WHERE id = ? AND deleted_at IS NULL";
 
     match sqlx::query(delete_query)
         .bind(id.as_str())
@@ -622,9 +1107,9 @@ pub async fn delete_title(
                     }
                 }))
             } else {
-                info!("Successfully deleted title {}", id);
+                info!("Moved title {} to the trash", id);
                 HttpResponse::Ok().json(serde_json::json!({
-                    "message": "Title deleted successfully"
+                    "message": "Title moved to the trash"
                 }))
             }
         }
@@ -657,7 +1142,7 @@ pub async fn list_title_authors(
             ta.role, ta.display_order
         FROM authors a
         INNER JOIN title_authors ta ON a.id = ta.author_id
-        WHERE ta.title_id = ?
+        WHERE ta.title_id = ? AND a.deleted_at IS NULL
         ORDER BY ta.display_order ASC, a.last_name ASC
     "#;
 
@@ -862,170 +1347,76 @@ pub async fn remove_author_from_title(
     }
 }
 
-/// Advanced search and filtering for titles.
-///
-/// **Endpoint**: `GET /api/v1/titles/search`
-///
-/// This handler provides comprehensive search and filtering capabilities for titles.
-/// It supports multiple filter criteria that can be combined, free text search,
-/// and various sorting options. Results include volume counts and availability information.
-///
-/// # Arguments
-///
-/// * `data` - Application state containing the database connection pool
-/// * `params` - Query parameters containing search filters (see TitleSearchParams)
-///
-/// # Query Parameters
-///
-/// All parameters are optional and can be combined:
-///
-/// * `q` - Free text search (searches title, subtitle, author names, ISBN)
-/// * `title` - Filter by title (partial match, case-insensitive)
-/// * `subtitle` - Filter by subtitle (partial match)
-/// * `isbn` - Filter by ISBN (partial or exact match)
-/// * `series_id` - Filter by series UUID
-/// * `author_id` - Filter by author UUID
-/// * `genre_id` - Filter by genre UUID
-/// * `publisher_id` - Filter by publisher UUID
-/// * `year_from` - Minimum publication year (inclusive)
-/// * `year_to` - Maximum publication year (inclusive)
-/// * `language` - Filter by language code (exact match)
-/// * `dewey_code` - Filter by Dewey classification (partial match)
-/// * `has_volumes` - Filter by ownership (true=owned, false=wishlist)
-/// * `available` - Filter by availability (true=at least one available volume)
-/// * `location_id` - Filter by storage location
-/// * `sort_by` - Sort field (title, publication_year, created_at)
-/// * `sort_order` - Sort direction (asc, desc)
-/// * `limit` - Maximum results (default: 100, max: 500)
-/// * `offset` - Results to skip (for pagination)
-///
-/// # Returns
-///
-/// * `HttpResponse::Ok` with JSON array of TitleWithCount objects on success
-/// * `HttpResponse::BadRequest` if validation fails
-/// * `HttpResponse::InternalServerError` if the database query fails
-///
-/// # Response Format
-///
-/// ```json
-/// {
-///   "results": [
-///     {
-///       "id": "uuid-string",
-///       "title": "Book Title",
-///       "subtitle": "Optional Subtitle",
-///       "isbn": "978-1234567890",
-///       "publication_year": 2020,
-///       "volume_count": 3,
-///       "available_count": 2,
-///       ...
-///     }
-///   ],
-///   "total": 42,
-///   "limit": 100,
-///   "offset": 0
-/// }
-/// ```
-///
-/// # Examples
-///
-/// ```
-/// // Search for "Harry Potter" books
-/// GET /api/v1/titles/search?q=harry+potter
-///
-/// // Find all books in a series, sorted by series number
-/// GET /api/v1/titles/search?series_id=uuid-here&sort_by=title
-///
-/// // Find wishlist items (books without volumes)
-/// GET /api/v1/titles/search?has_volumes=false
-///
-/// // Find available fiction books published after 2010
-/// GET /api/v1/titles/search?genre_id=fiction-uuid&year_from=2010&available=true
-///
-/// // Complex search with multiple filters
-/// GET /api/v1/titles/search?author_id=uuid&language=en&year_from=2000&year_to=2023&sort_by=publication_year&sort_order=desc
-/// ```
-///
-/// # Performance
+/// WHERE and HAVING conditions of a title search, with the values to bind to
+/// the WHERE placeholders.
 ///
-/// - Uses DISTINCT to avoid duplicates from JOINs
-/// - Employs database indexes for optimized filtering
-/// - LEFT JOINs preserve titles without volumes/authors
-/// - Query is built dynamically based on provided filters
-/// - LIMIT is enforced to prevent excessive result sets
-pub async fn search_titles(
-    data: web::Data<AppState>,
-    mut params: web::Query<TitleSearchParams>,
-) -> impl Responder {
-    info!("GET /api/v1/titles/search - Advanced title search with filters: {:?}", params);
+/// The conditions use the aliases of the search query: `t` for titles, `v` for
+/// volumes, `ta` and `a` for title authors and authors.
+pub(crate) struct SearchFilters {
+    pub where_clauses: Vec<String>,
+    pub bind_values: Vec<String>,
+    pub having_clauses: Vec<&'static str>,
+}
 
-    // Validate parameters
-    if let Err(e) = params.validate() {
-        warn!("Invalid search parameters: {}", e);
-        return HttpResponse::BadRequest().json(serde_json::json!({
-            "error": {
-                "code": "INVALID_PARAMETERS",
-                "message": e
-            }
-        }));
+impl SearchFilters {
+    /// Query selecting the UUID of every matching title, to bind with `bind_values`.
+    pub fn id_query(&self) -> String {
+        let mut query = format!(
+            "SELECT t.id FROM titles t \
+             LEFT JOIN volumes v ON t.id = v.title_id \
+             LEFT JOIN title_authors ta ON t.id = ta.title_id \
+             LEFT JOIN authors a ON ta.author_id = a.id AND a.deleted_at IS NULL \
+             WHERE {} GROUP BY t.id",
+            self.where_clauses.join(" AND ")
+        );
+        if !self.having_clauses.is_empty() {
+            query.push_str(&format!(" HAVING {}", self.having_clauses.join(" AND ")));
+        }
+        query
     }
+}
 
-    // Build the base query with all necessary JOINs
-    let base_query = r#"
-        SELECT DISTINCT
-            t.id,
-            t.title,
-            t.subtitle,
-            t.isbn,
-            t.publisher_old as publisher,
-            t.publisher_id,
-            t.publication_year,
-            t.pages,
-            t.language,
-            t.dewey_code,
-            t.genre_old as genre,
-            t.genre_id,
-            s.name as series_name,
-            t.series_id,
-            t.series_number,
-            t.summary,
-            t.cover_url,
-            t.image_mime_type,
-            t.image_filename,
-            t.created_at,
-            t.updated_at,
-            COUNT(DISTINCT v.id) as volume_count,
-            SUM(CASE WHEN v.loan_status = 'Available' THEN 1 ELSE 0 END) as available_count
-        FROM titles t
-        LEFT JOIN volumes v ON t.id = v.title_id
-        LEFT JOIN series s ON t.series_id = s.id
-        LEFT JOIN publishers p ON t.publisher_id = p.id
-        LEFT JOIN genres g ON t.genre_id = g.id
-        LEFT JOIN title_authors ta ON t.id = ta.title_id
-        LEFT JOIN authors a ON ta.author_id = a.id
-    "#;
-
-    // Build WHERE clauses dynamically
-    let mut where_clauses = vec!["1=1".to_string()]; // Always true, simplifies logic
+/// Translates search parameters into SQL conditions, or builds the error response
+/// for an invalid parameter. `user_id` is the reader of the `unread` filter; reading
+/// records are private, so nobody can filter on another user's readings.
+pub(crate) async fn search_filters(
+    pool: &MySqlPool,
+    params: &TitleSearchParams,
+    user_id: &str,
+) -> Result<SearchFilters, HttpResponse> {
+    // Build WHERE clauses dynamically; titles in the trash are never returned
+    let mut where_clauses = vec!["t.deleted_at IS NULL".to_string()];
     let mut bind_values: Vec<String> = Vec::new();
 
     // Free text search across multiple fields
     if let Some(ref q) = params.q {
         let search_term = format!("%{}%", q);
-        where_clauses.push(
-            "(t.title LIKE ? OR t.subtitle LIKE ? OR t.isbn LIKE ? OR \
-             CONCAT(a.first_name, ' ', a.last_name) LIKE ?)"
-            .to_string()
-        );
-        bind_values.push(search_term.clone());
-        bind_values.push(search_term.clone());
-        bind_values.push(search_term.clone());
-        bind_values.push(search_term);
+        let mut clause = "(t.title LIKE ? OR t.original_title LIKE ? OR t.subtitle LIKE ? OR t.isbn LIKE ? OR \
+             CONCAT(a.first_name, ' ', a.last_name) LIKE ? OR \
+             t.id IN (SELECT tc.title_id FROM title_contents tc \
+                LEFT JOIN title_content_authors tca ON tc.id = tca.content_id \
+                LEFT JOIN authors ca ON tca.author_id = ca.id AND ca.deleted_at IS NULL \
+                WHERE tc.title LIKE ? OR CONCAT(ca.first_name, ' ', ca.last_name) LIKE ?)"
+            .to_string();
+        for _ in 0..7 {
+            bind_values.push(search_term.clone());
+        }
+
+        // A valid ISBN also finds its ISBN-10/ISBN-13 twin
+        if let Ok(isbn) = Isbn::parse(q) {
+            let (isbn_clause, isbn_values) = identifier_match_clause(&isbn_forms(&isbn));
+            clause.push_str(" OR ");
+            clause.push_str(&isbn_clause);
+            bind_values.extend(isbn_values);
+        }
+        clause.push(')');
+        where_clauses.push(clause);
     }
 
-    // Title filter
+    // Title filter, on the title or the original title of a translation
     if let Some(ref title) = params.title {
-        where_clauses.push("t.title LIKE ?".to_string());
+        where_clauses.push("(t.title LIKE ? OR t.original_title LIKE ?)".to_string());
+        bind_values.push(format!("%{}%", title));
         bind_values.push(format!("%{}%", title));
     }
 
@@ -1037,8 +1428,26 @@ pub async fn search_titles(
 
     // ISBN filter
     if let Some(ref isbn) = params.isbn {
-        where_clauses.push("t.isbn LIKE ?".to_string());
-        bind_values.push(format!("%{}%", isbn));
+        match Isbn::parse(isbn) {
+            Ok(isbn) => {
+                let (isbn_clause, isbn_values) = identifier_match_clause(&isbn_forms(&isbn));
+                where_clauses.push(isbn_clause);
+                bind_values.extend(isbn_values);
+            }
+            Err(_) => {
+                // Partial ISBN, ignore hyphens and spaces on both sides
+                where_clauses.push(format!("{} LIKE ?", COMPACT_ISBN_SQL));
+                bind_values.push(format!("%{}%", isbn::normalize(isbn)));
+            }
+        }
+    }
+
+    // Identifier filter (any kind, normalized every way it could be read)
+    if let Some(ref identifier) = params.identifier {
+        let (identifier_clause, identifier_values) =
+            identifier_match_clause(&identifiers::search_candidates(identifier));
+        where_clauses.push(identifier_clause);
+        bind_values.extend(identifier_values);
     }
 
     // Series filter
@@ -1047,16 +1456,123 @@ pub async fn search_titles(
         bind_values.push(series_id.clone());
     }
 
+    // Work filter (all editions and translations of a work)
+    if let Some(ref work_id) = params.work_id {
+        where_clauses.push("t.work_id = ?".to_string());
+        bind_values.push(work_id.clone());
+    }
+
+    // Tag filter: titles carrying all (default) or any of the tags
+    let tag_names = params.tag_names();
+    if !tag_names.is_empty() {
+        let placeholders = vec!["?"; tag_names.len()].join(", ");
+        let having = if params.tags_match.as_deref() == Some("any") {
+            String::new()
+        } else {
+            format!("HAVING COUNT(DISTINCT tg.id) = {}", tag_names.len())
+        };
+        where_clauses.push(format!(
+            "t.id IN (SELECT tt.title_id FROM title_tags tt INNER JOIN tags tg ON tt.tag_id = tg.id \
+             WHERE tg.name IN ({}) GROUP BY tt.title_id {})",
+            placeholders, having
+        ));
+        bind_values.extend(tag_names);
+    }
+
+    // Custom field filter; volume fields match titles with at least one such volume
+    if let (Some(field_id), Some(value)) = (&params.custom_field_id, &params.custom_field_value) {
+        let field = match crate::handlers::custom_fields::fetch_custom_field(pool, field_id).await {
+            Ok(Some(field)) => field,
+            Ok(None) => {
+                warn!("Unknown custom field {} in search", field_id);
+                return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": {
+                        "code": "INVALID_PARAMETERS",
+                        "message": format!("Unknown custom field: {}", field_id)
+                    }
+                })));
+            }
+            Err(e) => {
+                error!("Database error while fetching custom field {}: {}", field_id, e);
+                return Err(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": {
+                        "code": "DATABASE_ERROR",
+                        "message": "Failed to fetch custom field",
+                        "details": {
+                            "error": e.to_string()
+                        }
+                    }
+                })));
+            }
+        };
+
+        // A blank value matches any title where the field is set
+        let value_condition = match field.normalize_value(value) {
+            Ok(None) => None,
+            Ok(Some(_)) if field.field_type == CustomFieldType::Text => {
+                Some(("cv.value LIKE ?", format!("%{}%", value.trim())))
+            }
+            Ok(Some(normalized)) => Some(("cv.value = ?", normalized)),
+            Err(message) => {
+                warn!("Invalid custom field value in search: {}", message);
+                return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": {
+                        "code": "INVALID_PARAMETERS",
+                        "message": format!("{}: {}", field.name, message)
+                    }
+                })));
+            }
+        };
+        let subquery = match field.scope {
+            CustomFieldScope::Title => "SELECT cv.title_id FROM title_custom_values cv",
+            CustomFieldScope::Volume => {
+                "SELECT cvol.title_id FROM volume_custom_values cv INNER JOIN volumes cvol ON cv.volume_id = cvol.id"
+            }
+        };
+        bind_values.push(field_id.clone());
+        match value_condition {
+            Some((condition, bind_value)) => {
+                where_clauses.push(format!("t.id IN ({} WHERE cv.field_id = ? AND {})", subquery, condition));
+                bind_values.push(bind_value);
+            }
+            None => where_clauses.push(format!("t.id IN ({} WHERE cv.field_id = ?)", subquery)),
+        }
+    }
+
     // Author filter
     if let Some(ref author_id) = params.author_id {
-        where_clauses.push("ta.author_id = ?".to_string());
+        // Anthologies and collections also match through the authors of their contents
+        where_clauses.push(
+            "(ta.author_id = ? OR t.id IN (SELECT tc.title_id FROM title_contents tc \
+             INNER JOIN title_content_authors tca ON tc.id = tca.content_id WHERE tca.author_id = ?))"
+                .to_string(),
+        );
+        bind_values.push(author_id.clone());
         bind_values.push(author_id.clone());
     }
 
-    // Genre filter
+    // Genre filter, including the sub-genres of the requested genre
     if let Some(ref genre_id) = params.genre_id {
-        where_clauses.push("t.genre_id = ?".to_string());
-        bind_values.push(genre_id.clone());
+        let genre_ids = match crate::handlers::genres::genre_with_descendants(pool, genre_id).await {
+            Ok(ids) => ids,
+            Err(e) => {
+                error!("Database error while resolving sub-genres of {}: {}", genre_id, e);
+                return Err(HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": {
+                        "code": "DATABASE_ERROR",
+                        "message": "Failed to resolve genre hierarchy",
+                        "details": {
+                            "error": e.to_string()
+                        }
+                    }
+                })));
+            }
+        };
+        where_clauses.push(format!(
+            "t.id IN (SELECT title_id FROM title_genres WHERE genre_id IN ({}))",
+            vec!["?"; genre_ids.len()].join(", ")
+        ));
+        bind_values.extend(genre_ids);
     }
 
     // Publisher filter
@@ -1078,8 +1594,21 @@ pub async fn search_titles(
 
     // Language filter
     if let Some(ref language) = params.language {
+        // Search by any code or name of the language, as it is stored normalized
+        let code = match languages::normalize(language) {
+            Ok(code) => code,
+            Err(message) => {
+                warn!("Invalid language in search: {}", message);
+                return Err(HttpResponse::BadRequest().json(serde_json::json!({
+                    "error": {
+                        "code": "INVALID_PARAMETERS",
+                        "message": format!("Invalid language: {}", message)
+                    }
+                })));
+            }
+        };
         where_clauses.push("t.language = ?".to_string());
-        bind_values.push(language.clone());
+        bind_values.push(code);
     }
 
     // Dewey classification filter
@@ -1094,16 +1623,21 @@ pub async fn search_titles(
         bind_values.push(location_id.clone());
     }
 
-    // Build the complete WHERE clause
-    let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+    // Wishlist filter
+    if let Some(wishlist) = params.wishlist {
+        let exists = if wishlist { "EXISTS" } else { "NOT EXISTS" };
+        where_clauses.push(format!("{} (SELECT 1 FROM wishlist_items wi WHERE wi.title_id = t.id)", exists));
+    }
 
-    // Build GROUP BY and HAVING clauses
-    let group_by = r#"
-        GROUP BY t.id, t.title, t.subtitle, t.isbn, t.publisher_old, t.publisher_id,
-                 t.publication_year, t.pages, t.language, t.dewey_code,
-                 t.genre_old, t.genre_id, s.name, t.series_id, t.series_number, t.summary,
-                 t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at
-    "#;
+    // Reading filter (titles the reader has or has not finished)
+    if let Some(unread) = params.unread {
+        let exists = if unread { "NOT EXISTS" } else { "EXISTS" };
+        where_clauses.push(format!(
+            "{} (SELECT 1 FROM reading_records rr WHERE rr.title_id = t.id AND rr.user_id = ? AND rr.status = 'read')",
+            exists
+        ));
+        bind_values.push(user_id.to_string());
+    }
 
     let mut having_clauses = Vec::new();
 
@@ -1123,6 +1657,238 @@ pub async fn search_titles(
         }
     }
 
+    Ok(SearchFilters { where_clauses, bind_values, having_clauses })
+}
+
+/// UUIDs of all the titles matching search parameters, ignoring sorting and pagination.
+pub(crate) async fn search_title_ids(
+    pool: &MySqlPool,
+    params: &TitleSearchParams,
+    user_id: &str,
+) -> Result<Vec<String>, HttpResponse> {
+    let filters = search_filters(pool, params, user_id).await?;
+    let query = filters.id_query();
+
+    let mut query_builder = sqlx::query_scalar::<_, String>(&query);
+    for value in &filters.bind_values {
+        query_builder = query_builder.bind(value);
+    }
+    query_builder.fetch_all(pool).await.map_err(|e| {
+        error!("Database error while searching titles: {}", e);
+        HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": {
+                "code": "DATABASE_ERROR",
+                "message": "Failed to search titles",
+                "details": { "error": e.to_string() }
+            }
+        }))
+    })
+}
+
+/// Wraps a search query so it keeps only the first matching title of each work,
+/// in the requested order. Titles without a work are a group of their own, and the
+/// limit applies to the groups.
+fn group_by_work_query(matched_query: &str, order_field: &str, order_direction: &str, limit_clause: &str) -> String {
+    let field = order_field.trim_start_matches("t.");
+    format!(
+        "SELECT * FROM ( \
+            SELECT matched.*, ROW_NUMBER() OVER ( \
+                PARTITION BY COALESCE(matched.work_id, matched.id) \
+                ORDER BY matched.{field} {order_direction}, matched.id) AS work_rank \
+            FROM ({matched_query}) matched \
+         ) ranked WHERE work_rank = 1 ORDER BY {field} {order_direction} {limit_clause}"
+    )
+}
+
+/// Advanced search and filtering for titles.
+///
+/// **Endpoint**: `GET /api/v1/titles/search`
+///
+/// This handler provides comprehensive search and filtering capabilities for titles.
+/// It supports multiple filter criteria that can be combined, free text search,
+/// and various sorting options. Results include volume counts and availability information.
+///
+/// # Arguments
+///
+/// * `data` - Application state containing the database connection pool
+/// * `params` - Query parameters containing search filters (see TitleSearchParams)
+///
+/// # Query Parameters
+///
+/// All parameters are optional and can be combined:
+///
+/// * `q` - Free text search (searches title, original title, subtitle, author names, ISBN, and the story
+///   titles and author names of the contents of anthologies)
+/// * `title` - Filter by title or original title (partial match, case-insensitive)
+/// * `subtitle` - Filter by subtitle (partial match)
+/// * `isbn` - Filter by ISBN. A valid ISBN also matches its ISBN-10/ISBN-13 twin and
+///   the titles' additional identifiers; anything else is a partial match ignoring hyphens
+/// * `identifier` - Filter by any identifier (ISBN, EAN, ISSN, LCCN, OCLC), matched
+///   against the ISBN and the additional identifiers of each title
+/// * `series_id` - Filter by series UUID
+/// * `work_id` - Filter by work UUID (all editions and translations of a work)
+/// * `tags` - Comma-separated tag names
+/// * `tags_match` - `all` (default) to require every tag, `any` to require at least one
+/// * `custom_field_id`, `custom_field_value` - Custom field filter: substring match for
+///   text fields, exact value otherwise, any value when blank. Volume fields match titles
+///   with at least one volume carrying the value
+/// * `author_id` - Filter by author UUID, including anthologies containing a story by the author
+/// * `genre_id` - Filter by genre UUID (primary or secondary genre), sub-genres included
+/// * `publisher_id` - Filter by publisher UUID
+/// * `year_from` - Minimum publication year (inclusive)
+/// * `year_to` - Maximum publication year (inclusive)
+/// * `language` - Filter by language, given as any of its ISO 639 codes or names
+/// * `dewey_code` - Filter by Dewey classification (partial match)
+/// * `has_volumes` - Filter by ownership (true=owned, false=no volumes yet)
+/// * `wishlist` - Filter by wishlist entry (true=on the wishlist, false=not on it)
+/// * `unread` - Filter by reading of the current user (true=not read yet, false=read)
+/// * `available` - Filter by availability (true=at least one available volume)
+/// * `location_id` - Filter by storage location
+/// * `group_by_work` - Return one title per work (the first in sort order) instead
+///   of every edition; titles without a work are returned as-is
+/// * `sort_by` - Sort field (title, publication_year, created_at, rating, series_number)
+/// * `sort_order` - Sort direction (asc, desc)
+/// * `limit` - Maximum results (default: 100, max: 500)
+/// * `offset` - Results to skip (for pagination)
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of TitleWithCount objects on success
+/// * `HttpResponse::BadRequest` if validation fails
+/// * `HttpResponse::InternalServerError` if the database query fails
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "results": [
+///     {
+///       "id": "uuid-string",
+///       "title": "Book Title",
+///       "subtitle": "Optional Subtitle",
+///       "isbn": "978-1234567890",
+///       "publication_year": 2020,
+///       "volume_count": 3,
+///       "available_count": 2,
+///       ...
+///     }
+///   ],
+///   "total": 42,
+///   "limit": 100,
+///   "offset": 0
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// // Search for "Harry Potter" books
+/// GET /api/v1/titles/search?q=harry+potter
+///
+/// // Find all books in a series, sorted by series number
+/// GET /api/v1/titles/search?series_id=uuid-here&sort_by=series_number
+///
+/// // Find wishlist items
+/// GET /api/v1/titles/search?wishlist=true
+///
+/// // Find books tagged either "signed" or "gift from grandma"
+/// GET /api/v1/titles/search?tags=signed,gift+from+grandma&tags_match=any
+///
+/// // Find available fiction books published after 2010
+/// GET /api/v1/titles/search?genre_id=fiction-uuid&year_from=2010&available=true
+///
+/// // Complex search with multiple filters
+/// GET /api/v1/titles/search?author_id=uuid&language=en&year_from=2000&year_to=2023&sort_by=publication_year&sort_order=desc
+/// ```
+///
+/// # Performance
+///
+/// - Uses DISTINCT to avoid duplicates from JOINs
+/// - Employs database indexes for optimized filtering
+/// - LEFT JOINs preserve titles without volumes/authors
+/// - Query is built dynamically based on provided filters
+/// - LIMIT is enforced to prevent excessive result sets
+pub async fn search_titles(
+    data: web::Data<AppState>,
+    mut params: web::Query<TitleSearchParams>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("GET /api/v1/titles/search - Advanced title search with filters: {:?}", params);
+
+    // Validate parameters
+    if let Err(e) = params.validate() {
+        warn!("Invalid search parameters: {}", e);
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_PARAMETERS",
+                "message": e
+            }
+        }));
+    }
+
+    // Build the base query with all necessary JOINs
+    let base_query = format!(r#"
+        SELECT DISTINCT
+            t.id,
+            t.title,
+            t.subtitle,
+            t.isbn,
+            t.publisher_old as publisher,
+            t.publisher_id,
+            t.publication_year,
+            t.pages,
+            t.language,
+            t.original_title,
+            t.original_language,
+            t.translation_notes,
+            t.dewey_code,
+            t.genre_old as genre,
+            t.genre_id,
+            {genre_ids} as genre_ids,
+            {custom_fields} as custom_fields,
+            {wishlist_priority} as wishlist_priority,
+            s.name as series_name,
+            t.series_id,
+            t.series_number,
+            {series_position} as series_position,
+            t.work_id,
+            t.summary,
+            t.cover_url,
+            t.image_mime_type,
+            t.image_filename,
+            t.created_at,
+            t.updated_at,
+            {average_rating} as average_rating,
+            {rating_count} as rating_count,
+            COUNT(DISTINCT v.id) as volume_count,
+            SUM(CASE WHEN v.loan_status = 'Available' THEN 1 ELSE 0 END) as available_count
+        FROM titles t
+        LEFT JOIN volumes v ON t.id = v.title_id
+        LEFT JOIN series s ON t.series_id = s.id
+        LEFT JOIN publishers p ON t.publisher_id = p.id
+        LEFT JOIN genres g ON t.genre_id = g.id
+        LEFT JOIN title_authors ta ON t.id = ta.title_id
+        LEFT JOIN authors a ON ta.author_id = a.id AND a.deleted_at IS NULL
+    "#, genre_ids = GENRE_IDS_SQL, custom_fields = CUSTOM_FIELDS_SQL, wishlist_priority = WISHLIST_PRIORITY_SQL,
+        series_position = SERIES_POSITION_SQL, average_rating = AVERAGE_RATING_SQL, rating_count = RATING_COUNT_SQL);
+
+    let SearchFilters { where_clauses, bind_values, having_clauses } =
+        match search_filters(&data.db_pool, &params, &user.id).await {
+            Ok(filters) => filters,
+            Err(response) => return response,
+        };
+
+    // Build the complete WHERE clause
+    let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+
+    // Build GROUP BY and HAVING clauses
+    let group_by = r#"
+        GROUP BY t.id, t.title, t.subtitle, t.isbn, t.publisher_old, t.publisher_id,
+                 t.publication_year, t.pages, t.language, t.original_title, t.original_language, t.translation_notes, t.dewey_code,
+                 t.genre_old, t.genre_id, s.name, t.series_id, t.series_number, t.work_id, t.summary,
+                 t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at
+    "#;
+
     let having_clause = if !having_clauses.is_empty() {
         format!("HAVING {}", having_clauses.join(" AND "))
     } else {
@@ -1133,6 +1899,8 @@ pub async fn search_titles(
     let order_field = match params.sort_by.as_str() {
         "publication_year" => "t.publication_year",
         "created_at" => "t.created_at",
+        "rating" => "average_rating",
+        "series_number" => "series_position",
         _ => "t.title", // default to title
     };
 
@@ -1148,10 +1916,15 @@ pub async fn search_titles(
     let limit_clause = format!("LIMIT {} OFFSET {}", params.limit, params.offset);
 
     // Combine all parts into final query
-    let final_query = format!(
-        "{} {} {} {} {} {}",
-        base_query, where_clause, group_by, having_clause, order_by, limit_clause
-    );
+    let final_query = if params.group_by_work.unwrap_or(false) {
+        let matched_query = format!("{} {} {} {}", base_query, where_clause, group_by, having_clause);
+        group_by_work_query(&matched_query, order_field, order_direction, &limit_clause)
+    } else {
+        format!(
+            "{} {} {} {} {} {}",
+            base_query, where_clause, group_by, having_clause, order_by, limit_clause
+        )
+    };
 
     debug!("Executing search query: {}", final_query);
     debug!("Bind values: {:?}", bind_values);
@@ -1193,12 +1966,19 @@ pub async fn search_titles(
                             publication_year: row.get("publication_year"),
                             pages: row.get("pages"),
                             language: row.get("language"),
+                            original_title: row.get("original_title"),
+                            original_language: row.get("original_language"),
+                            translation_notes: row.get("translation_notes"),
                             dewey_code: row.get("dewey_code"),
                             genre: row.get("genre"),
                             genre_id: row.get("genre_id"),
+                            genre_ids: parse_genre_ids(row.get("genre_ids")),
                             series_name: row.get("series_name"),
                             series_id: row.get("series_id"),
                             series_number: row.get("series_number"),
+                            work_id: row.get("work_id"),
+                            custom_fields: parse_custom_values(row.get("custom_fields")),
+                            wishlist_priority: row.get::<Option<String>, _>("wishlist_priority").as_deref().and_then(WishlistPriority::from_db),
                             summary: row.get("summary"),
                             cover_url: row.get("cover_url"),
                             image_data: None,
@@ -1208,6 +1988,8 @@ pub async fn search_titles(
                             updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
                         },
                         volume_count: row.get("volume_count"),
+                        average_rating: row.get("average_rating"),
+                        rating_count: row.get("rating_count"),
                     })
                 })
                 .collect();
@@ -1238,7 +2020,7 @@ pub async fn search_titles(
 /// Helper function to calculate similarity between two titles
 ///
 /// Uses multiple factors to determine if two titles are likely duplicates:
-/// - ISBN exact match (100% weight if both present)
+/// - ISBN match, ISBN-10 and ISBN-13 forms being equivalent (100% weight if both present)
 /// - Title similarity using Jaro-Winkler distance
 /// - Author names comparison
 /// - Publication year proximity
@@ -1260,10 +2042,13 @@ fn calculate_similarity(title1: &TitleWithCount, title2: &TitleWithCount) -> (f6
     // ISBN match - strongest indicator (100% if both present and match)
     if let (Some(isbn1), Some(isbn2)) = (&title1.title.isbn, &title2.title.isbn) {
         if !isbn1.is_empty() && !isbn2.is_empty() {
-            let normalized_isbn1 = isbn1.replace("-", "").replace(" ", "");
-            let normalized_isbn2 = isbn2.replace("-", "").replace(" ", "");
+            // Valid ISBNs compare as ISBN-13, so an ISBN-10 matches its ISBN-13 twin
+            let same_isbn = match (Isbn::parse(isbn1), Isbn::parse(isbn2)) {
+                (Ok(parsed1), Ok(parsed2)) => parsed1 == parsed2,
+                _ => isbn::normalize(isbn1) == isbn::normalize(isbn2),
+            };
 
-            if normalized_isbn1 == normalized_isbn2 {
+            if same_isbn {
                 score = 100.0;
                 reasons.push("ISBN match".to_string());
                 return (score, reasons); // ISBN match is definitive
@@ -1321,7 +2106,7 @@ fn calculate_similarity(title1: &TitleWithCount, title2: &TitleWithCount) -> (f6
 /// **Endpoint**: `GET /api/v1/titles/duplicates`
 ///
 /// This handler analyzes all titles in the database to find potential duplicates based on
-/// similarity metrics. It compares ISBNs, titles, authors, and publication years.
+/// similarity metrics. It compares ISBNs and other identifiers, titles, authors, and publication years.
 ///
 /// # Query Parameters
 ///
@@ -1334,9 +2119,10 @@ fn calculate_similarity(title1: &TitleWithCount, title2: &TitleWithCount) -> (f6
 ///
 /// # Algorithm
 ///
-/// 1. Fetches all titles from the database
+/// 1. Fetches all titles and their additional identifiers from the database
 /// 2. Compares every pair of titles (O(n^2) complexity - resource intensive for large libraries)
-/// 3. Calculates a similarity score for each pair
+/// 3. Scores 100 when the pair shares an ISBN, LCCN or OCLC number (ISSNs are shared by a
+///    whole series and ignored), otherwise calculates a similarity score
 /// 4. Categorizes matches into High, Medium, and Low confidence buckets
 pub async fn detect_duplicates(
     data: web::Data<AppState>,
@@ -1353,24 +2139,29 @@ pub async fn detect_duplicates(
 
     debug!("Minimum similarity score threshold: {}", min_score);
 
-    let query_str = r#"
+    let query_str = format!(r#"
         SELECT
             t.id, t.title, t.subtitle, t.isbn, t.publisher_old as publisher, t.publisher_id,
-            t.publication_year, t.pages, t.language, t.dewey_code,
-            t.genre_old as genre, t.genre_id, s.name as series_name, t.series_id, t.series_number,
-            t.summary, t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at,
+            t.publication_year, t.pages, t.language, t.original_title, t.original_language, t.translation_notes, t.dewey_code,
+            t.genre_old as genre, t.genre_id, {genre_ids} as genre_ids, {custom_fields} as custom_fields,
+            {wishlist_priority} as wishlist_priority, s.name as series_name, t.series_id, t.series_number,
+            t.work_id, t.summary, t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at,
+            {average_rating} as average_rating,
+            {rating_count} as rating_count,
             COUNT(v.id) as volume_count
         FROM titles t
         LEFT JOIN volumes v ON t.id = v.title_id
         LEFT JOIN series s ON t.series_id = s.id
+        WHERE t.deleted_at IS NULL
         GROUP BY t.id, t.title, t.subtitle, t.isbn, t.publisher_old, t.publisher_id,
-                 t.publication_year, t.pages, t.language, t.dewey_code,
+                 t.publication_year, t.pages, t.language, t.original_title, t.original_language, t.translation_notes, t.dewey_code,
                  t.genre_old, t.genre_id, s.name, t.series_id, t.series_number,
-                 t.summary, t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at
+                 t.work_id, t.summary, t.cover_url, t.image_mime_type, t.image_filename, t.created_at, t.updated_at
         ORDER BY t.title ASC
-    "#;
+    "#, genre_ids = GENRE_IDS_SQL, custom_fields = CUSTOM_FIELDS_SQL, wishlist_priority = WISHLIST_PRIORITY_SQL,
+        average_rating = AVERAGE_RATING_SQL, rating_count = RATING_COUNT_SQL);
 
-    let rows = match sqlx::query(query_str).fetch_all(&data.db_pool).await {
+    let rows = match sqlx::query(&query_str).fetch_all(&data.db_pool).await {
         Ok(rows) => rows,
         Err(e) => {
             error!("Database error: {}", e);
@@ -1392,24 +2183,68 @@ pub async fn detect_duplicates(
                 publisher: row.get("publisher"), publisher_id: row.get("publisher_id"),
                 publication_year: row.get("publication_year"), pages: row.get("pages"),
                 language: row.get("language"), dewey_code: row.get("dewey_code"),
+                original_title: row.get("original_title"), original_language: row.get("original_language"),
+                translation_notes: row.get("translation_notes"),
                 genre: row.get("genre"),
-                genre_id: row.get("genre_id"), series_name: row.get("series_name"),
+                genre_id: row.get("genre_id"), genre_ids: parse_genre_ids(row.get("genre_ids")),
+                series_name: row.get("series_name"),
                 series_id: row.get("series_id"), series_number: row.get("series_number"),
+                work_id: row.get("work_id"),
+                custom_fields: parse_custom_values(row.get("custom_fields")),
+                wishlist_priority: row.get::<Option<String>, _>("wishlist_priority").as_deref().and_then(WishlistPriority::from_db),
                 summary: row.get("summary"), cover_url: row.get("cover_url"), image_data: None,
                 image_mime_type: row.get("image_mime_type"), image_filename: row.get("image_filename"),
                 created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
                 updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
             },
             volume_count: row.get("volume_count"),
+            average_rating: row.get("average_rating"),
+            rating_count: row.get("rating_count"),
         })
     }).collect();
 
+    // Additional identifiers, grouped by title
+    let identifier_rows = match sqlx::query("SELECT title_id, identifier_type, value FROM title_identifiers")
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Database error: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to fetch title identifiers" }
+            }));
+        }
+    };
+    let mut identifiers_by_title: HashMap<String, Vec<(IdentifierType, String)>> = HashMap::new();
+    for row in identifier_rows {
+        let identifier_type: String = row.get("identifier_type");
+        if let Some(kind) = IdentifierType::from_db(&identifier_type) {
+            identifiers_by_title
+                .entry(row.get("title_id"))
+                .or_default()
+                .push((kind, row.get("value")));
+        }
+    }
+    let keys: Vec<HashSet<String>> = titles
+        .iter()
+        .map(|t| {
+            let identifiers = identifiers_by_title.get(&t.title.id.to_string());
+            edition_keys(t.title.isbn.as_deref(), identifiers.map(Vec::as_slice).unwrap_or(&[]))
+        })
+        .collect();
+
     info!("Comparing {} titles for duplicates", titles.len());
 
     let mut all_pairs = Vec::new();
     for i in 0..titles.len() {
         for j in (i + 1)..titles.len() {
-            let (similarity_score, match_reasons) = calculate_similarity(&titles[i], &titles[j]);
+            // A shared ISBN, LCCN or OCLC number is definitive, like an ISBN match
+            let shared_key = keys[i].intersection(&keys[j]).min();
+            let (similarity_score, match_reasons) = match shared_key {
+                Some(key) => (100.0, vec![format!("Identifier match ({})", key)]),
+                None => calculate_similarity(&titles[i], &titles[j]),
+            };
             if similarity_score >= min_score {
                 let confidence = if similarity_score >= 90.0 {
                     DuplicateConfidence::High
@@ -1450,12 +2285,23 @@ pub async fn detect_duplicates(
     })
 }
 
+/// Whether a merge moves the secondary title's contents to the primary title, given
+/// how many entries the primary title has.
+///
+/// The contents are kept only if the primary has none, as both titles usually list
+/// the same stories; otherwise they are deleted with the secondary title.
+fn merge_moves_contents(primary_content_count: i64) -> bool {
+    primary_content_count == 0
+}
+
 /// Merges a secondary title into a primary title.
 ///
 /// **Endpoint**: `POST /api/v1/titles/{primary_id}/merge/{secondary_id}`
 ///
-/// This handler merges two title records. All volumes associated with the secondary title
-/// are moved to the primary title, and then the secondary title is deleted.
+/// This handler merges two title records. All volumes, identifiers, tags, genres, relations, reading records, reviews
+/// and notes associated with the secondary title are moved to the primary title, and then the secondary title is deleted.
+/// The secondary title's contents are moved only when the primary title has none, and its
+/// wishlist entry only when the primary title is not on the wishlist.
 ///
 /// # Arguments
 ///
@@ -1513,9 +2359,9 @@ pub async fn merge_titles(
     };
 
     // Verify titles exist
-    let primary_exists = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ?")
+    let primary_exists = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
         .bind(&primary_id).fetch_one(&mut *tx).await.unwrap_or(0);
-    let secondary_exists = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ?")
+    let secondary_exists = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
         .bind(&secondary_id).fetch_one(&mut *tx).await.unwrap_or(0);
 
     if primary_exists == 0 || secondary_exists == 0 {
@@ -1554,6 +2400,169 @@ pub async fn merge_titles(
         }
     };
 
+    // Keep the secondary title's identifiers; those the primary already has are
+    // skipped and removed with the secondary title
+    if let Err(e) = sqlx::query("UPDATE IGNORE title_identifiers SET title_id = ? WHERE title_id = ?")
+        .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move identifiers: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move identifiers" }
+        }));
+    }
+
+    // Same for tags
+    if let Err(e) = sqlx::query("UPDATE IGNORE title_tags SET title_id = ? WHERE title_id = ?")
+        .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move tags: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move tags" }
+        }));
+    }
+
+    // Same for genres, which become secondary genres of the primary title; a primary
+    // title without any genre takes the secondary title's primary genre too
+    let primary_has_genres = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM title_genres WHERE title_id = ?")
+        .bind(&primary_id).fetch_one(&mut *tx).await
+    {
+        Ok(count) => count > 0,
+        Err(e) => {
+            error!("Failed to check genres: {}", e);
+            let _ = tx.rollback().await;
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to move genres" }
+            }));
+        }
+    };
+    let genres_result = sqlx::query(
+        "UPDATE IGNORE title_genres SET title_id = ?, is_primary = is_primary AND ? WHERE title_id = ?"
+    )
+    .bind(&primary_id).bind(!primary_has_genres).bind(&secondary_id)
+    .execute(&mut *tx).await;
+    let genres_result = match genres_result {
+        Ok(_) if !primary_has_genres => sqlx::query(
+            "UPDATE titles t SET genre_id = \
+             (SELECT genre_id FROM title_genres WHERE title_id = t.id AND is_primary) WHERE t.id = ?"
+        )
+        .bind(&primary_id).execute(&mut *tx).await,
+        other => other,
+    };
+    if let Err(e) = genres_result {
+        error!("Failed to move genres: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move genres" }
+        }));
+    }
+
+    // Same for custom field values; the primary title's values win
+    if let Err(e) = sqlx::query("UPDATE IGNORE title_custom_values SET title_id = ? WHERE title_id = ?")
+        .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move custom field values: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move custom field values" }
+        }));
+    }
+
+    let move_contents = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM title_contents WHERE title_id = ?")
+        .bind(&primary_id).fetch_one(&mut *tx).await
+    {
+        Ok(count) => merge_moves_contents(count),
+        Err(e) => {
+            error!("Failed to check contents: {}", e);
+            let _ = tx.rollback().await;
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to move contents" }
+            }));
+        }
+    };
+    if move_contents
+        && let Err(e) = sqlx::query("UPDATE title_contents SET title_id = ? WHERE title_id = ?")
+            .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move contents: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move contents" }
+        }));
+    }
+
+    // Point the secondary title's relations to the primary; relations between the two
+    // titles and those the primary already has are removed with the secondary title
+    for query in [
+        "UPDATE IGNORE title_relations SET title_id = ? WHERE title_id = ? AND related_title_id <> ?",
+        "UPDATE IGNORE title_relations SET related_title_id = ? WHERE related_title_id = ? AND title_id <> ?",
+    ] {
+        if let Err(e) = sqlx::query(query)
+            .bind(&primary_id).bind(&secondary_id).bind(&primary_id).execute(&mut *tx).await
+        {
+            error!("Failed to move relations: {}", e);
+            let _ = tx.rollback().await;
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to move relations" }
+            }));
+        }
+    }
+
+    // Keep the secondary title's wishlist entry, unless the primary title is already wished for
+    if let Err(e) = sqlx::query("UPDATE IGNORE wishlist_items SET title_id = ? WHERE title_id = ?")
+        .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move wishlist entry: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move wishlist entry" }
+        }));
+    }
+
+    // Keep every reader's history: the secondary title's readings become readings of the primary
+    if let Err(e) = sqlx::query("UPDATE reading_records SET title_id = ? WHERE title_id = ?")
+        .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move reading records: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move reading records" }
+        }));
+    }
+
+    // A user keeps one review per title: when both titles have one, the more recently
+    // updated review wins and the other is removed
+    for (query, first, second) in [
+        (
+            "DELETE p FROM title_reviews p \
+             INNER JOIN title_reviews s ON s.user_id = p.user_id AND s.title_id = ? \
+             WHERE p.title_id = ? AND s.updated_at > p.updated_at",
+            &secondary_id,
+            &primary_id,
+        ),
+        ("UPDATE IGNORE title_reviews SET title_id = ? WHERE title_id = ?", &primary_id, &secondary_id),
+    ] {
+        if let Err(e) = sqlx::query(query).bind(first).bind(second).execute(&mut *tx).await {
+            error!("Failed to move reviews: {}", e);
+            let _ = tx.rollback().await;
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to move reviews" }
+            }));
+        }
+    }
+
+    // Notes and quotes are not limited per title, they all move
+    if let Err(e) = sqlx::query("UPDATE title_notes SET title_id = ? WHERE title_id = ?")
+        .bind(&primary_id).bind(&secondary_id).execute(&mut *tx).await
+    {
+        error!("Failed to move notes: {}", e);
+        let _ = tx.rollback().await;
+        return HttpResponse::InternalServerError().json(serde_json::json!({
+            "error": { "code": "DATABASE_ERROR", "message": "Failed to move notes" }
+        }));
+    }
+
     // Delete secondary title
     if let Err(e) = sqlx::query("DELETE FROM titles WHERE id = ?").bind(&secondary_id).execute(&mut *tx).await {
         error!("Failed to delete secondary title: {}", e);
@@ -1583,3 +2592,74 @@ pub async fn merge_titles(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_genre_ids() {
+        assert_eq!(parse_genre_ids(Some("g1,g2,g3".to_string())), ids(&["g1", "g2", "g3"]));
+        assert_eq!(parse_genre_ids(Some("g1".to_string())), ids(&["g1"]));
+        assert!(parse_genre_ids(None).is_empty());
+    }
+
+    #[test]
+    fn test_dedup_genre_ids_keeps_primary_first() {
+        assert_eq!(dedup_genre_ids(&ids(&["g2", "g1", "g2", "g3", "g1"])), ids(&["g2", "g1", "g3"]));
+    }
+
+    #[test]
+    fn test_dedup_genre_ids_drops_blank_ids() {
+        assert_eq!(dedup_genre_ids(&ids(&[" ", "g1 ", "", " g1", "g2"])), ids(&["g1", "g2"]));
+        assert!(dedup_genre_ids(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_non_blank() {
+        assert_eq!(non_blank(Some("  Der Zauberberg ")), Some("Der Zauberberg".to_string()));
+        assert_eq!(non_blank(Some("   ")), None);
+        assert_eq!(non_blank(None), None);
+    }
+
+    #[test]
+    fn test_parse_original_language() {
+        assert_eq!(parse_original_language(Some(" German ")), Ok(Some("de".to_string())));
+        assert_eq!(parse_original_language(Some("pt-BR")), Ok(Some("pt".to_string())));
+        // A blank value clears the original language
+        assert_eq!(parse_original_language(Some("  ")), Ok(None));
+        assert_eq!(parse_original_language(None), Ok(None));
+
+        let (language, _) = parse_original_language(Some(" Klingon ")).unwrap_err();
+        assert_eq!(language, "Klingon");
+    }
+
+    #[test]
+    fn test_merge_moves_contents_only_to_titles_without_contents() {
+        assert!(merge_moves_contents(0));
+        assert!(!merge_moves_contents(1));
+        assert!(!merge_moves_contents(12));
+    }
+
+    #[test]
+    fn test_group_by_work_query_keeps_one_title_per_work() {
+        let query = group_by_work_query("SELECT t.* FROM titles t WHERE t.language = ?", "t.title", "ASC", "LIMIT 20 OFFSET 0");
+
+        assert!(query.contains("PARTITION BY COALESCE(matched.work_id, matched.id)"));
+        assert!(query.contains("ORDER BY matched.title ASC, matched.id"));
+        assert!(query.contains("FROM (SELECT t.* FROM titles t WHERE t.language = ?) matched"));
+        assert!(query.ends_with("WHERE work_rank = 1 ORDER BY title ASC LIMIT 20 OFFSET 0"));
+    }
+
+    #[test]
+    fn test_group_by_work_query_with_computed_sort_field() {
+        let query = group_by_work_query("SELECT t.* FROM titles t", "average_rating", "DESC", "LIMIT 5 OFFSET 10");
+
+        assert!(query.contains("ORDER BY matched.average_rating DESC, matched.id"));
+        assert!(query.ends_with("ORDER BY average_rating DESC LIMIT 5 OFFSET 10"));
+    }
+}
@@ -0,0 +1,324 @@
+//! API handlers for the trash of deleted titles, authors and borrowers.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/trash` - List deleted items, optionally of one type
+//! - `POST /api/v1/trash/{item_type}/{id}/restore` - Restore a deleted item
+//! - `DELETE /api/v1/trash/{item_type}/{id}` - Purge a deleted item for good (admin)
+//!
+//! Deleting a title, author or borrower only sets its `deleted_at` column;
+//! every list and search endpoint skips such rows. Items older than
+//! `trash.retention_days` are purged by [`run_retention_task`].
+//!
+//! `deleted_at` is set with `UTC_TIMESTAMP()` and compared against it, so the
+//! retention does not shift with the time zone of the database session.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{TrashItem, TrashItemType, TrashListParams};
+use crate::AppState;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use log::{info, warn, error};
+use sqlx::{MySqlPool, Row};
+
+/// How often [`run_retention_task`] looks for expired items.
+const RETENTION_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// The table of an item type and the SQL expression of its display name.
+fn item_table(item_type: TrashItemType) -> (&'static str, &'static str) {
+    match item_type {
+        TrashItemType::Title => ("titles", "title"),
+        TrashItemType::Author => ("authors", "CONCAT(first_name, ' ', last_name)"),
+        TrashItemType::Borrower => ("borrowers", "name"),
+    }
+}
+
+/// Condition an expired item must meet to be purged automatically.
+///
+/// Loans reference their title and borrower with `ON DELETE RESTRICT`, so
+/// items that appear in the loan history stay in the trash.
+fn purgeable_condition(item_type: TrashItemType) -> &'static str {
+    match item_type {
+        TrashItemType::Title => "NOT EXISTS (SELECT 1 FROM loans l WHERE l.title_id = titles.id)",
+        TrashItemType::Author => "TRUE",
+        TrashItemType::Borrower => "NOT EXISTS (SELECT 1 FROM loans l WHERE l.borrower_id = borrowers.id)",
+    }
+}
+
+/// Query listing the deleted items of the given types, most recently deleted first.
+fn list_query(item_types: &[TrashItemType]) -> String {
+    item_types
+        .iter()
+        .map(|item_type| {
+            let (table, name) = item_table(*item_type);
+            format!(
+                "SELECT '{}' as item_type, id, {} as name, deleted_at FROM {} WHERE deleted_at IS NOT NULL",
+                item_type, name, table
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ")
+        + " ORDER BY deleted_at DESC"
+}
+
+/// Query purging the items of a type deleted more than `?` days ago.
+fn expired_query(item_type: TrashItemType) -> String {
+    let (table, _) = item_table(item_type);
+    format!(
+        "DELETE FROM {} WHERE deleted_at < UTC_TIMESTAMP() - INTERVAL ? DAY AND {}",
+        table,
+        purgeable_condition(item_type)
+    )
+}
+
+/// Query restoring one item; rows that are not in the trash are left alone.
+fn restore_query(item_type: TrashItemType) -> String {
+    let (table, _) = item_table(item_type);
+    format!("UPDATE {} SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL", table)
+}
+
+/// Query purging one item; rows that are not in the trash are left alone.
+fn purge_query(item_type: TrashItemType) -> String {
+    let (table, _) = item_table(item_type);
+    format!("DELETE FROM {} WHERE id = ? AND deleted_at IS NOT NULL", table)
+}
+
+/// When an item deleted at `deleted_at` is purged, `None` when retention is disabled.
+fn purge_at(deleted_at: DateTime<Utc>, retention_days: i64) -> Option<DateTime<Utc>> {
+    (retention_days > 0).then(|| deleted_at + Duration::days(retention_days))
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn not_in_trash(item_type: TrashItemType, id: &str) -> HttpResponse {
+    warn!("No {} {} in the trash", item_type, id);
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": {
+            "code": "NOT_FOUND",
+            "message": format!("No {} with this ID in the trash", item_type)
+        }
+    }))
+}
+
+/// Permanently deletes the items that have been in the trash for more than `retention_days`.
+///
+/// Does nothing when `retention_days` is 0 or less. Returns the number of purged items.
+pub async fn purge_expired_items(pool: &MySqlPool, retention_days: i64) -> Result<u64, sqlx::Error> {
+    if retention_days <= 0 {
+        return Ok(0);
+    }
+
+    let mut purged = 0;
+    for item_type in TrashItemType::ALL {
+        purged += sqlx::query(&expired_query(item_type))
+            .bind(retention_days)
+            .execute(pool)
+            .await?
+            .rows_affected();
+    }
+    Ok(purged)
+}
+
+/// Purges expired trash items at startup and then every hour, for as long as the server runs.
+pub async fn run_retention_task(pool: MySqlPool, retention_days: i64) {
+    if retention_days <= 0 {
+        info!("Trash retention disabled, deleted items are kept until purged by hand");
+        return;
+    }
+
+    let mut interval = tokio::time::interval(RETENTION_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        match purge_expired_items(&pool, retention_days).await {
+            Ok(0) => {}
+            Ok(count) => info!("Purged {} item(s) deleted more than {} days ago", count, retention_days),
+            Err(e) => error!("Failed to purge expired trash items: {}", e),
+        }
+    }
+}
+
+/// Lists the deleted titles, authors and borrowers, most recently deleted first.
+///
+/// **Endpoint**: `GET /api/v1/trash`
+///
+/// # Query Parameters
+///
+/// * `item_type` - Only list items of this type (`title`, `author` or `borrower`)
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `TrashItem` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_trash(
+    data: web::Data<AppState>,
+    params: web::Query<TrashListParams>,
+) -> impl Responder {
+    info!("GET /api/v1/trash - Fetching deleted items");
+
+    let retention_days = data.trash.retention_days;
+    let item_types = match params.item_type {
+        Some(item_type) => vec![item_type],
+        None => TrashItemType::ALL.to_vec(),
+    };
+    match sqlx::query(&list_query(&item_types)).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let items: Vec<TrashItem> = rows
+                .iter()
+                .filter_map(|row| {
+                    let item_type: String = row.get("item_type");
+                    let deleted_at: NaiveDateTime = row.get("deleted_at");
+                    let deleted_at: DateTime<Utc> = DateTime::from_naive_utc_and_offset(deleted_at, Utc);
+                    Some(TrashItem {
+                        item_type: TrashItemType::from_db(&item_type)?,
+                        id: row.get("id"),
+                        name: row.get("name"),
+                        deleted_at,
+                        purge_at: purge_at(deleted_at, retention_days),
+                    })
+                })
+                .collect();
+            info!("Found {} items in the trash", items.len());
+            HttpResponse::Ok().json(items)
+        }
+        Err(e) => database_error("Failed to fetch trash", e),
+    }
+}
+
+/// Restores a deleted item, making it visible again everywhere.
+///
+/// **Endpoint**: `POST /api/v1/trash/{item_type}/{id}/restore`
+///
+/// A restored author gets back their links to titles, which are kept while in the trash.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the item was restored
+/// * `HttpResponse::NotFound` (404) if no item of that type and ID is in the trash
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn restore_trash_item(
+    data: web::Data<AppState>,
+    path: web::Path<(TrashItemType, String)>,
+) -> impl Responder {
+    let (item_type, id) = path.into_inner();
+    info!("POST /api/v1/trash/{}/{}/restore - Restoring item", item_type, id);
+
+    match sqlx::query(&restore_query(item_type)).bind(&id).execute(&data.db_pool).await {
+        Ok(result) if result.rows_affected() == 0 => not_in_trash(item_type, &id),
+        Ok(_) => {
+            info!("Restored {} {}", item_type, id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": format!("The {} was restored", item_type)
+            }))
+        }
+        Err(e) => database_error("Failed to restore item", e),
+    }
+}
+
+/// Permanently deletes an item from the trash.
+///
+/// **Endpoint**: `DELETE /api/v1/trash/{item_type}/{id}`
+///
+/// Purging a title also deletes its identifiers, tags, genres and custom values;
+/// purging an author deletes their links to titles.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the item was purged
+/// * `HttpResponse::NotFound` (404) if no item of that type and ID is in the trash
+/// * `HttpResponse::Conflict` (409) if loans still reference the title or borrower
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn purge_trash_item(
+    data: web::Data<AppState>,
+    path: web::Path<(TrashItemType, String)>,
+) -> impl Responder {
+    let (item_type, id) = path.into_inner();
+    info!("DELETE /api/v1/trash/{}/{} - Purging item", item_type, id);
+
+    match sqlx::query(&purge_query(item_type)).bind(&id).execute(&data.db_pool).await {
+        Ok(result) if result.rows_affected() == 0 => not_in_trash(item_type, &id),
+        Ok(_) => {
+            info!("Purged {} {}", item_type, id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": format!("The {} was permanently deleted", item_type)
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_foreign_key_violation() => {
+            warn!("Cannot purge {} {}: referenced by loans", item_type, id);
+            HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "HAS_LOAN_HISTORY",
+                    "message": format!("This {} appears in the loan history and cannot be purged", item_type)
+                }
+            }))
+        }
+        Err(e) => database_error("Failed to purge item", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_restore_and_purge_only_touch_trashed_items() {
+        assert_eq!(
+            restore_query(TrashItemType::Title),
+            "UPDATE titles SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL"
+        );
+        assert_eq!(
+            purge_query(TrashItemType::Borrower),
+            "DELETE FROM borrowers WHERE id = ? AND deleted_at IS NOT NULL"
+        );
+        for item_type in TrashItemType::ALL {
+            assert!(restore_query(item_type).ends_with("AND deleted_at IS NOT NULL"));
+            assert!(purge_query(item_type).ends_with("AND deleted_at IS NOT NULL"));
+        }
+    }
+
+    #[test]
+    fn test_retention_keeps_items_with_loan_history() {
+        assert_eq!(
+            expired_query(TrashItemType::Title),
+            "DELETE FROM titles WHERE deleted_at < UTC_TIMESTAMP() - INTERVAL ? DAY \
+             AND NOT EXISTS (SELECT 1 FROM loans l WHERE l.title_id = titles.id)"
+        );
+        assert!(expired_query(TrashItemType::Borrower).contains("l.borrower_id = borrowers.id"));
+        assert!(expired_query(TrashItemType::Author).ends_with("AND TRUE"));
+    }
+
+    #[test]
+    fn test_purge_at() {
+        let deleted_at = Utc.with_ymd_and_hms(2026, 3, 30, 22, 15, 0).unwrap();
+        assert_eq!(
+            purge_at(deleted_at, 30),
+            Some(Utc.with_ymd_and_hms(2026, 4, 29, 22, 15, 0).unwrap())
+        );
+        assert_eq!(purge_at(deleted_at, 0), None);
+        assert_eq!(purge_at(deleted_at, -1), None);
+    }
+
+    #[test]
+    fn test_list_query() {
+        assert_eq!(
+            list_query(&[TrashItemType::Author]),
+            "SELECT 'author' as item_type, id, CONCAT(first_name, ' ', last_name) as name, deleted_at \
+             FROM authors WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC"
+        );
+
+        let query = list_query(&TrashItemType::ALL);
+        assert_eq!(query.matches(" UNION ALL ").count(), 2);
+        assert_eq!(query.matches("ORDER BY").count(), 1);
+        for item_type in TrashItemType::ALL {
+            assert!(query.contains(&format!("'{}' as item_type", item_type)));
+        }
+    }
+}
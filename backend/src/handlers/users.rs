@@ -0,0 +1,378 @@
+//! API handlers for managing user accounts.
+//!
+//! This module provides HTTP handlers for listing, creating, updating, and deleting
+//! the accounts allowed to use the API. Passwords are only ever stored as Argon2 hashes
+//! and are never returned.
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::{self, AuthenticatedUser, MIN_PASSWORD_LENGTH};
+use crate::models::{CreateUserRequest, UpdateUserRequest, User};
+use crate::AppState;
+use log::{info, warn, error};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Builds a `User` from a row selecting `id, username, display_name, role, borrower_id, is_active, created_at, updated_at`.
+///
+/// Returns `None` if the stored id is not a valid UUID.
+pub(crate) fn user_from_row(row: &MySqlRow) -> Option<User> {
+    let id: String = row.get("id");
+    let created_at: chrono::NaiveDateTime = row.get("created_at");
+    let updated_at: chrono::NaiveDateTime = row.get("updated_at");
+
+    Some(User {
+        id: Uuid::parse_str(&id).ok()?,
+        username: row.get("username"),
+        display_name: row.get("display_name"),
+        role: auth::role_from_db(row.get("role")),
+        borrower_id: row.get("borrower_id"),
+        is_active: row.get("is_active"),
+        created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
+        updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
+    })
+}
+
+fn invalid_borrower_response() -> HttpResponse {
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "INVALID_BORROWER",
+            "message": "The linked borrower does not exist"
+        }
+    }))
+}
+
+fn password_too_short_response() -> HttpResponse {
+    HttpResponse::BadRequest().json(serde_json::json!({
+        "error": {
+            "code": "PASSWORD_TOO_SHORT",
+            "message": format!("Password must be at least {} characters long", MIN_PASSWORD_LENGTH)
+        }
+    }))
+}
+
+/// Lists all user accounts.
+///
+/// **Endpoint**: `GET /api/v1/users`
+///
+/// # Arguments
+///
+/// * `data` - Application state containing the database connection pool
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `User` objects on success
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_users(data: web::Data<AppState>) -> impl Responder {
+    info!("GET /api/v1/users - Fetching all users");
+
+    let query = r#"
+        SELECT id, username, display_name, role, borrower_id, is_active, created_at, updated_at
+        FROM users
+        ORDER BY username
+    "#;
+
+    match sqlx::query(query).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let users: Vec<User> = rows.iter().filter_map(user_from_row).collect();
+            info!("Successfully fetched {} users", users.len());
+            HttpResponse::Ok().json(users)
+        }
+        Err(e) => {
+            error!("Failed to fetch users: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to fetch users",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Creates a new user account.
+///
+/// **Endpoint**: `POST /api/v1/users`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "username": "jane",
+///   "password": "at-least-8-chars",
+///   "display_name": "Jane Doe",
+///   "role": "librarian",
+///   "borrower_id": null
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with new user ID on success
+/// * `HttpResponse::BadRequest` if the username is empty, the password is too short
+///   or the linked borrower does not exist
+/// * `HttpResponse::Conflict` if the username is already taken
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn create_user(
+    request: web::Json<CreateUserRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    info!("POST /api/v1/users - Creating user: {}", request.username);
+
+    let username = request.username.trim();
+    if username.is_empty() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": "Username is required"
+            }
+        }));
+    }
+    if request.password.chars().count() < MIN_PASSWORD_LENGTH {
+        return password_too_short_response();
+    }
+
+    let password_hash = match auth::hash_password(&request.password) {
+        Ok(hash) => hash,
+        Err(e) => {
+            error!("Failed to hash password: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "INTERNAL_ERROR",
+                    "message": "Failed to hash password"
+                }
+            }));
+        }
+    };
+
+    let id = Uuid::new_v4().to_string();
+
+    match sqlx::query(
+        "INSERT INTO users (id, username, password_hash, display_name, role, borrower_id, is_active)
+         VALUES (?, ?, ?, ?, ?, ?, TRUE)"
+    )
+    .bind(&id)
+    .bind(username)
+    .bind(&password_hash)
+    .bind(&request.display_name)
+    .bind(request.role.unwrap_or_default().to_string())
+    .bind(request.borrower_id.as_deref().filter(|b| !b.is_empty()))
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Successfully created user: {}", username);
+            HttpResponse::Created().json(serde_json::json!({ "id": id }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            warn!("Username already exists: {}", username);
+            HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "DUPLICATE_USERNAME",
+                    "message": format!("Username '{}' is already taken", username)
+                }
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_foreign_key_violation() => {
+            invalid_borrower_response()
+        }
+        Err(e) => {
+            error!("Failed to create user: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to create user",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Updates an existing user account.
+///
+/// **Endpoint**: `PUT /api/v1/users/{id}`
+///
+/// Only provided fields are updated. Changing the password or disabling the
+/// account closes all of the user's open sessions.
+///
+/// # Business Rules
+///
+/// - An admin cannot lower their own role or disable their own account.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` on success
+/// * `HttpResponse::BadRequest` if no fields are provided, the password is too short
+///   or the linked borrower does not exist
+/// * `HttpResponse::Conflict` if an admin tries to demote or disable themselves
+/// * `HttpResponse::NotFound` if the user does not exist
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn update_user(
+    id: web::Path<String>,
+    request: web::Json<UpdateUserRequest>,
+    current_user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    info!("PUT /api/v1/users/{} - Updating user", id);
+
+    let demotes_self = request.role.is_some_and(|role| role < current_user.role);
+    let disables_self = request.is_active == Some(false);
+    if current_user.id == id.as_str() && (demotes_self || disables_self) {
+        return HttpResponse::Conflict().json(serde_json::json!({
+            "error": {
+                "code": "CANNOT_DEMOTE_SELF",
+                "message": "You cannot lower your own role or disable your own account"
+            }
+        }));
+    }
+
+    let mut query_builder = sqlx::QueryBuilder::<sqlx::MySql>::new("UPDATE users SET ");
+    let mut separated = query_builder.separated(", ");
+    let mut has_updates = false;
+
+    if let Some(display_name) = &request.display_name {
+        separated.push("display_name = ").push_bind_unseparated(display_name.clone());
+        has_updates = true;
+    }
+    if let Some(is_active) = request.is_active {
+        separated.push("is_active = ").push_bind_unseparated(is_active);
+        has_updates = true;
+    }
+    if let Some(role) = request.role {
+        separated.push("role = ").push_bind_unseparated(role.to_string());
+        has_updates = true;
+    }
+    if let Some(borrower_id) = &request.borrower_id {
+        let borrower_id = Some(borrower_id.clone()).filter(|b| !b.is_empty());
+        separated.push("borrower_id = ").push_bind_unseparated(borrower_id);
+        has_updates = true;
+    }
+    if let Some(password) = &request.password {
+        if password.chars().count() < MIN_PASSWORD_LENGTH {
+            return password_too_short_response();
+        }
+        match auth::hash_password(password) {
+            Ok(hash) => {
+                separated.push("password_hash = ").push_bind_unseparated(hash);
+                has_updates = true;
+            }
+            Err(e) => {
+                error!("Failed to hash password: {}", e);
+                return HttpResponse::InternalServerError().json(serde_json::json!({
+                    "error": {
+                        "code": "INTERNAL_ERROR",
+                        "message": "Failed to hash password"
+                    }
+                }));
+            }
+        }
+    }
+
+    if !has_updates {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": "No fields to update"
+            }
+        }));
+    }
+
+    query_builder.push(" WHERE id = ");
+    query_builder.push_bind(id.as_str());
+
+    match query_builder.build().execute(&data.db_pool).await {
+        Ok(result) if result.rows_affected() == 0 => HttpResponse::NotFound().json(serde_json::json!({
+            "error": {
+                "code": "NOT_FOUND",
+                "message": "User not found"
+            }
+        })),
+        Ok(_) => {
+            if (request.password.is_some() || request.is_active == Some(false))
+                && let Err(e) = sqlx::query("DELETE FROM user_sessions WHERE user_id = ?")
+                    .bind(id.as_str())
+                    .execute(&data.db_pool)
+                    .await
+            {
+                warn!("Failed to close sessions of user {}: {}", id, e);
+            }
+            info!("Successfully updated user: {}", id);
+            HttpResponse::Ok().json(serde_json::json!({ "id": id.as_str() }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_foreign_key_violation() => {
+            invalid_borrower_response()
+        }
+        Err(e) => {
+            error!("Failed to update user: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to update user",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
+
+/// Deletes a user account.
+///
+/// **Endpoint**: `DELETE /api/v1/users/{id}`
+///
+/// # Business Rules
+///
+/// - A user cannot delete their own account, so at least one account always remains.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` on success
+/// * `HttpResponse::Conflict` if the caller tries to delete their own account
+/// * `HttpResponse::NotFound` if the user does not exist
+/// * `HttpResponse::InternalServerError` if the database operation fails
+pub async fn delete_user(
+    id: web::Path<String>,
+    current_user: AuthenticatedUser,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    info!("DELETE /api/v1/users/{} - Deleting user", id);
+
+    if current_user.id == id.as_str() {
+        return HttpResponse::Conflict().json(serde_json::json!({
+            "error": {
+                "code": "CANNOT_DELETE_SELF",
+                "message": "You cannot delete your own account"
+            }
+        }));
+    }
+
+    match sqlx::query("DELETE FROM users WHERE id = ?")
+        .bind(id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => HttpResponse::NotFound().json(serde_json::json!({
+            "error": {
+                "code": "NOT_FOUND",
+                "message": "User not found"
+            }
+        })),
+        Ok(_) => {
+            info!("Successfully deleted user: {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "User deleted successfully"
+            }))
+        }
+        Err(e) => {
+            error!("Failed to delete user: {}", e);
+            HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to delete user",
+                    "details": { "error": e.to_string() }
+                }
+            }))
+        }
+    }
+}
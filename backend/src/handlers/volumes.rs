@@ -4,12 +4,17 @@
 //! physical volumes (copies) of a title. It tracks volume condition, status, and location.
 
 use actix_web::{web, HttpResponse, Responder};
-use crate::models::{Volume, VolumeCondition, VolumeLoanStatus, CreateVolumeRequest, UpdateVolumeRequest};
+use crate::handlers::custom_fields::{parse_custom_values, save_custom_values, validate_custom_values};
+use crate::models::{Volume, VolumeCondition, VolumeLoanStatus, CreateVolumeRequest, UpdateVolumeRequest, CustomFieldScope};
 use crate::AppState;
 use log::{info, warn, error, debug};
 use sqlx::Row;
 use uuid::Uuid;
 
+/// SQL expression for the custom field values of a volume, as a JSON object keyed by field UUID.
+const CUSTOM_FIELDS_SQL: &str = "(SELECT CAST(JSON_OBJECTAGG(vcv.field_id, vcv.value) AS CHAR) \
+     FROM volume_custom_values vcv WHERE vcv.volume_id = volumes.id)";
+
 /// Lists all volumes for a specific title.
 ///
 /// **Endpoint**: `GET /api/v1/titles/{title_id}/volumes`
@@ -32,7 +37,7 @@ pub async fn list_volumes_by_title(
 ) -> impl Responder {
     info!("GET /api/v1/titles/{}/volumes - Fetching volumes for title", title_id);
 
-    let query = r#"
+    let query = format!(r#"
         SELECT
             id,
             title_id,
@@ -42,14 +47,15 @@ pub async fn list_volumes_by_title(
             location_id,
             loan_status,
             individual_notes,
+            {custom_fields} as custom_fields,
             created_at,
             updated_at
         FROM volumes
         WHERE title_id = ?
         ORDER BY copy_number ASC
-    "#;
+    "#, custom_fields = CUSTOM_FIELDS_SQL);
 
-    match sqlx::query(query)
+    match sqlx::query(&query)
         .bind(title_id.as_str())
         .fetch_all(&data.db_pool)
         .await
@@ -119,6 +125,7 @@ pub async fn list_volumes_by_title(
                         location_id,
                         loan_status,
                         individual_notes: row.get("individual_notes"),
+                        custom_fields: parse_custom_values(row.get("custom_fields")),
                         created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
                         updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
                     })
@@ -163,7 +170,7 @@ pub async fn get_volume(
 ) -> impl Responder {
     info!("GET /api/v1/volumes/{} - Fetching volume", id);
 
-    let query = r#"
+    let query = format!(r#"
         SELECT
             id,
             title_id,
@@ -173,13 +180,14 @@ pub async fn get_volume(
             location_id,
             loan_status,
             individual_notes,
+            {custom_fields} as custom_fields,
             created_at,
             updated_at
         FROM volumes
         WHERE id = ?
-    "#;
+    "#, custom_fields = CUSTOM_FIELDS_SQL);
 
-    match sqlx::query(query)
+    match sqlx::query(&query)
         .bind(id.as_str())
         .fetch_one(&data.db_pool)
         .await
@@ -249,6 +257,7 @@ pub async fn get_volume(
                 location_id,
                 loan_status,
                 individual_notes: row.get("individual_notes"),
+                custom_fields: parse_custom_values(row.get("custom_fields")),
                 created_at: chrono::DateTime::from_naive_utc_and_offset(created_at, chrono::Utc),
                 updated_at: chrono::DateTime::from_naive_utc_and_offset(updated_at, chrono::Utc),
             };
@@ -300,14 +309,16 @@ pub async fn get_volume(
 ///   "barcode": "123456789",
 ///   "condition": "good",
 ///   "location_id": "uuid-string",
-///   "individual_notes": "Optional notes"
+///   "individual_notes": "Optional notes",
+///   "custom_fields": { "box-field-uuid": "12" }
 /// }
 /// ```
 ///
 /// # Returns
 ///
 /// * `HttpResponse::Created` (201) with new volume ID and copy number on success
-/// * `HttpResponse::BadRequest` if barcode format is invalid
+/// * `HttpResponse::BadRequest` if barcode format is invalid or a custom field value does not fit its field
+/// * `HttpResponse::NotFound` if the title does not exist or is in the trash
 /// * `HttpResponse::Conflict` if barcode already exists
 /// * `HttpResponse::InternalServerError` if database operation fails
 pub async fn create_volume(
@@ -362,6 +373,33 @@ pub async fn create_volume(
         }
     }
 
+    // Volumes cannot be added to a title in the trash
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(&req.title_id)
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(0) => {
+            warn!("Title {} not found", req.title_id);
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": {
+                    "code": "NOT_FOUND",
+                    "message": "Title not found"
+                }
+            }));
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("Database error while checking title: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": {
+                    "code": "DATABASE_ERROR",
+                    "message": "Failed to check title"
+                }
+            }));
+        }
+    }
+
     // Auto-calculate copy_number (get max copy_number for this title + 1)
     let copy_number_query = "SELECT COALESCE(MAX(copy_number), 0) + 1 as next_copy_number FROM volumes WHERE title_id = ?";
     let copy_number: i32 = match sqlx::query(copy_number_query)
@@ -383,6 +421,11 @@ pub async fn create_volume(
 
     debug!("Calculated copy_number: {} for title {}", copy_number, req.title_id);
 
+    let custom_values = match validate_custom_values(&data.db_pool, CustomFieldScope::Volume, &req.custom_fields).await {
+        Ok(values) => values,
+        Err(response) => return response,
+    };
+
     // Convert condition enum to string
     let condition_str = match req.condition {
         VolumeCondition::Excellent => "excellent",
@@ -397,7 +440,17 @@ pub async fn create_volume(
         VALUES (?, ?, ?, ?, ?, ?, 'available', ?, NOW(), NOW())
     "#;
 
-    match sqlx::query(insert_query)
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => {
+            error!("Failed to start transaction: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to create volume" }
+            }));
+        }
+    };
+
+    let result = sqlx::query(insert_query)
         .bind(new_id.to_string())
         .bind(&req.title_id)
         .bind(copy_number)
@@ -405,10 +458,19 @@ pub async fn create_volume(
         .bind(condition_str)
         .bind(&req.location_id)
         .bind(&req.individual_notes)
-        .execute(&data.db_pool)
-        .await
-    {
-        Ok(_) => {
+        .execute(&mut *tx)
+        .await;
+    let result = match result {
+        Ok(_) => save_custom_values(&mut tx, CustomFieldScope::Volume, &new_id.to_string(), &custom_values).await,
+        Err(e) => Err(e),
+    };
+    let result = match result {
+        Ok(()) => tx.commit().await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
             info!("Successfully created volume with ID: {}", new_id);
             HttpResponse::Created().json(serde_json::json!({
                 "id": new_id.to_string(),
@@ -452,10 +514,13 @@ pub async fn create_volume(
 ///   "condition": "excellent",
 ///   "location_id": "new-location-uuid",
 ///   "loan_status": "available",
-///   "individual_notes": "Updated notes"
+///   "individual_notes": "Updated notes",
+///   "custom_fields": { "box-field-uuid": "14", "shop-field-uuid": "" }
 /// }
 /// ```
 ///
+/// `custom_fields` only changes the listed fields; a blank value clears the field.
+///
 /// # Returns
 ///
 /// * `HttpResponse::Ok` on success
@@ -492,6 +557,9 @@ pub async fn update_volume(
         update_parts.push("individual_notes = ?");
         has_updates = true;
     }
+    if !req.custom_fields.is_empty() {
+        has_updates = true;
+    }
 
     if !has_updates {
         warn!("No fields to update for volume {}", id);
@@ -503,6 +571,11 @@ pub async fn update_volume(
         }));
     }
 
+    let custom_values = match validate_custom_values(&data.db_pool, CustomFieldScope::Volume, &req.custom_fields).await {
+        Ok(values) => values,
+        Err(response) => return response,
+    };
+
     update_parts.push("updated_at = NOW()");
     let update_clause = update_parts.join(", ");
     let query = format!("UPDATE volumes SET {} WHERE id = ?", update_clause);
@@ -553,7 +626,29 @@ pub async fn update_volume(
 
     query_builder = query_builder.bind(id.as_str());
 
-    match query_builder.execute(&data.db_pool).await {
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => {
+            error!("Failed to start transaction: {}", e);
+            return HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": { "code": "DATABASE_ERROR", "message": "Failed to update volume" }
+            }));
+        }
+    };
+
+    let result = query_builder.execute(&mut *tx).await;
+    let result = match result {
+        Ok(result) if result.rows_affected() > 0 => {
+            save_custom_values(&mut tx, CustomFieldScope::Volume, &id, &custom_values).await.map(|_| result)
+        }
+        result => result,
+    };
+    let result = match result {
+        Ok(result) => tx.commit().await.map(|_| result),
+        Err(e) => Err(e),
+    };
+
+    match result {
         Ok(result) => {
             if result.rows_affected() == 0 {
                 warn!("Volume {} not found", id);
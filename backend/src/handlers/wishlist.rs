@@ -0,0 +1,593 @@
+//! API handlers for the wishlist of titles to acquire.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/wishlist` - List wished titles, highest priority first
+//! - `PUT /api/v1/titles/{title_id}/wishlist` - Put a title on the wishlist or change its entry
+//! - `DELETE /api/v1/titles/{title_id}/wishlist` - Take a title off the wishlist
+//! - `POST /api/v1/titles/{title_id}/wishlist/acquire` - Create the first volume and drop the entry
+//! - `GET /api/v1/wishlist/shares` - List share links
+//! - `POST /api/v1/wishlist/shares` - Create a share link
+//! - `DELETE /api/v1/wishlist/shares/{id}` - Revoke a share link
+//! - `GET /api/v1/public/wishlist/{token}` - Read-only wishlist export, no account needed
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::auth::{self, AuthenticatedUser};
+use crate::models::{
+    AcquireWishlistItemRequest, CreateWishlistShareLinkRequest, CreateWishlistShareLinkResponse,
+    SetWishlistItemRequest, SharedWishlist, SharedWishlistItem, VolumeCondition, WishlistItem,
+    WishlistPriority, WishlistShareLink,
+};
+use crate::AppState;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use log::{info, warn, error};
+use sqlx::mysql::MySqlRow;
+use sqlx::Row;
+use uuid::Uuid;
+
+/// Wished titles with their authors and volume count, highest priority first.
+const WISHLIST_QUERY: &str = r#"
+    SELECT
+        t.id AS title_id,
+        t.title,
+        t.subtitle,
+        t.isbn,
+        (SELECT GROUP_CONCAT(CONCAT(a.first_name, ' ', a.last_name) ORDER BY ta.display_order SEPARATOR ', ')
+         FROM title_authors ta INNER JOIN authors a ON ta.author_id = a.id
+         WHERE ta.title_id = t.id AND a.deleted_at IS NULL) AS authors,
+        wi.priority,
+        wi.desired_edition,
+        CAST(wi.max_price AS DOUBLE) AS max_price,
+        wi.notes,
+        (SELECT COUNT(*) FROM volumes v WHERE v.title_id = t.id) AS volume_count,
+        wi.created_at,
+        wi.updated_at
+    FROM wishlist_items wi
+    INNER JOIN titles t ON wi.title_id = t.id
+    WHERE t.deleted_at IS NULL
+    ORDER BY FIELD(wi.priority, 'high', 'medium', 'low'), wi.created_at
+"#;
+
+fn wishlist_item_from_row(row: &MySqlRow) -> Option<WishlistItem> {
+    let priority: String = row.get("priority");
+    let created_at: NaiveDateTime = row.get("created_at");
+    let updated_at: NaiveDateTime = row.get("updated_at");
+
+    Some(WishlistItem {
+        title_id: row.get("title_id"),
+        title: row.get("title"),
+        subtitle: row.get("subtitle"),
+        isbn: row.get("isbn"),
+        authors: row.get("authors"),
+        priority: WishlistPriority::from_db(&priority)?,
+        desired_edition: row.get("desired_edition"),
+        max_price: row.get("max_price"),
+        notes: row.get("notes"),
+        volume_count: row.get("volume_count"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+        updated_at: DateTime::from_naive_utc_and_offset(updated_at, Utc),
+    })
+}
+
+/// Keeps the fields of an entry that share links expose, leaving out notes and volume counts.
+fn shared_item(item: WishlistItem) -> SharedWishlistItem {
+    SharedWishlistItem {
+        title: item.title,
+        subtitle: item.subtitle,
+        isbn: item.isbn,
+        authors: item.authors,
+        priority: item.priority,
+        desired_edition: item.desired_edition,
+        max_price: item.max_price,
+    }
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+fn not_on_wishlist(title_id: &str) -> HttpResponse {
+    warn!("Title {} is not on the wishlist", title_id);
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": { "code": "NOT_FOUND", "message": "This title is not on the wishlist" }
+    }))
+}
+
+/// Lists the wished titles, highest priority first and oldest first within a priority.
+///
+/// **Endpoint**: `GET /api/v1/wishlist`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `WishlistItem` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_wishlist(data: web::Data<AppState>) -> impl Responder {
+    info!("GET /api/v1/wishlist - Fetching wishlist");
+
+    match sqlx::query(WISHLIST_QUERY).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let items: Vec<WishlistItem> = rows.iter().filter_map(wishlist_item_from_row).collect();
+            info!("Found {} titles on the wishlist", items.len());
+            HttpResponse::Ok().json(items)
+        }
+        Err(e) => database_error("Failed to fetch wishlist", e),
+    }
+}
+
+/// Puts a title on the wishlist, or replaces its wishlist entry.
+///
+/// **Endpoint**: `PUT /api/v1/titles/{title_id}/wishlist`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "priority": "high",
+///   "desired_edition": "hardback",
+///   "max_price": 25.0,
+///   "notes": "Signed copy if possible"
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the entry was saved
+/// * `HttpResponse::BadRequest` (400) if `max_price` is negative
+/// * `HttpResponse::NotFound` (404) if the title does not exist or is in the trash
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn set_wishlist_item(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<SetWishlistItemRequest>,
+) -> impl Responder {
+    info!("PUT /api/v1/titles/{}/wishlist - Setting wishlist entry ({})", title_id, req.priority);
+
+    if req.max_price.is_some_and(|price| !price.is_finite() || price < 0.0) {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": "max_price must be a positive amount"
+            }
+        }));
+    }
+
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(title_id.as_str())
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(0) => {
+            warn!("Title {} not found", title_id);
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Title not found" }
+            }));
+        }
+        Ok(_) => {}
+        Err(e) => return database_error("Failed to check title", e),
+    }
+
+    let query = r#"
+        INSERT INTO wishlist_items (title_id, priority, desired_edition, max_price, notes, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, NOW(), NOW())
+        ON DUPLICATE KEY UPDATE
+            priority = VALUES(priority),
+            desired_edition = VALUES(desired_edition),
+            max_price = VALUES(max_price),
+            notes = VALUES(notes),
+            updated_at = NOW()
+    "#;
+
+    match sqlx::query(query)
+        .bind(title_id.as_str())
+        .bind(req.priority.to_string())
+        .bind(req.desired_edition.as_deref().map(str::trim).filter(|edition| !edition.is_empty()))
+        .bind(req.max_price)
+        .bind(req.notes.as_deref().map(str::trim).filter(|notes| !notes.is_empty()))
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Title {} is on the wishlist with {} priority", title_id, req.priority);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Wishlist entry saved"
+            }))
+        }
+        Err(e) => database_error("Failed to save wishlist entry", e),
+    }
+}
+
+/// Takes a title off the wishlist.
+///
+/// **Endpoint**: `DELETE /api/v1/titles/{title_id}/wishlist`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the entry was removed
+/// * `HttpResponse::NotFound` (404) if the title is not on the wishlist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn remove_wishlist_item(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+) -> impl Responder {
+    info!("DELETE /api/v1/titles/{}/wishlist - Removing wishlist entry", title_id);
+
+    match sqlx::query("DELETE FROM wishlist_items WHERE title_id = ?")
+        .bind(title_id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => not_on_wishlist(&title_id),
+        Ok(_) => {
+            info!("Removed title {} from the wishlist", title_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Title removed from the wishlist"
+            }))
+        }
+        Err(e) => database_error("Failed to remove wishlist entry", e),
+    }
+}
+
+/// Marks a wished title as acquired: creates its volume and removes the wishlist entry.
+///
+/// **Endpoint**: `POST /api/v1/titles/{title_id}/wishlist/acquire`
+///
+/// Both happen in one transaction, so a rejected volume leaves the entry in place.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "barcode": "123456789",
+///   "condition": "Good",
+///   "location_id": "uuid-string",
+///   "individual_notes": "Birthday present"
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new volume's UUID and copy number
+/// * `HttpResponse::BadRequest` (400) if the barcode is not numeric
+/// * `HttpResponse::NotFound` (404) if the title is not on the wishlist
+/// * `HttpResponse::Conflict` (409) if the barcode already exists
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn acquire_wishlist_item(
+    data: web::Data<AppState>,
+    title_id: web::Path<String>,
+    req: web::Json<AcquireWishlistItemRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/titles/{}/wishlist/acquire - Acquiring wished title", title_id);
+
+    if req.barcode.is_empty() || !req.barcode.chars().all(|c| c.is_ascii_digit()) {
+        warn!("Invalid barcode format: {}", req.barcode);
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "INVALID_BARCODE",
+                "message": "Barcode must be a numeric value (e.g., 123456)"
+            }
+        }));
+    }
+
+    let mut tx = match data.db_pool.begin().await {
+        Ok(tx) => tx,
+        Err(e) => return database_error("Failed to acquire title", e),
+    };
+
+    let wished = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM wishlist_items wi INNER JOIN titles t ON wi.title_id = t.id \
+         WHERE wi.title_id = ? AND t.deleted_at IS NULL FOR UPDATE"
+    )
+    .bind(title_id.as_str())
+    .fetch_one(&mut *tx)
+    .await;
+    match wished {
+        Ok(0) => return not_on_wishlist(&title_id),
+        Ok(_) => {}
+        Err(e) => return database_error("Failed to fetch wishlist entry", e),
+    }
+
+    let copy_number: i32 = match sqlx::query_scalar(
+        "SELECT COALESCE(MAX(copy_number), 0) + 1 FROM volumes WHERE title_id = ?"
+    )
+    .bind(title_id.as_str())
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(copy_number) => copy_number,
+        Err(e) => return database_error("Failed to calculate copy number", e),
+    };
+
+    let condition = match req.condition {
+        VolumeCondition::Excellent => "excellent",
+        VolumeCondition::Good => "good",
+        VolumeCondition::Fair => "fair",
+        VolumeCondition::Poor => "poor",
+        VolumeCondition::Damaged => "damaged",
+    };
+    let volume_id = Uuid::new_v4().to_string();
+
+    let result = sqlx::query(
+        "INSERT INTO volumes (id, title_id, copy_number, barcode, `condition`, location_id, loan_status, individual_notes, created_at, updated_at) \
+         VALUES (?, ?, ?, ?, ?, ?, 'available', ?, NOW(), NOW())"
+    )
+    .bind(&volume_id)
+    .bind(title_id.as_str())
+    .bind(copy_number)
+    .bind(&req.barcode)
+    .bind(condition)
+    .bind(&req.location_id)
+    .bind(&req.individual_notes)
+    .execute(&mut *tx)
+    .await;
+    let result = match result {
+        Ok(_) => sqlx::query("DELETE FROM wishlist_items WHERE title_id = ?")
+            .bind(title_id.as_str())
+            .execute(&mut *tx)
+            .await
+            .map(|_| ()),
+        Err(e) => Err(e),
+    };
+    let result = match result {
+        Ok(()) => tx.commit().await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
+            info!("Acquired title {} as volume {} (copy {})", title_id, volume_id, copy_number);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": volume_id,
+                "copy_number": copy_number,
+                "message": "Volume created and title removed from the wishlist"
+            }))
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => {
+            warn!("Barcode {} already exists", req.barcode);
+            HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "DUPLICATE_BARCODE",
+                    "message": "Barcode already exists",
+                    "details": { "barcode": req.barcode }
+                }
+            }))
+        }
+        Err(e) => database_error("Failed to acquire title", e),
+    }
+}
+
+fn share_link_from_row(row: &MySqlRow) -> WishlistShareLink {
+    let created_at: NaiveDateTime = row.get("created_at");
+    WishlistShareLink {
+        id: row.get("id"),
+        name: row.get("name"),
+        created_by: row.get("created_by"),
+        created_at: DateTime::from_naive_utc_and_offset(created_at, Utc),
+    }
+}
+
+/// Lists the wishlist share links, newest first.
+///
+/// **Endpoint**: `GET /api/v1/wishlist/shares`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `WishlistShareLink` objects
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_share_links(data: web::Data<AppState>) -> impl Responder {
+    info!("GET /api/v1/wishlist/shares - Fetching share links");
+
+    let query = r#"
+        SELECT l.id, l.name, u.username AS created_by, l.created_at
+        FROM wishlist_share_links l
+        LEFT JOIN users u ON l.created_by = u.id
+        ORDER BY l.created_at DESC
+    "#;
+
+    match sqlx::query(query).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let links: Vec<WishlistShareLink> = rows.iter().map(share_link_from_row).collect();
+            HttpResponse::Ok().json(links)
+        }
+        Err(e) => database_error("Failed to fetch share links", e),
+    }
+}
+
+/// Creates a read-only share link of the wishlist.
+///
+/// **Endpoint**: `POST /api/v1/wishlist/shares`
+///
+/// The token is only returned in this response; only its hash is stored.
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with a `CreateWishlistShareLinkResponse`
+/// * `HttpResponse::BadRequest` (400) if the name is empty
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn create_share_link(
+    data: web::Data<AppState>,
+    req: web::Json<CreateWishlistShareLinkRequest>,
+    user: AuthenticatedUser,
+) -> impl Responder {
+    info!("POST /api/v1/wishlist/shares - Creating share link '{}'", req.name);
+
+    let name = req.name.trim();
+    if name.is_empty() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": {
+                "code": "VALIDATION_ERROR",
+                "message": "A share link needs a name"
+            }
+        }));
+    }
+
+    let id = Uuid::new_v4().to_string();
+    let token = auth::generate_token();
+
+    match sqlx::query(
+        "INSERT INTO wishlist_share_links (id, name, token_hash, created_by, created_at) VALUES (?, ?, ?, ?, NOW())"
+    )
+    .bind(&id)
+    .bind(name)
+    .bind(auth::hash_token(&token))
+    .bind(&user.id)
+    .execute(&data.db_pool)
+    .await
+    {
+        Ok(_) => {
+            info!("Created wishlist share link {} for {}", id, user.username);
+            HttpResponse::Created().json(CreateWishlistShareLinkResponse {
+                link: WishlistShareLink {
+                    id,
+                    name: name.to_string(),
+                    created_by: Some(user.username),
+                    created_at: Utc::now(),
+                },
+                token,
+            })
+        }
+        Err(e) => database_error("Failed to create share link", e),
+    }
+}
+
+/// Revokes a wishlist share link; its URL stops working immediately.
+///
+/// **Endpoint**: `DELETE /api/v1/wishlist/shares/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the link was revoked
+/// * `HttpResponse::NotFound` (404) if the link does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_share_link(
+    data: web::Data<AppState>,
+    id: web::Path<String>,
+) -> impl Responder {
+    info!("DELETE /api/v1/wishlist/shares/{} - Revoking share link", id);
+
+    match sqlx::query("DELETE FROM wishlist_share_links WHERE id = ?")
+        .bind(id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Share link {} not found", id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Share link not found" }
+            }))
+        }
+        Ok(_) => {
+            info!("Revoked wishlist share link {}", id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Share link revoked"
+            }))
+        }
+        Err(e) => database_error("Failed to revoke share link", e),
+    }
+}
+
+/// Exports the wishlist for the holder of a share link.
+///
+/// **Endpoint**: `GET /api/v1/public/wishlist/{token}`
+///
+/// Needs no session: the token in the URL is the credential. Notes and volume
+/// counts are left out.
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with a `SharedWishlist` object
+/// * `HttpResponse::NotFound` if the token does not match a share link
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn shared_wishlist(
+    data: web::Data<AppState>,
+    token: web::Path<String>,
+) -> impl Responder {
+    info!("GET /api/v1/public/wishlist - Exporting shared wishlist");
+
+    let name = match sqlx::query_scalar::<_, String>("SELECT name FROM wishlist_share_links WHERE token_hash = ?")
+        .bind(auth::hash_token(&token))
+        .fetch_optional(&data.db_pool)
+        .await
+    {
+        Ok(Some(name)) => name,
+        Ok(None) => {
+            warn!("Rejected unknown wishlist share token");
+            return HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Share link not found" }
+            }));
+        }
+        Err(e) => return database_error("Failed to fetch share link", e),
+    };
+
+    match sqlx::query(WISHLIST_QUERY).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let items = rows
+                .iter()
+                .filter_map(wishlist_item_from_row)
+                .map(shared_item)
+                .collect();
+            HttpResponse::Ok().json(SharedWishlist { name, items })
+        }
+        Err(e) => database_error("Failed to fetch wishlist", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::http::StatusCode;
+    use actix_web::App;
+
+    #[actix_web::test]
+    async fn test_shared_wishlist_needs_no_session() {
+        let app = actix_web::test::init_service(
+            App::new()
+                .wrap(actix_web::middleware::from_fn(auth::require_auth))
+                .route("/api/v1/public/wishlist/{token}", web::get().to(HttpResponse::Ok))
+                .route("/api/v1/wishlist/shares", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let request = actix_web::test::TestRequest::get().uri("/api/v1/public/wishlist/some-token").to_request();
+        let response = actix_web::test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Managing share links still needs a session
+        let request = actix_web::test::TestRequest::get().uri("/api/v1/wishlist/shares").to_request();
+        let response = actix_web::test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_shared_item_leaves_out_private_fields() {
+        let now = Utc::now();
+        let item = WishlistItem {
+            title_id: "title".to_string(),
+            title: "Dune".to_string(),
+            subtitle: None,
+            isbn: Some("9780441172719".to_string()),
+            authors: Some("Frank Herbert".to_string()),
+            priority: WishlistPriority::High,
+            desired_edition: Some("Hardcover".to_string()),
+            max_price: Some(25.0),
+            notes: Some("Ask for a discount".to_string()),
+            volume_count: 1,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let json = serde_json::to_value(shared_item(item)).unwrap();
+        assert_eq!(json["title"], "Dune");
+        assert_eq!(json["desired_edition"], "Hardcover");
+        assert_eq!(json["max_price"], 25.0);
+        assert!(json.get("notes").is_none());
+        assert!(json.get("volume_count").is_none());
+        assert!(json.get("title_id").is_none());
+    }
+}
@@ -0,0 +1,429 @@
+//! Work management API handlers.
+//!
+//! A work groups the titles that are editions or translations of the same
+//! text, e.g. "Le Petit Prince" and "The Little Prince".
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/works` - List all works with title counts
+//! - `GET /api/v1/works/{id}` - Get a single work by ID
+//! - `POST /api/v1/works` - Create a new work
+//! - `PUT /api/v1/works/{id}` - Update an existing work
+//! - `DELETE /api/v1/works/{id}` - Delete a work (only if no titles are attached)
+//! - `GET /api/v1/works/{id}/titles` - List the titles of a work
+//! - `POST /api/v1/works/{id}/titles/{title_id}` - Attach a title to a work
+//! - `DELETE /api/v1/works/{id}/titles/{title_id}` - Detach a title from a work
+//!
+//! # Business Rules
+//!
+//! - A title belongs to at most one work; attaching it to another work moves it
+//! - Works with attached titles cannot be deleted (delete protection)
+
+use actix_web::{web, HttpResponse, Responder};
+use crate::models::{CreateWorkRequest, UpdateWorkRequest, Work, WorkWithTitleCount};
+use crate::AppState;
+use log::{info, warn, error};
+use sqlx::Row;
+use uuid::Uuid;
+
+fn work_not_found(work_id: &str) -> HttpResponse {
+    warn!("Work {} not found", work_id);
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": { "code": "NOT_FOUND", "message": "Work not found" }
+    }))
+}
+
+fn database_error(message: &str, e: sqlx::Error) -> HttpResponse {
+    error!("{}: {}", message, e);
+    HttpResponse::InternalServerError().json(serde_json::json!({
+        "error": {
+            "code": "DATABASE_ERROR",
+            "message": message,
+            "details": { "error": e.to_string() }
+        }
+    }))
+}
+
+/// Whether a work exists.
+async fn work_exists(data: &AppState, work_id: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM works WHERE id = ?")
+        .bind(work_id)
+        .fetch_one(&data.db_pool)
+        .await
+        .map(|count| count > 0)
+}
+
+/// Lists all works with the number of titles attached to each.
+///
+/// **Endpoint**: `GET /api/v1/works`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with JSON array of `WorkWithTitleCount` objects, ordered by title
+/// * `HttpResponse::InternalServerError` if the database query fails
+pub async fn list_works(data: web::Data<AppState>) -> impl Responder {
+    info!("GET /api/v1/works - Fetching all works");
+
+    let query = r#"
+        SELECT
+            w.id,
+            w.title,
+            w.description,
+            w.created_at,
+            w.updated_at,
+            COUNT(t.id) as title_count
+        FROM works w
+        LEFT JOIN titles t ON w.id = t.work_id AND t.deleted_at IS NULL
+        GROUP BY w.id, w.title, w.description, w.created_at, w.updated_at
+        ORDER BY w.title ASC
+    "#;
+
+    match sqlx::query(query).fetch_all(&data.db_pool).await {
+        Ok(rows) => {
+            let works: Vec<WorkWithTitleCount> = rows
+                .iter()
+                .filter_map(|row| {
+                    let id: String = row.get("id");
+                    Some(WorkWithTitleCount {
+                        work: Work {
+                            id: Uuid::parse_str(&id).ok()?,
+                            title: row.get("title"),
+                            description: row.get("description"),
+                            created_at: row.get("created_at"),
+                            updated_at: row.get("updated_at"),
+                        },
+                        title_count: row.get("title_count"),
+                    })
+                })
+                .collect();
+            info!("Found {} works", works.len());
+            HttpResponse::Ok().json(works)
+        }
+        Err(e) => database_error("Failed to fetch works", e),
+    }
+}
+
+/// Retrieves a single work.
+///
+/// **Endpoint**: `GET /api/v1/works/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with the `Work`
+/// * `HttpResponse::NotFound` (404) if the work does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database query fails
+pub async fn get_work(data: web::Data<AppState>, work_id: web::Path<String>) -> impl Responder {
+    info!("GET /api/v1/works/{} - Fetching work", work_id);
+
+    match sqlx::query_as::<_, Work>("SELECT id, title, description, created_at, updated_at FROM works WHERE id = ?")
+        .bind(work_id.as_str())
+        .fetch_optional(&data.db_pool)
+        .await
+    {
+        Ok(Some(work)) => HttpResponse::Ok().json(work),
+        Ok(None) => work_not_found(&work_id),
+        Err(e) => database_error("Failed to fetch work", e),
+    }
+}
+
+/// Creates a new work.
+///
+/// **Endpoint**: `POST /api/v1/works`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "title": "Le Petit Prince",
+///   "description": "Novella by Antoine de Saint-Exupéry, 1943"
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `HttpResponse::Created` (201) with the new work's UUID
+/// * `HttpResponse::BadRequest` (400) if the title is empty
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn create_work(
+    data: web::Data<AppState>,
+    req: web::Json<CreateWorkRequest>,
+) -> impl Responder {
+    info!("POST /api/v1/works - Creating work: {}", req.title);
+
+    if req.title.trim().is_empty() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": { "code": "VALIDATION_ERROR", "message": "Work title cannot be empty" }
+        }));
+    }
+
+    let new_id = Uuid::new_v4();
+    match sqlx::query("INSERT INTO works (id, title, description) VALUES (?, ?, ?)")
+        .bind(new_id.to_string())
+        .bind(req.title.trim())
+        .bind(&req.description)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully created work {}", new_id);
+            HttpResponse::Created().json(serde_json::json!({
+                "id": new_id.to_string(),
+                "message": "Work created successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to create work", e),
+    }
+}
+
+/// Updates the title and/or description of a work.
+///
+/// **Endpoint**: `PUT /api/v1/works/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the work was updated
+/// * `HttpResponse::BadRequest` (400) if no field was provided or the title is empty
+/// * `HttpResponse::NotFound` (404) if the work does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn update_work(
+    data: web::Data<AppState>,
+    work_id: web::Path<String>,
+    req: web::Json<UpdateWorkRequest>,
+) -> impl Responder {
+    info!("PUT /api/v1/works/{} - Updating work", work_id);
+
+    if req.title.is_none() && req.description.is_none() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": { "code": "NO_UPDATES", "message": "No fields provided for update" }
+        }));
+    }
+    if req.title.as_deref().is_some_and(|title| title.trim().is_empty()) {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": { "code": "VALIDATION_ERROR", "message": "Work title cannot be empty" }
+        }));
+    }
+
+    match work_exists(&data, &work_id).await {
+        Ok(true) => {}
+        Ok(false) => return work_not_found(&work_id),
+        Err(e) => return database_error("Failed to check work", e),
+    }
+
+    let mut update_parts = Vec::new();
+    if req.title.is_some() {
+        update_parts.push("title = ?");
+    }
+    if req.description.is_some() {
+        update_parts.push("description = ?");
+    }
+    let query = format!("UPDATE works SET {} WHERE id = ?", update_parts.join(", "));
+
+    let mut query_builder = sqlx::query(&query);
+    if let Some(ref title) = req.title {
+        query_builder = query_builder.bind(title.trim());
+    }
+    if let Some(ref description) = req.description {
+        query_builder = query_builder.bind(description);
+    }
+
+    match query_builder.bind(work_id.as_str()).execute(&data.db_pool).await {
+        Ok(_) => {
+            info!("Successfully updated work {}", work_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Work updated successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to update work", e),
+    }
+}
+
+/// Deletes a work that has no titles attached.
+///
+/// **Endpoint**: `DELETE /api/v1/works/{id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the work was deleted
+/// * `HttpResponse::NotFound` (404) if the work does not exist
+/// * `HttpResponse::Conflict` (409) if titles are still attached to the work
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn delete_work(data: web::Data<AppState>, work_id: web::Path<String>) -> impl Responder {
+    info!("DELETE /api/v1/works/{} - Deleting work", work_id);
+
+    match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE work_id = ?")
+        .bind(work_id.as_str())
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(count) if count > 0 => {
+            warn!("Cannot delete work {}: {} title(s) attached", work_id, count);
+            return HttpResponse::Conflict().json(serde_json::json!({
+                "error": {
+                    "code": "HAS_TITLES",
+                    "message": format!("Cannot delete work: {} title(s) are attached to it", count),
+                    "details": { "title_count": count }
+                }
+            }));
+        }
+        Ok(_) => {}
+        Err(e) => return database_error("Failed to check work usage", e),
+    }
+
+    match sqlx::query("DELETE FROM works WHERE id = ?")
+        .bind(work_id.as_str())
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => work_not_found(&work_id),
+        Ok(_) => {
+            info!("Successfully deleted work {}", work_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Work deleted successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to delete work", e),
+    }
+}
+
+/// Lists the titles (editions and translations) of a work.
+///
+/// **Endpoint**: `GET /api/v1/works/{id}/titles`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` with a JSON array of `{id, title, subtitle, isbn, language, publication_year}`,
+///   ordered by publication year
+/// * `HttpResponse::NotFound` (404) if the work does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database query fails
+pub async fn list_work_titles(data: web::Data<AppState>, work_id: web::Path<String>) -> impl Responder {
+    info!("GET /api/v1/works/{}/titles - Fetching titles of work", work_id);
+
+    match work_exists(&data, &work_id).await {
+        Ok(true) => {}
+        Ok(false) => return work_not_found(&work_id),
+        Err(e) => return database_error("Failed to check work", e),
+    }
+
+    let query = r#"
+        SELECT id, title, subtitle, isbn, language, publication_year
+        FROM titles
+        WHERE work_id = ? AND deleted_at IS NULL
+        ORDER BY publication_year IS NULL, publication_year, title
+    "#;
+
+    match sqlx::query(query)
+        .bind(work_id.as_str())
+        .fetch_all(&data.db_pool)
+        .await
+    {
+        Ok(rows) => {
+            let titles: Vec<serde_json::Value> = rows
+                .iter()
+                .map(|row| {
+                    serde_json::json!({
+                        "id": row.get::<String, _>("id"),
+                        "title": row.get::<String, _>("title"),
+                        "subtitle": row.get::<Option<String>, _>("subtitle"),
+                        "isbn": row.get::<Option<String>, _>("isbn"),
+                        "language": row.get::<String, _>("language"),
+                        "publication_year": row.get::<Option<i32>, _>("publication_year"),
+                    })
+                })
+                .collect();
+            info!("Found {} titles for work {}", titles.len(), work_id);
+            HttpResponse::Ok().json(titles)
+        }
+        Err(e) => database_error("Failed to fetch titles of work", e),
+    }
+}
+
+/// Attaches a title to a work, moving it out of any work it belonged to.
+///
+/// **Endpoint**: `POST /api/v1/works/{id}/titles/{title_id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the title is now attached to the work
+/// * `HttpResponse::NotFound` (404) if the work or the title does not exist
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn attach_title_to_work(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (work_id, title_id) = path.into_inner();
+    info!("POST /api/v1/works/{}/titles/{} - Attaching title", work_id, title_id);
+
+    match work_exists(&data, &work_id).await {
+        Ok(true) => {}
+        Ok(false) => return work_not_found(&work_id),
+        Err(e) => return database_error("Failed to check work", e),
+    }
+
+    let title_exists = match sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM titles WHERE id = ? AND deleted_at IS NULL")
+        .bind(&title_id)
+        .fetch_one(&data.db_pool)
+        .await
+    {
+        Ok(count) => count > 0,
+        Err(e) => return database_error("Failed to check title", e),
+    };
+    if !title_exists {
+        warn!("Title {} not found", title_id);
+        return HttpResponse::NotFound().json(serde_json::json!({
+            "error": { "code": "NOT_FOUND", "message": "Title not found" }
+        }));
+    }
+
+    match sqlx::query("UPDATE titles SET work_id = ? WHERE id = ?")
+        .bind(&work_id)
+        .bind(&title_id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(_) => {
+            info!("Successfully attached title {} to work {}", title_id, work_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Title attached to work successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to attach title to work", e),
+    }
+}
+
+/// Detaches a title from a work.
+///
+/// **Endpoint**: `DELETE /api/v1/works/{id}/titles/{title_id}`
+///
+/// # Returns
+///
+/// * `HttpResponse::Ok` (200) if the title was detached
+/// * `HttpResponse::NotFound` (404) if the title is not attached to this work
+/// * `HttpResponse::InternalServerError` (500) if the database operation fails
+pub async fn detach_title_from_work(
+    data: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (work_id, title_id) = path.into_inner();
+    info!("DELETE /api/v1/works/{}/titles/{} - Detaching title", work_id, title_id);
+
+    match sqlx::query("UPDATE titles SET work_id = NULL WHERE id = ? AND work_id = ?")
+        .bind(&title_id)
+        .bind(&work_id)
+        .execute(&data.db_pool)
+        .await
+    {
+        Ok(result) if result.rows_affected() == 0 => {
+            warn!("Title {} is not attached to work {}", title_id, work_id);
+            HttpResponse::NotFound().json(serde_json::json!({
+                "error": { "code": "NOT_FOUND", "message": "Title is not attached to this work" }
+            }))
+        }
+        Ok(_) => {
+            info!("Successfully detached title {} from work {}", title_id, work_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "message": "Title detached from work successfully"
+            }))
+        }
+        Err(e) => database_error("Failed to detach title from work", e),
+    }
+}
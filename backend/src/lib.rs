@@ -15,11 +15,19 @@ pub mod models;
 pub mod handlers;
 pub mod google_books;
 pub mod configuration;
+pub mod auth;
+pub mod security;
+
+use auth::RequireRole;
+use configuration::{ApplicationSettings, AuthSettings, TrashSettings};
+use models::UserRole;
 
 
 // Application state that holds the database pool
 pub struct AppState {
     pub db_pool: Pool,
+    pub auth: AuthSettings,
+    pub trash: TrashSettings,
 }
 
 
@@ -112,108 +120,210 @@ async fn db_health_check(data: web::Data<AppState>) -> impl Responder {
 /// * `listener` - A `std::net::TcpListener` that the server will listen on. This allows
 ///   the caller to control the address and port.
 /// * `db_pool` - A `sqlx::MySqlPool` (aliased as `Pool`) for database connections.
+/// * `application_settings` - CORS allow-list, security headers and optional TLS
+///   certificate. The server speaks HTTPS on `listener` when a certificate is configured.
+/// * `auth_settings` - Session settings used by the login handler.
+/// * `trash_settings` - Retention of deleted items, shown in the trash listing.
 ///
 /// # Returns
 ///
 /// A `Result` which is:
 /// - `Ok(Server)` on successful binding. The `Server` handle can be awaited to run the server.
-/// - `Err(std::io::Error)` if the server fails to bind to the listener.
-pub async fn run(listener: TcpListener, db_pool: Pool) -> Result<Server, std::io::Error> {
+/// - `Err(std::io::Error)` if the server fails to bind to the listener or the TLS
+///   certificate cannot be loaded.
+pub async fn run(
+    listener: TcpListener,
+    db_pool: Pool,
+    application_settings: ApplicationSettings,
+    auth_settings: AuthSettings,
+    trash_settings: TrashSettings,
+) -> Result<Server, std::io::Error> {
     // Wrap the pool in Arc for thread-safe sharing
     info!("Listening on: {:?}", &listener);
-    let db_pool = web::Data::new(AppState { db_pool });
+    let db_pool = web::Data::new(AppState { db_pool, auth: auth_settings, trash: trash_settings });
+    let tls_config = security::load_rustls_config(&application_settings)?;
 
     info!("Configuring HTTP server routes");
     let server = HttpServer::new(move || {
         debug!("Creating new App instance");
         
-        // Configure CORS
-        let cors = actix_cors::Cors::default()
-            .allow_any_origin() // For development, allow any origin. In production, restrict to frontend URL.
-            .allow_any_method()
-            .allow_any_header()
-            .max_age(3600);
-
+        // Authentication runs inside CORS so preflight requests and 401 responses get CORS headers.
+        // Security headers are outermost so every response carries them.
+        // Routes open to any logged-in user (readers included) have no `RequireRole`.
         App::new()
-            .wrap(cors)
+            .wrap(actix_web::middleware::from_fn(auth::require_auth))
+            .wrap(security::cors(&application_settings))
+            .wrap(security::security_headers(&application_settings))
             .app_data(db_pool.clone())
             .route("/health", web::get().to(health_check))
             .route("/health/db", web::get().to(db_health_check))
+            // API v1 routes - Authentication
+            .route("/api/v1/auth/login", web::post().to(handlers::auth::login))
+            .route("/api/v1/auth/logout", web::post().to(handlers::auth::logout))
+            .route("/api/v1/auth/me", web::get().to(handlers::auth::me))
+            // API v1 routes - Users
+            .route("/api/v1/users", web::get().to(handlers::users::list_users).wrap(RequireRole(UserRole::Admin)))
+            .route("/api/v1/users", web::post().to(handlers::users::create_user).wrap(RequireRole(UserRole::Admin)))
+            .route("/api/v1/users/{id}", web::put().to(handlers::users::update_user).wrap(RequireRole(UserRole::Admin)))
+            .route("/api/v1/users/{id}", web::delete().to(handlers::users::delete_user).wrap(RequireRole(UserRole::Admin)))
+            // API v1 routes - API Tokens (login session only, any role)
+            .route("/api/v1/tokens", web::get().to(handlers::api_tokens::list_api_tokens))
+            .route("/api/v1/tokens", web::post().to(handlers::api_tokens::create_api_token))
+            .route("/api/v1/tokens/{id}", web::delete().to(handlers::api_tokens::revoke_api_token))
             // API v1 routes - Titles
             .route("/api/v1/titles", web::get().to(handlers::titles::list_titles))
             .route("/api/v1/titles/search", web::get().to(handlers::titles::search_titles))
-            .route("/api/v1/titles/duplicates", web::get().to(handlers::titles::detect_duplicates))
-            .route("/api/v1/titles/{primary_id}/merge/{secondary_id}", web::post().to(handlers::titles::merge_titles))
-            .route("/api/v1/titles", web::post().to(handlers::titles::create_title))
-            .route("/api/v1/titles/{id}", web::put().to(handlers::titles::update_title))
-            .route("/api/v1/titles/{id}", web::delete().to(handlers::titles::delete_title))
+            .route("/api/v1/titles/duplicates", web::get().to(handlers::titles::detect_duplicates).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/bulk", web::post().to(handlers::bulk::bulk_edit_titles).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{primary_id}/merge/{secondary_id}", web::post().to(handlers::titles::merge_titles).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles", web::post().to(handlers::titles::create_title).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{id}", web::put().to(handlers::titles::update_title).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{id}", web::delete().to(handlers::titles::delete_title).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Title Authors
             .route("/api/v1/titles/{title_id}/authors", web::get().to(handlers::titles::list_title_authors))
-            .route("/api/v1/titles/{title_id}/authors", web::post().to(handlers::titles::add_author_to_title))
-            .route("/api/v1/titles/{title_id}/authors/{author_id}", web::delete().to(handlers::titles::remove_author_from_title))
+            .route("/api/v1/titles/{title_id}/authors", web::post().to(handlers::titles::add_author_to_title).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/authors/{author_id}", web::delete().to(handlers::titles::remove_author_from_title).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Title Identifiers
+            .route("/api/v1/titles/{title_id}/identifiers", web::get().to(handlers::title_identifiers::list_title_identifiers))
+            .route("/api/v1/titles/{title_id}/identifiers", web::post().to(handlers::title_identifiers::add_title_identifier).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/identifiers/{id}", web::put().to(handlers::title_identifiers::update_title_identifier).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/identifiers/{id}", web::delete().to(handlers::title_identifiers::delete_title_identifier).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/tags", web::get().to(handlers::tags::list_title_tags))
+            .route("/api/v1/titles/{title_id}/tags", web::post().to(handlers::tags::add_tag_to_title).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/tags/{tag_id}", web::delete().to(handlers::tags::remove_tag_from_title).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Title relations
+            .route("/api/v1/titles/{title_id}/relations", web::get().to(handlers::title_relations::list_title_relations))
+            .route("/api/v1/titles/{title_id}/relations", web::post().to(handlers::title_relations::add_title_relation).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/relations/{id}", web::delete().to(handlers::title_relations::delete_title_relation).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Title contents (anthologies and collections)
+            .route("/api/v1/titles/{title_id}/contents", web::get().to(handlers::title_contents::list_title_contents))
+            .route("/api/v1/titles/{title_id}/contents", web::post().to(handlers::title_contents::add_title_content).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/contents/{id}", web::put().to(handlers::title_contents::update_title_content).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/contents/{id}", web::delete().to(handlers::title_contents::delete_title_content).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Title reviews and notes (own entries, any role)
+            .route("/api/v1/titles/{title_id}/reviews", web::get().to(handlers::reviews::list_title_reviews))
+            .route("/api/v1/titles/{title_id}/reviews", web::put().to(handlers::reviews::set_title_review))
+            .route("/api/v1/titles/{title_id}/reviews", web::delete().to(handlers::reviews::delete_title_review))
+            .route("/api/v1/titles/{title_id}/notes", web::get().to(handlers::reviews::list_title_notes))
+            .route("/api/v1/titles/{title_id}/notes", web::post().to(handlers::reviews::create_title_note))
+            .route("/api/v1/titles/{title_id}/notes/{id}", web::put().to(handlers::reviews::update_title_note))
+            .route("/api/v1/titles/{title_id}/notes/{id}", web::delete().to(handlers::reviews::delete_title_note))
+            // API v1 routes - Title revision history
+            .route("/api/v1/titles/{title_id}/revisions", web::get().to(handlers::title_revisions::list_title_revisions))
+            .route("/api/v1/titles/{title_id}/revisions/diff", web::get().to(handlers::title_revisions::diff_title_revisions))
+            .route("/api/v1/titles/{title_id}/revisions/{revision}/revert", web::post().to(handlers::title_revisions::revert_title_revision).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Wishlist
+            .route("/api/v1/wishlist", web::get().to(handlers::wishlist::list_wishlist))
+            .route("/api/v1/wishlist/shares", web::get().to(handlers::wishlist::list_share_links).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/wishlist/shares", web::post().to(handlers::wishlist::create_share_link).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/wishlist/shares/{id}", web::delete().to(handlers::wishlist::delete_share_link).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/wishlist", web::put().to(handlers::wishlist::set_wishlist_item).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/wishlist", web::delete().to(handlers::wishlist::remove_wishlist_item).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/titles/{title_id}/wishlist/acquire", web::post().to(handlers::wishlist::acquire_wishlist_item).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/public/wishlist/{token}", web::get().to(handlers::wishlist::shared_wishlist))
+            // API v1 routes - Reading tracking (own records, any role)
+            .route("/api/v1/reading", web::get().to(handlers::reading::list_reading_records))
+            .route("/api/v1/reading", web::post().to(handlers::reading::create_reading_record))
+            .route("/api/v1/reading/current", web::get().to(handlers::reading::currently_reading))
+            .route("/api/v1/reading/finished", web::get().to(handlers::reading::finished_readings))
+            .route("/api/v1/reading/{id}", web::put().to(handlers::reading::update_reading_record))
+            .route("/api/v1/reading/{id}", web::delete().to(handlers::reading::delete_reading_record))
             // API v1 routes - Volumes
             .route("/api/v1/titles/{title_id}/volumes", web::get().to(handlers::volumes::list_volumes_by_title))
-            .route("/api/v1/volumes", web::post().to(handlers::volumes::create_volume))
+            .route("/api/v1/volumes", web::post().to(handlers::volumes::create_volume).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/volumes/bulk", web::post().to(handlers::bulk::bulk_edit_volumes).wrap(RequireRole(UserRole::Librarian)))
             .route("/api/v1/volumes/{id}", web::get().to(handlers::volumes::get_volume))
-            .route("/api/v1/volumes/{id}", web::put().to(handlers::volumes::update_volume))
-            .route("/api/v1/volumes/{id}", web::delete().to(handlers::volumes::delete_volume))
+            .route("/api/v1/volumes/{id}", web::put().to(handlers::volumes::update_volume).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/volumes/{id}", web::delete().to(handlers::volumes::delete_volume).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Locations
             .route("/api/v1/locations", web::get().to(handlers::locations::list_locations))
-            .route("/api/v1/locations", web::post().to(handlers::locations::create_location))
+            .route("/api/v1/locations", web::post().to(handlers::locations::create_location).wrap(RequireRole(UserRole::Librarian)))
             .route("/api/v1/locations/{id}", web::get().to(handlers::locations::get_location))
-            .route("/api/v1/locations/{id}", web::put().to(handlers::locations::update_location))
-            .route("/api/v1/locations/{id}", web::delete().to(handlers::locations::delete_location))
+            .route("/api/v1/locations/{id}", web::put().to(handlers::locations::update_location).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/locations/{id}", web::delete().to(handlers::locations::delete_location).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Authors
             .route("/api/v1/authors", web::get().to(handlers::authors::list_authors))
-            .route("/api/v1/authors", web::post().to(handlers::authors::create_author))
+            .route("/api/v1/authors", web::post().to(handlers::authors::create_author).wrap(RequireRole(UserRole::Librarian)))
             .route("/api/v1/authors/{id}", web::get().to(handlers::authors::get_author))
-            .route("/api/v1/authors/{id}", web::put().to(handlers::authors::update_author))
-            .route("/api/v1/authors/{id}", web::delete().to(handlers::authors::delete_author))
+            .route("/api/v1/authors/{id}", web::put().to(handlers::authors::update_author).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/authors/{id}", web::delete().to(handlers::authors::delete_author).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Publishers
             .route("/api/v1/publishers", web::get().to(handlers::publishers::list_publishers))
-            .route("/api/v1/publishers", web::post().to(handlers::publishers::create_publisher))
+            .route("/api/v1/publishers", web::post().to(handlers::publishers::create_publisher).wrap(RequireRole(UserRole::Librarian)))
             .route("/api/v1/publishers/{id}", web::get().to(handlers::publishers::get_publisher))
-            .route("/api/v1/publishers/{id}", web::put().to(handlers::publishers::update_publisher))
-            .route("/api/v1/publishers/{id}", web::delete().to(handlers::publishers::delete_publisher))
+            .route("/api/v1/publishers/{id}", web::put().to(handlers::publishers::update_publisher).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/publishers/{id}", web::delete().to(handlers::publishers::delete_publisher).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Genres
             .route("/api/v1/genres", web::get().to(handlers::genres::list_genres))
-            .route("/api/v1/genres", web::post().to(handlers::genres::create_genre))
+            .route("/api/v1/genres", web::post().to(handlers::genres::create_genre).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/genres/tree", web::get().to(handlers::genres::get_genre_tree))
             .route("/api/v1/genres/{id}", web::get().to(handlers::genres::get_genre))
-            .route("/api/v1/genres/{id}", web::put().to(handlers::genres::update_genre))
-            .route("/api/v1/genres/{id}", web::delete().to(handlers::genres::delete_genre))
+            .route("/api/v1/genres/{id}", web::put().to(handlers::genres::update_genre).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/genres/{id}", web::delete().to(handlers::genres::delete_genre).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Series
             .route("/api/v1/series", web::get().to(handlers::series::list_series))
-            .route("/api/v1/series", web::post().to(handlers::series::create_series))
+            .route("/api/v1/series", web::post().to(handlers::series::create_series).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/series/tree", web::get().to(handlers::series::get_series_tree))
+            .route("/api/v1/series/missing", web::get().to(handlers::series::list_missing_volumes))
             .route("/api/v1/series/{id}", web::get().to(handlers::series::get_series))
-            .route("/api/v1/series/{id}", web::put().to(handlers::series::update_series))
-            .route("/api/v1/series/{id}", web::delete().to(handlers::series::delete_series))
+            .route("/api/v1/series/{id}", web::put().to(handlers::series::update_series).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/series/{id}", web::delete().to(handlers::series::delete_series).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Tags
+            .route("/api/v1/tags", web::get().to(handlers::tags::list_tags))
+            .route("/api/v1/tags/{id}", web::put().to(handlers::tags::rename_tag).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/tags/{id}", web::delete().to(handlers::tags::delete_tag).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Custom fields
+            .route("/api/v1/custom-fields", web::get().to(handlers::custom_fields::list_custom_fields))
+            .route("/api/v1/custom-fields", web::post().to(handlers::custom_fields::create_custom_field).wrap(RequireRole(UserRole::Admin)))
+            .route("/api/v1/custom-fields/{id}", web::get().to(handlers::custom_fields::get_custom_field))
+            .route("/api/v1/custom-fields/{id}", web::put().to(handlers::custom_fields::update_custom_field).wrap(RequireRole(UserRole::Admin)))
+            .route("/api/v1/custom-fields/{id}", web::delete().to(handlers::custom_fields::delete_custom_field).wrap(RequireRole(UserRole::Admin)))
+            // API v1 routes - Works
+            .route("/api/v1/works", web::get().to(handlers::works::list_works))
+            .route("/api/v1/works", web::post().to(handlers::works::create_work).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/works/{id}", web::get().to(handlers::works::get_work))
+            .route("/api/v1/works/{id}", web::put().to(handlers::works::update_work).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/works/{id}", web::delete().to(handlers::works::delete_work).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/works/{id}/titles", web::get().to(handlers::works::list_work_titles))
+            .route("/api/v1/works/{id}/titles/{title_id}", web::post().to(handlers::works::attach_title_to_work).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/works/{id}/titles/{title_id}", web::delete().to(handlers::works::detach_title_from_work).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Uploads
-            .route("/api/v1/uploads/cover", web::post().to(handlers::uploads::upload_cover))
+            .route("/api/v1/uploads/cover", web::post().to(handlers::uploads::upload_cover).wrap(RequireRole(UserRole::Librarian)))
             .route("/api/v1/uploads/cover/{title_id}", web::get().to(handlers::uploads::get_cover))
-            .route("/api/v1/uploads/cover/{title_id}", web::delete().to(handlers::uploads::delete_cover))
+            .route("/api/v1/uploads/cover/{title_id}", web::delete().to(handlers::uploads::delete_cover).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - ISBN Lookup
-            .route("/api/v1/isbn/{isbn}", web::get().to(handlers::isbn_lookup::lookup_isbn))
+            .route("/api/v1/isbn/{isbn}", web::get().to(handlers::isbn_lookup::lookup_isbn).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Languages
+            .route("/api/v1/languages", web::get().to(handlers::languages::list_languages))
             // API v1 routes - Borrower Groups
-            .route("/api/v1/borrower-groups", web::get().to(handlers::borrower_groups::list_borrower_groups))
-            .route("/api/v1/borrower-groups", web::post().to(handlers::borrower_groups::create_borrower_group))
-            .route("/api/v1/borrower-groups/{id}", web::put().to(handlers::borrower_groups::update_borrower_group))
-            .route("/api/v1/borrower-groups/{id}", web::delete().to(handlers::borrower_groups::delete_borrower_group))
+            .route("/api/v1/borrower-groups", web::get().to(handlers::borrower_groups::list_borrower_groups).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/borrower-groups", web::post().to(handlers::borrower_groups::create_borrower_group).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/borrower-groups/{id}", web::put().to(handlers::borrower_groups::update_borrower_group).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/borrower-groups/{id}", web::delete().to(handlers::borrower_groups::delete_borrower_group).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Borrowers
-            .route("/api/v1/borrowers", web::get().to(handlers::borrowers::list_borrowers))
-            .route("/api/v1/borrowers", web::post().to(handlers::borrowers::create_borrower))
-            .route("/api/v1/borrowers/{id}", web::put().to(handlers::borrowers::update_borrower))
-            .route("/api/v1/borrowers/{id}", web::delete().to(handlers::borrowers::delete_borrower))
+            .route("/api/v1/borrowers", web::get().to(handlers::borrowers::list_borrowers).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/borrowers", web::post().to(handlers::borrowers::create_borrower).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/borrowers/{id}", web::put().to(handlers::borrowers::update_borrower).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/borrowers/{id}", web::delete().to(handlers::borrowers::delete_borrower).wrap(RequireRole(UserRole::Librarian)))
             // API v1 routes - Loans
-            .route("/api/v1/loans", web::get().to(handlers::loans::list_active_loans))
-            .route("/api/v1/loans", web::post().to(handlers::loans::create_loan_by_barcode))
-            .route("/api/v1/loans/overdue", web::get().to(handlers::loans::list_overdue_loans))
-            .route("/api/v1/loans/{id}/return", web::post().to(handlers::loans::return_loan))
-            .route("/api/v1/loans/{id}/extend", web::post().to(handlers::loans::extend_loan))
+            .route("/api/v1/loans", web::get().to(handlers::loans::list_active_loans).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/loans", web::post().to(handlers::loans::create_loan_by_barcode).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/loans/overdue", web::get().to(handlers::loans::list_overdue_loans).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/loans/{id}/return", web::post().to(handlers::loans::return_loan).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/loans/{id}/extend", web::post().to(handlers::loans::extend_loan).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Borrower self-service portal (any role)
+            .route("/api/v1/me/loans", web::get().to(handlers::portal::list_my_loans))
+            .route("/api/v1/me/loans/{id}/extend", web::post().to(handlers::portal::extend_my_loan))
             // API v1 routes - Statistics
             .route("/api/v1/statistics/library", web::get().to(handlers::statistics::get_library_statistics))
             .route("/api/v1/statistics/genres", web::get().to(handlers::statistics::get_volumes_per_genre))
             .route("/api/v1/statistics/locations", web::get().to(handlers::statistics::get_volumes_per_location))
-            .route("/api/v1/statistics/loans", web::get().to(handlers::statistics::get_loan_statistics))
+            .route("/api/v1/statistics/translations", web::get().to(handlers::statistics::get_translation_statistics))
+            .route("/api/v1/statistics/loans", web::get().to(handlers::statistics::get_loan_statistics).wrap(RequireRole(UserRole::Librarian)))
+            // API v1 routes - Trash
+            .route("/api/v1/trash", web::get().to(handlers::trash::list_trash).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/trash/{item_type}/{id}/restore", web::post().to(handlers::trash::restore_trash_item).wrap(RequireRole(UserRole::Librarian)))
+            .route("/api/v1/trash/{item_type}/{id}", web::delete().to(handlers::trash::purge_trash_item).wrap(RequireRole(UserRole::Admin)))
             // Serve static files
             .service(
                 actix_files::Files::new("/", {
@@ -252,8 +362,15 @@ pub async fn run(listener: TcpListener, db_pool: Pool) -> Result<Server, std::io
                     }
                 })
             )
-    })
-    .listen(listener)?
+    });
+
+    let server = match tls_config {
+        Some(tls_config) => {
+            info!("TLS enabled, serving HTTPS");
+            server.listen_rustls_0_23(listener, tls_config)?
+        }
+        None => server.listen(listener)?,
+    }
     .run();
 
     info!("HTTP server started successfully");
@@ -84,7 +84,7 @@ async fn main() -> Result<(), std::io::Error> {
 
     // Get configuration from environment
     let database_url = configuration.database.connection_string();
-    let host = configuration.application.host;
+    let host = configuration.application.host.clone();
     let port = configuration.application.port;
     let address = format!("{}:{}", host, port);
 
@@ -94,7 +94,7 @@ async fn main() -> Result<(), std::io::Error> {
     info!("Connecting to database...");
     let db_pool = MySqlPoolOptions::new()
         .max_connections(5)
-        .connect(&database_url)
+        .connect(database_url.expose())
         .await
         .map_err(|e| {
             error!("Failed to connect to database: {}", e);
@@ -114,6 +114,27 @@ async fn main() -> Result<(), std::io::Error> {
         })?;
     info!("Database migrations applied successfully");
 
+    // Make sure at least one account can log in
+    backend::auth::ensure_initial_admin(&db_pool, &configuration.auth)
+        .await
+        .map_err(|e| {
+            error!("Failed to create initial user: {}", e);
+            std::io::Error::other(e)
+        })?;
+
+    // Store the languages of older titles as catalogue codes
+    match backend::handlers::titles::normalize_stored_languages(&db_pool).await {
+        Ok(0) => {}
+        Ok(count) => info!("Normalized the language of {} title(s)", count),
+        Err(e) => error!("Failed to normalize title languages: {}", e),
+    }
+
+    // Purge the trash in the background, now and then periodically
+    tokio::spawn(backend::handlers::trash::run_retention_task(
+        db_pool.clone(),
+        configuration.trash.retention_days,
+    ));
+
     info!("Starting server on {}", address);
 
     // Create TCP listener
@@ -126,5 +147,5 @@ async fn main() -> Result<(), std::io::Error> {
     info!("Server bound to {}", address);
 
     // Run the server
-    run(listener, db_pool).await?.await
+    run(listener, db_pool, configuration.application, configuration.auth, configuration.trash).await?.await
 }
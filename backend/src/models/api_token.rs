@@ -0,0 +1,19 @@
+//! API token models.
+//!
+//! This module defines the data structures for long-lived API tokens used by
+//! scripts and integrations instead of a user's password.
+//!
+//! # Key Features
+//!
+//! - **Scopes**: Each token only grants the listed `ApiScope`s, within its owner's role.
+//! - **Hash-only Storage**: Only the SHA-256 hash of the token is stored.
+//! - **Revocation**: Revoked or expired tokens are rejected immediately.
+
+/// API token metadata as returned by the listing endpoint.
+///
+/// # Database Structure
+///
+/// Mapped to the `api_tokens` table. The `token_hash` column is never exposed.
+pub use shared::models::api_tokens::ApiToken;
+
+pub use shared::dtos::api_tokens::{CreateApiTokenRequest, CreateApiTokenResponse};
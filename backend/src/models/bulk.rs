@@ -0,0 +1,20 @@
+//! Bulk edit models for the rbibli library management system.
+//!
+//! A bulk edit applies one patch to many titles or volumes, selected by ID
+//! or with the title search filters, in a single transaction. A dry run
+//! reports what would change without saving anything.
+
+/// Represents the result of a bulk edit for one title or volume.
+///
+/// # Fields
+///
+/// * `id` - ID of the title or volume (UUID)
+/// * `label` - Title, or barcode of the volume
+/// * `status` - `updated`, `unchanged` or `not_found`
+/// * `changes` - Fields changed, with their old and new values
+pub use shared::models::bulk::BulkItemResult;
+
+/// Represents the report of a bulk edit, with counters and per-item results.
+pub use shared::models::bulk::BulkEditReport;
+
+pub use shared::dtos::bulk::{BulkTitlePatch, BulkTitleEditRequest, BulkVolumePatch, BulkVolumeEditRequest};
@@ -0,0 +1,32 @@
+//! Custom field models for the rbibli library management system.
+//!
+//! Custom fields let administrators record data the schema does not have,
+//! such as a reading level on titles or the shop a volume was bought from.
+//!
+//! # Definitions and Values
+//!
+//! - A field has a type (`text`, `number`, `date`, `boolean`, `enum`) and a
+//!   scope (`title` or `volume`) that cannot change once created
+//! - Values are stored as normalized text in `title_custom_values` and
+//!   `volume_custom_values`, and returned in the `custom_fields` map of
+//!   titles and volumes, keyed by field UUID
+//! - Deleting a field deletes its values
+
+/// Represents a custom field definition.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier (UUID)
+/// * `name` - Label shown in forms, unique within a scope
+/// * `field_type` - Type of the values
+/// * `scope` - Whether the field applies to titles or volumes
+/// * `options` - Allowed values of an `enum` field
+/// * `created_at` - Record creation timestamp
+/// * `updated_at` - Last modification timestamp
+pub use shared::models::custom_fields::CustomField;
+
+pub use shared::dtos::custom_fields::CreateCustomFieldRequest;
+
+pub use shared::dtos::custom_fields::UpdateCustomFieldRequest;
+
+pub use shared::dtos::custom_fields::CustomFieldListParams;
@@ -7,6 +7,7 @@
 //!
 //! - **Categorization**: Simple name and description for grouping books.
 //! - **Statistics**: Tracks how many titles belong to each genre.
+//! - **Hierarchy**: Genres can have a parent genre (e.g. Fiction > Fantasy > Urban Fantasy).
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,10 @@ use uuid::Uuid;
 pub use shared::models::genres::Genre;
 pub use shared::models::genres::GenreWithTitleCount;
 
+/// A genre with its full path, subtree title count and sub-genres, as returned
+/// by the genre tree endpoint.
+pub use shared::models::genres::GenreTreeNode;
+
 pub use shared::dtos::genres::CreateGenreRequest;
 
 pub use shared::dtos::genres::UpdateGenreRequest;
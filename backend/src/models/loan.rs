@@ -48,3 +48,6 @@ pub use shared::models::loans::LoanDetail;
 pub use shared::dtos::loans::CreateLoanRequest;
 
 pub use shared::dtos::loans::ReturnLoanRequest;
+
+/// Loans of the borrower linked to the current user (self-service portal).
+pub use shared::dtos::loans::MyLoansResponse;
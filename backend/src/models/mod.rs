@@ -9,9 +9,19 @@ pub mod author;
 pub mod publisher;
 pub mod genre;
 pub mod series;
+pub mod work;
+pub mod tag;
+pub mod custom_field;
+pub mod trash;
+pub mod wishlist;
+pub mod reading;
+pub mod review;
+pub mod bulk;
 pub mod volume;
 pub mod borrower;
 pub mod loan;
+pub mod user;
+pub mod api_token;
 
 
 // Re-export shared enums
@@ -21,12 +31,27 @@ pub use shared::models::enums::{
     AuthorRole,
     DuplicateConfidence,
     LoanRecordStatus as LoanStatus,
+    UserRole,
+    ApiScope,
+    IdentifierType,
+    CustomFieldType,
+    CustomFieldScope,
+    TrashItemType,
+    WishlistPriority,
+    ReadingStatus,
+    TitleNoteKind,
+    TitleRelationType,
+    BulkItemStatus,
 };
 
 
 pub use title::{
     Title, TitleWithCount, CreateTitleRequest, UpdateTitleRequest, TitleSearchParams,
     DuplicatePair, DuplicateDetectionResponse, MergeTitlesRequest, MergeTitlesResponse,
+    TitleIdentifier, CreateTitleIdentifierRequest, UpdateTitleIdentifierRequest,
+    TitleRelation, CreateTitleRelationRequest,
+    TitleContent, TitleContentAuthor, TitleContentRequest, TitleContentAuthorRequest,
+    TitleRevision, TitleFieldChange, TitleRevisionDiff, TitleRevisionDiffParams,
 };
 pub use location::{Location, LocationWithPath, CreateLocationRequest, UpdateLocationRequest};
 pub use author::{
@@ -37,10 +62,29 @@ pub use publisher::{
     Publisher, PublisherWithTitleCount, CreatePublisherRequest, UpdatePublisherRequest
 };
 pub use genre::{
-    Genre, GenreWithTitleCount, CreateGenreRequest, UpdateGenreRequest
+    Genre, GenreWithTitleCount, GenreTreeNode, CreateGenreRequest, UpdateGenreRequest
 };
 pub use series::{
-    Series, SeriesWithTitleCount, CreateSeriesRequest, UpdateSeriesRequest
+    Series, SeriesWithTitleCount, SeriesDetail, SeriesTreeNode, SeriesListParams, CreateSeriesRequest, UpdateSeriesRequest
+};
+pub use work::{
+    Work, WorkWithTitleCount, CreateWorkRequest, UpdateWorkRequest
+};
+pub use tag::{
+    Tag, TagWithTitleCount, AddTagToTitleRequest, RenameTagRequest
+};
+pub use custom_field::{
+    CustomField, CreateCustomFieldRequest, UpdateCustomFieldRequest, CustomFieldListParams
+};
+pub use trash::{TrashItem, TrashListParams};
+pub use wishlist::{
+    WishlistItem, SharedWishlist, SharedWishlistItem, WishlistShareLink, SetWishlistItemRequest, AcquireWishlistItemRequest,
+    CreateWishlistShareLinkRequest, CreateWishlistShareLinkResponse,
+};
+pub use reading::{ReadingRecord, CreateReadingRecordRequest, UpdateReadingRecordRequest, ReadingListParams};
+pub use review::{TitleReview, TitleNote, SetTitleReviewRequest, TitleNoteRequest};
+pub use bulk::{
+    BulkItemResult, BulkEditReport, BulkTitlePatch, BulkTitleEditRequest, BulkVolumePatch, BulkVolumeEditRequest,
 };
 pub use volume::{
     Volume, CreateVolumeRequest, UpdateVolumeRequest
@@ -51,6 +95,11 @@ pub use borrower::{
     CreateBorrowerRequest, UpdateBorrowerRequest
 };
 pub use loan::{
-    Loan, LoanDetail, CreateLoanRequest, ReturnLoanRequest
+    Loan, LoanDetail, CreateLoanRequest, ReturnLoanRequest, MyLoansResponse
+};
+pub use user::{
+    User, LoginRequest, LoginResponse, CreateUserRequest, UpdateUserRequest
+};
+pub use api_token::{
+    ApiToken, CreateApiTokenRequest, CreateApiTokenResponse
 };
-
@@ -0,0 +1,17 @@
+//! Reading tracking models for the rbibli library management system.
+//!
+//! A reading record says where a person (a user account) is in reading a
+//! title: on their to-read list, reading it or done, with the dates and the
+//! current page. Re-reads are further records for the same person and title.
+
+/// Represents one reading of a title by a user.
+///
+/// # Fields
+///
+/// * `status` - `to_read`, `reading` or `read`
+/// * `started_on` / `finished_on` - Dates of the reading, filled in automatically on status changes
+/// * `current_page` - Last page read, compared to the title's `pages` in `progress_percent`
+/// * `reading_number` - 1 for the first reading, 2 for the first re-read, ...
+pub use shared::models::reading::ReadingRecord;
+
+pub use shared::dtos::reading::{CreateReadingRecordRequest, UpdateReadingRecordRequest, ReadingListParams};
@@ -0,0 +1,21 @@
+//! Review models for the rbibli library management system.
+//!
+//! Users rate titles from 1 to 5 stars and may write a review, visible to
+//! everyone; the average rating is shown with each title. Notes and favourite
+//! quotes are private to the user who wrote them.
+
+/// Represents a user's rating and review of a title.
+///
+/// # Database Structure
+///
+/// Mapped to the `title_reviews` table, unique per title and user.
+pub use shared::models::reviews::TitleReview;
+
+/// Represents a private note or quote, with an optional page reference.
+///
+/// # Database Structure
+///
+/// Mapped to the `title_notes` table.
+pub use shared::models::reviews::TitleNote;
+
+pub use shared::dtos::reviews::{SetTitleReviewRequest, TitleNoteRequest};
@@ -12,6 +12,8 @@
 //! - Optional descriptions for additional context
 //! - Title count tracking for each series
 //! - Delete protection: series with associated titles cannot be deleted
+//! - Nesting: a series can have a parent series (universe > series > sub-series)
+//! - Completeness: the numbers missing from the collection, up to the total number of volumes
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -30,6 +32,9 @@ use uuid::Uuid;
 /// * `id` - Unique identifier (UUID)
 /// * `name` - Series name (e.g., "Asterix", "Harry Potter")
 /// * `description` - Optional detailed description
+/// * `parent_id` - UUID of the parent series, for nested series
+/// * `total_volumes` - Number of volumes published, when known
+/// * `reading_order` - Recommended reading order, when it differs from the numbering
 /// * `created_at` - Record creation timestamp
 /// * `updated_at` - Last modification timestamp
 ///
@@ -47,6 +52,15 @@ use uuid::Uuid;
 pub use shared::models::series::Series;
 pub use shared::models::series::SeriesWithTitleCount;
 
+/// A series with its full path, subtree title count and sub-series, as returned
+/// by the series tree endpoint.
+pub use shared::models::series::SeriesTreeNode;
+
+/// A series with its owned title count and the numbers missing from the collection.
+pub use shared::models::series::SeriesDetail;
+
 pub use shared::dtos::series::CreateSeriesRequest;
 
 pub use shared::dtos::series::UpdateSeriesRequest;
+
+pub use shared::dtos::series::SeriesListParams;
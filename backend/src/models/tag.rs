@@ -0,0 +1,26 @@
+//! Tag models for the rbibli library management system.
+//!
+//! Tags are free-form labels such as "signed", "to-read-2026" or
+//! "gift from grandma". Unlike genres they are not curated: a tag is created
+//! the first time it is attached to a title.
+//!
+//! # Tags and Titles
+//!
+//! - Many-to-many relationship through the `title_tags` table
+//! - Tag names are unique, compared case-insensitively
+//! - Deleting a tag or a title removes the links, never the other side
+
+/// Represents a tag.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier (UUID)
+/// * `name` - Tag name, trimmed with inner whitespace collapsed
+/// * `created_at` - Record creation timestamp
+/// * `updated_at` - Last modification timestamp
+pub use shared::models::tags::Tag;
+pub use shared::models::tags::TagWithTitleCount;
+
+pub use shared::dtos::tags::AddTagToTitleRequest;
+
+pub use shared::dtos::tags::RenameTagRequest;
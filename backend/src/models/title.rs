@@ -7,6 +7,9 @@
 //!
 //! - **Abstract Metadata**: `Title` represents the book info (ISBN, author, etc.) separate from physical copies.
 //! - **Search & Filtering**: `TitleSearchParams` supports complex queries including fuzzy search.
+//! - **Identifiers**: `TitleIdentifier` holds extra ISBNs, EAN, ISSN, LCCN and OCLC numbers.
+//! - **Relations**: `TitleRelation` links sequels, translations, commentaries and adaptations.
+//! - **Contents**: `TitleContent` lists the stories of an anthology or collection.
 //! - **Duplicate Detection**: Models for identifying and merging duplicate entries.
 
 use chrono::{DateTime, Utc};
@@ -35,6 +38,44 @@ pub use shared::dtos::titles::UpdateTitleRequest;
 
 pub use shared::dtos::titles::TitleSearchParams;
 
+/// An additional identifier (ISBN, EAN, ISSN, LCCN, OCLC) attached to a title.
+///
+/// # Database Structure
+///
+/// Mapped to the `title_identifiers` table. Values are stored normalized.
+pub use shared::models::titles::TitleIdentifier;
+
+pub use shared::dtos::titles::{CreateTitleIdentifierRequest, UpdateTitleIdentifierRequest};
+
+/// A typed relation between two titles (sequel, translation, commentary, adaptation, companion).
+///
+/// # Database Structure
+///
+/// Mapped to the `title_relations` table. Each relation is stored once, from the
+/// title for which its type is the stored side, and listed with the inverse type
+/// on the other title.
+pub use shared::models::titles::TitleRelation;
+
+pub use shared::dtos::titles::CreateTitleRelationRequest;
+
+/// A story, essay or poem of an anthology or collection, with its own authors.
+///
+/// # Database Structure
+///
+/// Mapped to the `title_contents` table, with the authors in `title_content_authors`.
+pub use shared::models::titles::{TitleContent, TitleContentAuthor};
+
+pub use shared::dtos::titles::{TitleContentRequest, TitleContentAuthorRequest};
+
+/// A recorded change to a title's metadata, with the fields it changed.
+///
+/// # Database Structure
+///
+/// Mapped to the `title_revisions` table, with one `title_revision_changes` row per field.
+pub use shared::models::titles::{TitleRevision, TitleFieldChange, TitleRevisionDiff};
+
+pub use shared::dtos::titles::TitleRevisionDiffParams;
+
 use shared::models::enums::DuplicateConfidence;
 
 /// Represents a potential duplicate title pair.
@@ -0,0 +1,19 @@
+//! Trash models for the rbibli library management system.
+//!
+//! Deleting a title, an author or a borrower only sets its `deleted_at`
+//! column. The record is hidden from every list and search endpoint but can
+//! be restored until it is purged, either by hand or automatically once it
+//! has been in the trash longer than `trash.retention_days`.
+
+/// Represents a deleted record waiting in the trash.
+///
+/// # Fields
+///
+/// * `item_type` - `title`, `author` or `borrower`
+/// * `id` - ID of the deleted record (UUID)
+/// * `name` - Title, author full name or borrower name
+/// * `deleted_at` - When the record was deleted
+/// * `purge_at` - When it will be purged automatically, if retention is enabled
+pub use shared::models::trash::TrashItem;
+
+pub use shared::dtos::trash::TrashListParams;
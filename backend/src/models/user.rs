@@ -0,0 +1,22 @@
+//! User account models.
+//!
+//! This module defines the data structures for user accounts and login sessions.
+//! Every `/api/v1` route requires an authenticated user; see `crate::auth`.
+//!
+//! # Key Features
+//!
+//! - **Accounts**: Username, display name and an active flag.
+//! - **Password Hashing**: Passwords are stored as Argon2 hashes, never in clear text.
+//! - **Sessions**: Login issues a random token; only its SHA-256 hash is stored.
+
+/// User account allowed to access the API.
+///
+/// # Database Structure
+///
+/// Mapped to the `users` table in the database. The `password_hash` column
+/// is deliberately not part of this struct.
+pub use shared::models::users::User;
+
+pub use shared::dtos::users::{
+    LoginRequest, LoginResponse, CreateUserRequest, UpdateUserRequest
+};
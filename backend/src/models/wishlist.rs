@@ -0,0 +1,30 @@
+//! Wishlist models for the rbibli library management system.
+//!
+//! A title is on the wishlist when it has a `wishlist_items` row carrying its
+//! priority, the edition wanted, the highest price worth paying and notes.
+//! Share links let people without an account (e.g. relatives looking for a
+//! present) read the wishlist.
+
+/// Represents a wished title, with its authors and the number of volumes owned.
+///
+/// # Fields
+///
+/// * `priority` - `high`, `medium` or `low`; the wishlist is sorted by it
+/// * `desired_edition` - Edition wanted, e.g. "hardback"
+/// * `max_price` - Highest price worth paying
+pub use shared::models::wishlist::WishlistItem;
+
+/// Represents a wishlist entry as exported through a share link (no notes).
+pub use shared::models::wishlist::{SharedWishlist, SharedWishlistItem};
+
+/// Represents a read-only share link of the wishlist.
+///
+/// # Database Structure
+///
+/// Mapped to the `wishlist_share_links` table, which only stores the SHA-256 hash of the token.
+pub use shared::models::wishlist::WishlistShareLink;
+
+pub use shared::dtos::wishlist::{
+    SetWishlistItemRequest, AcquireWishlistItemRequest, CreateWishlistShareLinkRequest,
+    CreateWishlistShareLinkResponse,
+};
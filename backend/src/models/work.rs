@@ -0,0 +1,34 @@
+//! Work models for the rbibli library management system.
+//!
+//! A work is the abstract text behind one or more titles: the French and
+//! English translations of a novel, or its hardback and paperback editions,
+//! are different titles of the same work.
+//!
+//! # Works and Titles
+//!
+//! - One-to-many relationship: one work groups multiple titles
+//! - Each title is attached to at most one work (or none)
+//! - Titles without a work count as a work of their own in statistics and
+//!   in searches collapsed by work
+//! - Delete protection: works with attached titles cannot be deleted
+
+/// Represents an abstract work.
+///
+/// # Fields
+///
+/// * `id` - Unique identifier (UUID)
+/// * `title` - Reference title, usually the original title of the work
+/// * `description` - Optional description
+/// * `created_at` - Record creation timestamp
+/// * `updated_at` - Last modification timestamp
+///
+/// # Relationship with Titles
+///
+/// Titles reference works via the `work_id` foreign key with ON DELETE SET
+/// NULL, so removing a work never removes its titles.
+pub use shared::models::works::Work;
+pub use shared::models::works::WorkWithTitleCount;
+
+pub use shared::dtos::works::CreateWorkRequest;
+
+pub use shared::dtos::works::UpdateWorkRequest;
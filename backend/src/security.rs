@@ -0,0 +1,129 @@
+//! HTTP hardening: CORS allow-list, security headers and TLS.
+//!
+//! Everything here is driven by `ApplicationSettings`, so an instance exposed
+//! outside the local network can be locked down from `configuration.toml` or
+//! `APP__APPLICATION__*` environment variables without code changes.
+
+use crate::configuration::ApplicationSettings;
+use actix_cors::Cors;
+use actix_web::http::{header, Method};
+use actix_web::middleware::DefaultHeaders;
+use log::warn;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use std::io;
+use std::sync::Arc;
+
+/// Builds the CORS middleware from the configured allow-list.
+///
+/// Requests from other origins are still processed (so curl and scripts keep
+/// working) but get no CORS headers, which makes browsers block them.
+pub fn cors(settings: &ApplicationSettings) -> Cors {
+    let mut cors = Cors::default()
+        .allowed_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allowed_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT])
+        .block_on_origin_mismatch(false)
+        .max_age(3600);
+
+    for origin in &settings.allowed_origins {
+        cors = if origin == "*" {
+            cors.allow_any_origin()
+        } else {
+            cors.allowed_origin(origin)
+        };
+    }
+
+    cors
+}
+
+/// Builds the headers added to every response.
+///
+/// `Strict-Transport-Security` is only sent when the server speaks HTTPS, since
+/// browsers ignore it over plain HTTP.
+pub fn security_headers(settings: &ApplicationSettings) -> DefaultHeaders {
+    let headers = DefaultHeaders::new()
+        .add((header::CONTENT_SECURITY_POLICY, settings.content_security_policy.as_str()))
+        .add((header::X_CONTENT_TYPE_OPTIONS, "nosniff"))
+        .add((header::X_FRAME_OPTIONS, "DENY"))
+        .add((header::REFERRER_POLICY, "no-referrer"));
+
+    if settings.tls_paths().is_some() {
+        headers.add((
+            header::STRICT_TRANSPORT_SECURITY,
+            format!("max-age={}; includeSubDomains", settings.hsts_max_age_seconds),
+        ))
+    } else {
+        headers
+    }
+}
+
+/// Loads the rustls server configuration if HTTPS is configured.
+///
+/// # Returns
+///
+/// * `Ok(None)` - No certificate/key configured, serve plain HTTP
+/// * `Ok(Some(config))` - Certificate and key loaded
+/// * `Err` - A configured file cannot be read or does not contain a usable certificate/key
+pub fn load_rustls_config(settings: &ApplicationSettings) -> io::Result<Option<rustls::ServerConfig>> {
+    let Some((cert_path, key_path)) = settings.tls_paths() else {
+        if settings.tls_cert_path.is_some() || settings.tls_key_path.is_some() {
+            warn!("Only one of tls_cert_path and tls_key_path is set, serving plain HTTP");
+        }
+        return Ok(None);
+    };
+
+    let invalid = |path: &str, e: &dyn std::fmt::Display| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, e))
+    };
+
+    let certs = CertificateDer::pem_file_iter(cert_path)
+        .map_err(|e| invalid(cert_path, &e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| invalid(cert_path, &e))?;
+    if certs.is_empty() {
+        return Err(invalid(cert_path, &"no certificate found"));
+    }
+    let key = PrivateKeyDer::from_pem_file(key_path).map_err(|e| invalid(key_path, &e))?;
+
+    let config = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| io::Error::other(e.to_string()))?
+    .with_no_client_auth()
+    .with_single_cert(certs, key)
+    .map_err(|e| invalid(cert_path, &e))?;
+
+    Ok(Some(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> ApplicationSettings {
+        ApplicationSettings {
+            port: 8000,
+            host: "127.0.0.1".to_string(),
+            allowed_origins: Vec::new(),
+            tls_cert_path: None,
+            tls_key_path: None,
+            content_security_policy: "default-src 'self'".to_string(),
+            hsts_max_age_seconds: 60,
+        }
+    }
+
+    #[test]
+    fn test_tls_requires_both_paths() {
+        let mut settings = settings();
+        assert!(load_rustls_config(&settings).unwrap().is_none());
+
+        settings.tls_cert_path = Some("cert.pem".to_string());
+        assert!(settings.tls_paths().is_none());
+        assert!(load_rustls_config(&settings).unwrap().is_none());
+
+        settings.tls_key_path = Some("does-not-exist.pem".to_string());
+        assert_eq!(settings.tls_paths(), Some(("cert.pem", "does-not-exist.pem")));
+        assert!(load_rustls_config(&settings).is_err());
+    }
+}
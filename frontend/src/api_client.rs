@@ -12,16 +12,22 @@ use crate::models::{
     Volume, CreateVolumeRequest, UpdateVolumeRequest,
     IsbnLookupResponse, BorrowerGroup, CreateBorrowerGroupRequest, UpdateBorrowerGroupRequest,
     BorrowerWithGroup, CreateBorrowerRequest, UpdateBorrowerRequest,
-    LoanDetail, CreateLoanRequest, CreateLoanResponse,
+    LoanDetail, CreateLoanRequest, CreateLoanResponse, MyLoansResponse,
     LibraryStatistics, GenreStatistic, LocationStatistic, LoanStatistic,
-    DuplicateDetectionResponse, MergeTitlesRequest, MergeTitlesResponse
+    DuplicateDetectionResponse, MergeTitlesRequest, MergeTitlesResponse,
+    LoginRequest, LoginResponse, User, CustomField, CustomFieldScope
 };
+use std::cell::RefCell;
 use std::error::Error;
 
 /// API client for communicating with the rbibli backend
+///
+/// Every `/api/v1` call carries the session token obtained from `login` as an
+/// `Authorization: Bearer` header.
 pub struct ApiClient {
     base_url: String,
     client: reqwest::Client,
+    token: RefCell<Option<String>>,
 }
 
 impl ApiClient {
@@ -54,8 +60,43 @@ impl ApiClient {
         Self {
             base_url,
             client: reqwest::Client::new(),
+            token: RefCell::new(None),
         }
     }
+
+    /// Sets or clears the session token sent with every request.
+    pub fn set_token(&self, token: Option<String>) {
+        *self.token.borrow_mut() = token;
+    }
+
+    /// Returns the current session token, if logged in.
+    pub fn token(&self) -> Option<String> {
+        self.token.borrow().clone()
+    }
+
+    /// Adds the session token, if any, to a request.
+    fn authorize(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.token.borrow().as_deref() {
+            Some(token) => builder.bearer_auth(token),
+            None => builder,
+        }
+    }
+
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        self.authorize(self.client.get(url))
+    }
+
+    fn post(&self, url: &str) -> reqwest::RequestBuilder {
+        self.authorize(self.client.post(url))
+    }
+
+    fn put(&self, url: &str) -> reqwest::RequestBuilder {
+        self.authorize(self.client.put(url))
+    }
+
+    fn delete(&self, url: &str) -> reqwest::RequestBuilder {
+        self.authorize(self.client.delete(url))
+    }
 }
 
 impl Default for ApiClient {
@@ -106,7 +147,7 @@ impl ApiClient {
 
         println!("Fetching titles from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -138,7 +179,8 @@ impl ApiClient {
     /// * `year_to` - Maximum publication year (optional)
     /// * `language` - Filter by language code (optional)
     /// * `dewey_code` - Filter by Dewey classification (optional)
-    /// * `has_volumes` - Filter for owned books (true), wishlist (false), or all (None)
+    /// * `has_volumes` - Filter for owned books (true), books without volumes (false), or all (None)
+    /// * `wishlist` - Filter for titles on the wishlist (true), not on it (false), or all (None)
     /// * `available_only` - Filter for books with available volumes (optional)
     /// * `location_id` - Filter by storage location UUID (optional)
     ///
@@ -155,13 +197,13 @@ impl ApiClient {
     /// let client = ApiClient::default();
     ///
     /// // Simple text search
-    /// match client.search_titles(Some("harry potter"), None, None, None, None, None, None, None, None, None, None, None, None, None, None) {
+    /// match client.search_titles(Some("harry potter"), None, None, None, None, None, None, None, None, None, None, None, None, None, None, None) {
     ///     Ok(titles) => println!("Found {} titles", titles.len()),
     ///     Err(e) => eprintln!("Search failed: {}", e),
     /// }
     ///
     /// // Search by genre and year range
-    /// match client.search_titles(None, None, None, None, None, None, Some("genre-uuid"), None, Some("2000"), Some("2023"), None, None, None, None, None) {
+    /// match client.search_titles(None, None, None, None, None, None, Some("genre-uuid"), None, Some("2000"), Some("2023"), None, None, None, None, None, None) {
     ///     Ok(titles) => println!("Found {} titles", titles.len()),
     ///     Err(e) => eprintln!("Search failed: {}", e),
     /// }
@@ -181,6 +223,7 @@ impl ApiClient {
         language: Option<&str>,
         dewey_code: Option<&str>,
         has_volumes: Option<bool>,
+        wishlist: Option<bool>,
         available_only: Option<bool>,
         location_id: Option<&str>,
     ) -> Result<Vec<TitleWithCount>, Box<dyn Error>> {
@@ -211,6 +254,8 @@ impl ApiClient {
         if let Some(sid) = series_id {
             if !sid.is_empty() {
                 params.push(format!("series_id={}", urlencoding::encode(sid)));
+                // Show the titles of a series in numbering order
+                params.push("sort_by=series_number".to_string());
             }
         }
         if let Some(aid) = author_id {
@@ -251,6 +296,9 @@ impl ApiClient {
         if let Some(has_vols) = has_volumes {
             params.push(format!("has_volumes={}", has_vols));
         }
+        if let Some(wished) = wishlist {
+            params.push(format!("wishlist={}", wished));
+        }
         if let Some(available) = available_only {
             if available {
                 params.push("available=true".to_string());
@@ -267,7 +315,7 @@ impl ApiClient {
 
         println!("Searching titles with URL: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -310,7 +358,7 @@ impl ApiClient {
 
         println!("Detecting duplicates: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -364,7 +412,7 @@ impl ApiClient {
 
         let request_body = MergeTitlesRequest { confirm: true };
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request_body)
             .send()
@@ -399,7 +447,7 @@ impl ApiClient {
     ///   - `publication_year`: Optional publication year
     ///   - `pages`: Optional page count
     ///   - `language`: Language code (required)
-    ///   - `genre_id`: Optional genre UUID
+    ///   - `genre_ids`: Genre UUIDs, primary genre first
     ///   - `summary`: Optional book summary/description
     ///
     /// # Returns
@@ -426,9 +474,10 @@ impl ApiClient {
     ///     pages: Some(552),
     ///     language: "en".to_string(),
     ///     dewey_code: None,
-    ///     genre_id: None,
+    ///     genre_ids: Vec::new(),
     ///     summary: Some("The official book on Rust".to_string()),
     ///     cover_url: None,
+    ///     custom_fields: Default::default(),
     /// };
     ///
     /// match client.create_title(request) {
@@ -441,7 +490,7 @@ impl ApiClient {
 
         println!("Creating title: {}", request.title);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -506,7 +555,7 @@ impl ApiClient {
 
         println!("Updating title: {}", title_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -566,7 +615,7 @@ impl ApiClient {
 
         println!("Deleting title: {}", title_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -596,7 +645,7 @@ impl ApiClient {
 
         println!("Fetching authors for title: {}", title_id);
 
-        let response = self.client
+        let response = self
             .get(&url)
             .send()
             .await?;
@@ -628,7 +677,7 @@ impl ApiClient {
 
         println!("Adding author {} to title {} with role {:?}", request.author_id, title_id, request.role);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -665,7 +714,7 @@ impl ApiClient {
 
         println!("Removing author {} from title {}", author_id, title_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -717,7 +766,7 @@ impl ApiClient {
 
         println!("Fetching locations from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -780,7 +829,7 @@ impl ApiClient {
 
         println!("Creating location: {}", request.name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -842,7 +891,7 @@ impl ApiClient {
 
         println!("Updating location: {}", location_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -899,7 +948,7 @@ impl ApiClient {
 
         println!("Deleting location: {}", location_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -951,7 +1000,7 @@ impl ApiClient {
 
         println!("Fetching authors from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -1015,7 +1064,7 @@ impl ApiClient {
 
         println!("Creating author: {} {}", request.first_name, request.last_name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -1081,7 +1130,7 @@ impl ApiClient {
 
         println!("Updating author: {}", author_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -1136,7 +1185,7 @@ impl ApiClient {
 
         println!("Deleting author: {}", author_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -1187,7 +1236,7 @@ impl ApiClient {
 
         println!("Fetching publishers from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -1246,7 +1295,7 @@ impl ApiClient {
 
         println!("Creating publisher: {}", request.name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -1310,7 +1359,7 @@ impl ApiClient {
 
         println!("Updating publisher: {}", publisher_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -1365,7 +1414,7 @@ impl ApiClient {
 
         println!("Deleting publisher: {}", publisher_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -1416,7 +1465,7 @@ impl ApiClient {
 
         println!("Fetching genres from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -1429,6 +1478,34 @@ impl ApiClient {
         Ok(genres)
     }
 
+    /// Fetches the custom field definitions of a scope.
+    ///
+    /// This method makes a GET request to `/api/v1/custom-fields?scope=...` to
+    /// retrieve the fields administrators defined for titles or volumes. The
+    /// titles form renders one input per title field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<CustomField>)` - The field definitions, sorted by name
+    /// * `Err(Box<dyn Error>)` - An error if the request fails or the response cannot be parsed
+    pub async fn get_custom_fields(&self, scope: CustomFieldScope) -> Result<Vec<CustomField>, Box<dyn Error>> {
+        let url = format!("{}/api/v1/custom-fields?scope={}", self.base_url, scope);
+
+        println!("Fetching custom fields from: {}", url);
+
+        let response = self.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(format!("API returned status: {}", response.status()).into());
+        }
+
+        let fields: Vec<CustomField> = response.json().await?;
+
+        println!("Successfully fetched {} custom fields", fields.len());
+
+        Ok(fields)
+    }
+
     /// Creates a new genre/category in the library database.
     ///
     /// This method makes a POST request to `/api/v1/genres` to add a new book
@@ -1458,6 +1535,7 @@ impl ApiClient {
     /// let request = CreateGenreRequest {
     ///     name: "Science Fiction".to_string(),
     ///     description: Some("Speculative fiction based on scientific concepts".to_string()),
+    ///     parent_id: None,
     /// };
     ///
     /// match client.create_genre(request) {
@@ -1470,7 +1548,7 @@ impl ApiClient {
 
         println!("Creating genre: {}", request.name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -1521,6 +1599,7 @@ impl ApiClient {
     /// let request = UpdateGenreRequest {
     ///     name: Some("Sci-Fi".to_string()),
     ///     description: Some("Science Fiction and Fantasy".to_string()),
+    ///     parent_id: None,
     /// };
     ///
     /// match client.update_genre("123e4567-e89b-12d3-a456-426614174000", request) {
@@ -1533,7 +1612,7 @@ impl ApiClient {
 
         println!("Updating genre: {}", genre_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -1588,7 +1667,7 @@ impl ApiClient {
 
         println!("Deleting genre: {}", genre_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -1641,7 +1720,7 @@ impl ApiClient {
 
         println!("Fetching series from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -1682,6 +1761,9 @@ impl ApiClient {
     /// let request = CreateSeriesRequest {
     ///     name: "The Lord of the Rings".to_string(),
     ///     description: Some("High fantasy novel".to_string()),
+    ///     parent_id: None,
+    ///     total_volumes: None,
+    ///     reading_order: None,
     /// };
     ///
     /// match client.create_series(request) {
@@ -1694,7 +1776,7 @@ impl ApiClient {
 
         println!("Creating series: {}", request.name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -1744,6 +1826,9 @@ impl ApiClient {
     /// let request = UpdateSeriesRequest {
     ///     name: None,
     ///     description: Some("Updated description".to_string()),
+    ///     parent_id: None,
+    ///     total_volumes: None,
+    ///     reading_order: None,
     /// };
     ///
     /// match client.update_series("123e4567-e89b-12d3-a456-426614174000", request) {
@@ -1756,7 +1841,7 @@ impl ApiClient {
 
         println!("Updating series: {}", series_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -1811,7 +1896,7 @@ impl ApiClient {
 
         println!("Deleting series: {}", series_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -1870,7 +1955,7 @@ impl ApiClient {
 
         println!("Fetching volumes for title: {}", title_id);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -1918,6 +2003,7 @@ impl ApiClient {
     ///     condition: VolumeCondition::Good,
     ///     location_id: None,
     ///     individual_notes: Some("Signed by author".to_string()),
+    ///     custom_fields: Default::default(),
     /// };
     ///
     /// match client.create_volume(request) {
@@ -1930,7 +2016,7 @@ impl ApiClient {
 
         println!("Creating volume with barcode: {}", request.barcode);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(&request)
             .send()
@@ -1984,7 +2070,7 @@ impl ApiClient {
 
         println!("Updating volume: {}", volume_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(&request)
             .send()
@@ -2038,7 +2124,7 @@ impl ApiClient {
 
         println!("Deleting volume: {}", volume_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -2101,7 +2187,7 @@ impl ApiClient {
             .part("cover", part);
 
         // Send the request
-        let response = self.client.post(&url).multipart(form).send().await?;
+        let response = self.post(&url).multipart(form).send().await?;
 
         if response.status().is_success() {
             Ok(())
@@ -2138,7 +2224,7 @@ impl ApiClient {
     pub async fn lookup_isbn(&self, isbn: String) -> Result<IsbnLookupResponse, Box<dyn Error>> {
         let url = format!("{}/api/v1/isbn/{}", self.base_url, isbn);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if response.status().is_success() {
             let book_data: IsbnLookupResponse = response.json().await?;
@@ -2162,7 +2248,7 @@ impl ApiClient {
 
         println!("Fetching borrower groups from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -2189,7 +2275,7 @@ impl ApiClient {
 
         println!("Creating borrower group: {}", request.name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(request)
             .send()
@@ -2223,7 +2309,7 @@ impl ApiClient {
 
         println!("Updating borrower group: {}", group_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(request)
             .send()
@@ -2253,7 +2339,7 @@ impl ApiClient {
 
         println!("Deleting borrower group: {}", group_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -2280,7 +2366,7 @@ impl ApiClient {
 
         println!("Fetching borrowers from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -2307,7 +2393,7 @@ impl ApiClient {
 
         println!("Creating borrower: {}", request.name);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(request)
             .send()
@@ -2341,7 +2427,7 @@ impl ApiClient {
 
         println!("Updating borrower: {}", borrower_id);
 
-        let response = self.client
+        let response = self
             .put(&url)
             .json(request)
             .send()
@@ -2371,7 +2457,7 @@ impl ApiClient {
 
         println!("Deleting borrower: {}", borrower_id);
 
-        let response = self.client
+        let response = self
             .delete(&url)
             .send()
             .await?;
@@ -2398,7 +2484,7 @@ impl ApiClient {
 
         println!("Fetching active loans from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -2421,7 +2507,7 @@ impl ApiClient {
 
         println!("Fetching overdue loans from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get(&url).send().await?;
 
         if !response.status().is_success() {
             return Err(format!("API returned status: {}", response.status()).into());
@@ -2459,7 +2545,7 @@ impl ApiClient {
 
         println!("Creating loan for borrower: {}, barcode: {}", request.borrower_id, request.barcode);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .json(request)
             .send()
@@ -2497,7 +2583,7 @@ impl ApiClient {
 
         println!("Returning loan: {}", loan_id);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .send()
             .await?;
@@ -2517,7 +2603,7 @@ impl ApiClient {
 
         println!("Extending loan: {}", loan_id);
 
-        let response = self.client
+        let response = self
             .post(&url)
             .send()
             .await?;
@@ -2536,6 +2622,52 @@ impl ApiClient {
             .ok_or_else(|| "Extended loan not found in active loans".into())
     }
 
+    /// Fetches the loans of the borrower linked to the logged-in account.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MyLoansResponse)` - Active and past loans of the linked borrower
+    /// * `Err` - If the account is not linked to a borrower or the request fails
+    pub async fn get_my_loans(&self) -> Result<MyLoansResponse, Box<dyn Error>> {
+        let url = format!("{}/api/v1/me/loans", self.base_url);
+
+        let response = self.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            return Err(format!("Failed to fetch my loans: {}", error_text).into());
+        }
+
+        let my_loans: MyLoansResponse = response.json().await?;
+        Ok(my_loans)
+    }
+
+    /// Extends one of the logged-in borrower's own loans.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_id` - The ID of the loan to extend
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Loan was extended
+    /// * `Err` - If the loan is not theirs, was already extended, or the request fails
+    pub async fn extend_my_loan(&self, loan_id: &str) -> Result<(), Box<dyn Error>> {
+        let url = format!("{}/api/v1/me/loans/{}/extend", self.base_url, loan_id);
+
+        println!("Extending my loan: {}", loan_id);
+
+        let response = self.post(&url).send().await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            return Err(format!("Failed to extend loan: {}", error_text).into());
+        }
+
+        println!("Successfully extended my loan: {}", loan_id);
+        Ok(())
+    }
+
 
 
     // ========================================================================
@@ -2567,7 +2699,7 @@ impl ApiClient {
 
         println!("Fetching library statistics...");
 
-        let response = self.client
+        let response = self
             .get(&url)
             .send()
             .await?;
@@ -2609,7 +2741,7 @@ impl ApiClient {
 
         println!("Fetching genre statistics...");
 
-        let response = self.client
+        let response = self
             .get(&url)
             .send()
             .await?;
@@ -2651,7 +2783,7 @@ impl ApiClient {
 
         println!("Fetching location statistics...");
 
-        let response = self.client
+        let response = self
             .get(&url)
             .send()
             .await?;
@@ -2693,7 +2825,7 @@ impl ApiClient {
 
         println!("Fetching loan statistics...");
 
-        let response = self.client
+        let response = self
             .get(&url)
             .send()
             .await?;
@@ -2707,6 +2839,82 @@ impl ApiClient {
         println!("Found {} loan status types", stats.len());
         Ok(stats)
     }
-}
 
+    /// Logs in and stores the returned session token for subsequent requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Account name
+    /// * `password` - Account password
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LoginResponse)` - Token, expiration and user on success
+    /// * `Err` - If the credentials are rejected or the request fails
+    pub async fn login(&self, username: &str, password: &str) -> Result<LoginResponse, Box<dyn Error>> {
+        let url = format!("{}/api/v1/auth/login", self.base_url);
+
+        println!("Logging in as: {}", username);
 
+        let request = LoginRequest {
+            username: username.to_string(),
+            password: password.to_string(),
+        };
+
+        let response = self.client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Login failed: {} - {}", status, error_text).into());
+        }
+
+        let login: LoginResponse = response.json().await?;
+        self.set_token(Some(login.token.clone()));
+        println!("Logged in as: {}", login.user.username);
+        Ok(login)
+    }
+
+    /// Logs out by closing the session on the server and forgetting the token.
+    ///
+    /// The local token is cleared even if the server call fails.
+    pub async fn logout(&self) -> Result<(), Box<dyn Error>> {
+        let url = format!("{}/api/v1/auth/logout", self.base_url);
+
+        println!("Logging out...");
+
+        let result = self.post(&url).send().await;
+        self.set_token(None);
+        let response = result?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            return Err(format!("Failed to log out: {}", error_text).into());
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the user owning the current session.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(User)` - The logged-in user
+    /// * `Err` - If there is no valid session or the request fails
+    pub async fn get_current_user(&self) -> Result<User, Box<dyn Error>> {
+        let url = format!("{}/api/v1/auth/me", self.base_url);
+
+        let response = self.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(format!("API returned status: {}", response.status()).into());
+        }
+
+        let user: User = response.json().await?;
+        Ok(user)
+    }
+}
@@ -7,6 +7,7 @@
 // Prevent console window in addition to Slint window in Windows release builds when, e.g., starting the app via file manager. Ignored on other platforms.
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::rc::Rc;
 
@@ -20,7 +21,7 @@ use models::{
     CreateAuthorRequest, UpdateAuthorRequest, CreatePublisherRequest, UpdatePublisherRequest,
     CreateGenreRequest, UpdateGenreRequest, CreateSeriesRequest, UpdateSeriesRequest,
     CreateBorrowerGroupRequest, UpdateBorrowerGroupRequest, CreateBorrowerRequest,
-    UpdateBorrowerRequest, CreateLoanRequest
+    UpdateBorrowerRequest, CreateLoanRequest, LoanDetail, CustomFieldScope
 };
 use slint::{Model, ComponentHandle};
 
@@ -96,6 +97,8 @@ slint::include_modules!();
 #[derive(Serialize, Deserialize, Default)]
 struct AppConfig {
     language: String,
+    #[serde(default)]
+    session_token: Option<String>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -134,6 +137,81 @@ fn save_config(config: &AppConfig) {
     }
 }
 
+/// Returns the session token saved by a previous login, if any.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_session_token() -> Option<String> {
+    load_config().session_token
+}
+
+/// Returns the session token saved by a previous login, if any.
+#[cfg(target_arch = "wasm32")]
+fn load_session_token() -> Option<String> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item("rbibli_session_token").ok().flatten())
+        .filter(|token| !token.is_empty())
+}
+
+/// Saves (or clears, with `None`) the session token so the next start stays logged in.
+#[cfg(not(target_arch = "wasm32"))]
+fn save_session_token(token: Option<&str>) {
+    let mut config = load_config();
+    config.session_token = token.map(str::to_string);
+    save_config(&config);
+}
+
+/// Saves (or clears, with `None`) the session token so the next start stays logged in.
+#[cfg(target_arch = "wasm32")]
+fn save_session_token(token: Option<&str>) {
+    if let Some(window) = web_sys::window() {
+        if let Ok(Some(storage)) = window.local_storage() {
+            let _ = match token {
+                Some(token) => storage.set_item("rbibli_session_token", token),
+                None => storage.remove_item("rbibli_session_token"),
+            };
+        }
+    }
+}
+
+/// Builds the custom field entries of one title: every defined field, with the title's value or empty.
+fn custom_field_entries(
+    definitions: &slint::ModelRc<CustomFieldEntry>,
+    values: &BTreeMap<String, String>,
+) -> slint::ModelRc<CustomFieldEntry> {
+    let entries: Vec<CustomFieldEntry> = definitions
+        .iter()
+        .map(|mut entry| {
+            entry.value = values.get(entry.field_id.as_str()).cloned().unwrap_or_default().into();
+            entry
+        })
+        .collect();
+    Rc::new(slint::VecModel::from(entries)).into()
+}
+
+/// Fills the title language dropdown with the ISO 639 catalogue, named and sorted in the interface language.
+fn set_language_items(ui: &AppWindow, locale: &str) {
+    let mut items: Vec<LanguageItem> = shared::languages::LANGUAGES
+        .iter()
+        .map(|language| LanguageItem {
+            code: language.code.into(),
+            name: format!("{} ({})", language.name(locale), language.code).into(),
+        })
+        .collect();
+    items.sort_by_key(|item| item.name.to_lowercase());
+
+    let names: Vec<slint::SharedString> = items.iter().map(|item| item.name.clone()).collect();
+    ui.set_language_items(Rc::new(slint::VecModel::from(items)).into());
+    ui.set_language_names(Rc::new(slint::VecModel::from(names)).into());
+}
+
+/// Collects the values of a title form's custom fields, keyed by field ID.
+fn custom_field_values(entries: &slint::ModelRc<CustomFieldEntry>) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|entry| (entry.field_id.to_string(), entry.value.to_string()))
+        .collect()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
@@ -159,8 +237,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
     // Set application version from Cargo.toml
     ui.set_app_version(env!("CARGO_PKG_VERSION").into());
 
-    // Create API client
+    // Create API client, reusing the session of a previous login if any
     let api_client = Rc::new(ApiClient::default());
+    api_client.set_token(load_session_token());
 
     // Restore saved language (WASM only)
     #[cfg(target_arch = "wasm32")]
@@ -233,6 +312,8 @@ async fn run() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    set_language_items(&ui, ui.get_current_language().as_str());
+
     // Handle language change
     let ui_handle = ui.as_weak();
     ui.on_change_language(move |lang_code| {
@@ -310,6 +391,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
 
         // Update the UI property to reflect the change
         if let Some(ui) = ui_handle.upgrade() {
+            set_language_items(&ui, lang_code.as_str());
             ui.set_current_language(lang_code);
         }
     });
@@ -329,6 +411,30 @@ async fn run() -> Result<(), Box<dyn Error>> {
             slint::spawn_local(async move {
                 println!("Loading titles from backend...");
 
+                // Custom field definitions drive the dynamic part of the title forms
+                let definitions: Vec<CustomFieldEntry> = match api_client.get_custom_fields(CustomFieldScope::Title).await {
+                    Ok(fields) => fields
+                        .into_iter()
+                        .map(|field| CustomFieldEntry {
+                            field_id: field.id.to_string().into(),
+                            name: field.name.into(),
+                            field_type: field.field_type.to_string().into(),
+                            options: Rc::new(slint::VecModel::from(
+                                field.options.into_iter().map(Into::into).collect::<Vec<slint::SharedString>>()
+                            )).into(),
+                            value: "".into(),
+                        })
+                        .collect(),
+                    Err(e) => {
+                        eprintln!("Failed to load custom fields: {}", e);
+                        Vec::new()
+                    }
+                };
+                let definitions: slint::ModelRc<CustomFieldEntry> = Rc::new(slint::VecModel::from(definitions)).into();
+                if let Some(ui) = ui_weak.upgrade() {
+                    ui.set_custom_field_definitions(definitions.clone());
+                }
+
                 match api_client.get_titles().await {
                     Ok(titles_data) => {
                         println!("Successfully fetched {} titles", titles_data.len());
@@ -355,6 +461,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                                 dewey_code: t.title.dewey_code.clone().unwrap_or_default().into(),
                                 summary: t.title.summary.clone().unwrap_or_default().into(),
                                 cover_url: t.title.cover_url.clone().unwrap_or_default().into(),
+                                custom_fields: custom_field_entries(&definitions, &t.title.custom_fields),
                                 // Duplicate detection fields (initially false/empty)
                                 is_duplicate: false,
                                 duplicate_pair_id: "".into(),
@@ -709,14 +816,13 @@ async fn run() -> Result<(), Box<dyn Error>> {
                 println!("  series_id: {}", series_id);
                 println!("  has_volumes: {}, wishlist_only: {}", has_volumes, wishlist_only);
 
-                // Convert has_volumes and wishlist_only to the appropriate filter value
-                let has_volumes_filter = if wishlist_only {
-                    Some(false)  // Wishlist = books without volumes
-                } else if has_volumes {
+                // Convert has_volumes and wishlist_only to the appropriate filter values
+                let has_volumes_filter = if has_volumes && !wishlist_only {
                     Some(true)   // Owned = books with volumes
                 } else {
                     None         // Show all
                 };
+                let wishlist_filter = if wishlist_only { Some(true) } else { None };
 
                 // Convert available_only bool to Option<bool>
                 let available_filter = if available_only {
@@ -739,12 +845,18 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     if language.is_empty() { None } else { Some(language.as_str()) },
                     if dewey_code.is_empty() { None } else { Some(dewey_code.as_str()) },
                     has_volumes_filter,
+                    wishlist_filter,
                     available_filter,
                     if location_id.is_empty() { None } else { Some(location_id.as_str()) },
                 ).await {
                     Ok(titles) => {
                         println!("Search returned {} titles", titles.len());
 
+                        let definitions = match ui_weak.upgrade() {
+                            Some(ui) => ui.get_custom_field_definitions(),
+                            None => return,
+                        };
+
                         // Convert backend titles to Slint TitleData format
                         let slint_titles: Vec<TitleData> = titles
                             .into_iter()
@@ -768,6 +880,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                                     dewey_code: t.title.dewey_code.unwrap_or_default().into(),
                                     summary: t.title.summary.unwrap_or_default().into(),
                                     cover_url: t.title.cover_url.unwrap_or_default().into(),
+                                    custom_fields: custom_field_entries(&definitions, &t.title.custom_fields),
                                     // Duplicate detection fields (initially false/empty)
                                     is_duplicate: false,
                                     duplicate_pair_id: "".into(),
@@ -1490,6 +1603,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(description.to_string())
                     },
+                    parent_id: None,
                 };
 
                 match api_client.create_genre(request).await {
@@ -1533,6 +1647,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(description.to_string())
                     },
+                    parent_id: None,
                 };
 
                 match api_client.update_genre(&id.to_string(), request).await {
@@ -1609,19 +1724,20 @@ async fn run() -> Result<(), Box<dyn Error>> {
                             })
                             .collect();
 
-                        // Convert to SeriesItem for dropdown usage in TitlesPage
+                        // Convert to SeriesItem for dropdown usage in TitlesPage; the full
+                        // path tells sub-series of different universes apart
                         let series_items: Vec<SeriesItem> = series_data
                             .iter()
                             .map(|s| SeriesItem {
                                 id: s.series.id.to_string().into(),
-                                name: s.series.name.clone().into(),
+                                name: s.full_path.clone().into(),
                             })
                             .collect();
 
-                        // Extract series names for ComboBox model
+                        // Extract series paths for ComboBox model
                         let series_names: Vec<slint::SharedString> = series_data
                             .iter()
-                            .map(|s| s.series.name.clone().into())
+                            .map(|s| s.full_path.clone().into())
                             .collect();
 
                         // Update the UI with the series
@@ -1676,6 +1792,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(description.to_string())
                     },
+                    parent_id: None,
+                    total_volumes: None,
+                    reading_order: None,
                 };
 
                 match api_client.create_series(request).await {
@@ -1719,6 +1838,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(description.to_string())
                     },
+                    parent_id: None,
+                    total_volumes: None,
+                    reading_order: None,
                 };
 
                 match api_client.update_series(&id.to_string(), request).await {
@@ -1762,6 +1884,19 @@ async fn run() -> Result<(), Box<dyn Error>> {
         });
     }
 
+    // Connect the find-language-index callback
+    {
+        let ui_weak = ui.as_weak();
+        ui.on_find_language_index(move |code| {
+            if let Some(ui) = ui_weak.upgrade() {
+                if let Some(i) = ui.get_language_items().iter().position(|item| item.code == code) {
+                    return i as i32;
+                }
+            }
+            -1
+        });
+    }
+
     // Connect the find-genre-index callback
     {
         let ui_weak = ui.as_weak();
@@ -1811,6 +1946,14 @@ async fn run() -> Result<(), Box<dyn Error>> {
         });
     }
 
+    // Connect the clone-custom-fields callback
+    //
+    // Slint arrays are shared models, so the title forms edit a copy of the
+    // definitions (or of a title's entries) rather than the original list.
+    ui.on_clone_custom_fields(|entries| {
+        Rc::new(slint::VecModel::from(entries.iter().collect::<Vec<CustomFieldEntry>>())).into()
+    });
+
     // Connect the find-series-index callback
     {
         let ui_weak = ui.as_weak();
@@ -1835,7 +1978,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
     {
         let load_titles = load_titles.clone();
         let api_client = api_client.clone();
-        ui.on_create_title(move |title, subtitle, isbn, publisher, publisher_id, publication_year, pages, language, genre_id, series_id, series_number, summary, cover_url, dewey_code| {
+        ui.on_create_title(move |title, subtitle, isbn, publisher, publisher_id, publication_year, pages, language, genre_id, series_id, series_number, summary, cover_url, dewey_code, custom_fields| {
             let ui_weak = ui_weak.clone();
             let load_titles = load_titles.clone();
             let api_client = api_client.clone();
@@ -1853,10 +1996,22 @@ async fn run() -> Result<(), Box<dyn Error>> {
             let summary = summary.clone();
             let cover_url = cover_url.clone();
             let dewey_code = dewey_code.clone();
+            let custom_fields = custom_field_values(&custom_fields);
 
             slint::spawn_local(async move {
                 println!("Creating title: {}", title);
 
+                // Reject ISBN typos before they reach the backend
+                if !isbn.is_empty() {
+                    if let Err(e) = shared::isbn::Isbn::parse(&isbn) {
+                        eprintln!("Invalid ISBN {}: {}", isbn, e);
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_title_save_error(format!("Invalid ISBN {}: {}", isbn, e).into());
+                        }
+                        return;
+                    }
+                }
+
                 let request = CreateTitleRequest {
                     title: title.to_string(),
                     subtitle: if subtitle.is_empty() {
@@ -1895,10 +2050,10 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(dewey_code.to_string())
                     },
-                    genre_id: if genre_id.is_empty() {
-                        None
+                    genre_ids: if genre_id.is_empty() {
+                        Vec::new()
                     } else {
-                        Some(genre_id.to_string())
+                        vec![genre_id.to_string()]
                     },
                     series_id: if series_id.is_empty() {
                         None
@@ -1920,6 +2075,10 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(cover_url.to_string())
                     },
+                    original_title: None,
+                    original_language: None,
+                    translation_notes: None,
+                    custom_fields,
                 };
 
                 match api_client.create_title(request).await {
@@ -1932,6 +2091,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     }
                     Err(e) => {
                         eprintln!("Failed to create title: {}", e);
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_title_save_error(format!("Error: {}", e).into());
+                        }
                     }
                 }
             }).unwrap();
@@ -1946,7 +2108,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
     {
         let load_titles = load_titles.clone();
         let api_client = api_client.clone();
-        ui.on_update_title(move |id, title, subtitle, isbn, publisher, publisher_id, publication_year, pages, language, genre_id, series_id, series_number, summary, cover_url, dewey_code| {
+        let ui_weak = ui.as_weak();
+        ui.on_update_title(move |id, title, subtitle, isbn, publisher, publisher_id, publication_year, pages, language, genre_id, series_id, series_number, summary, cover_url, dewey_code, custom_fields| {
+            let ui_weak = ui_weak.clone();
             let load_titles = load_titles.clone();
             let api_client = api_client.clone();
             let id = id.clone();
@@ -1964,10 +2128,23 @@ async fn run() -> Result<(), Box<dyn Error>> {
             let summary = summary.clone();
             let cover_url = cover_url.clone();
             let dewey_code = dewey_code.clone();
+            // Blank values clear the field on the backend
+            let custom_fields = custom_field_values(&custom_fields);
 
             slint::spawn_local(async move {
                 println!("Updating title: {}", id);
 
+                // Reject ISBN typos before they reach the backend
+                if !isbn.is_empty() {
+                    if let Err(e) = shared::isbn::Isbn::parse(&isbn) {
+                        eprintln!("Invalid ISBN {}: {}", isbn, e);
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_title_save_error(format!("Invalid ISBN {}: {}", isbn, e).into());
+                        }
+                        return;
+                    }
+                }
+
                 let request = UpdateTitleRequest {
                     title: if title.is_empty() {
                         None
@@ -2014,7 +2191,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(dewey_code.to_string())
                     },
-                    genre_id: if genre_id.is_empty() {
+                    // Only the primary genre is edited here; secondary genres are kept
+                    genre_ids: None,
+                    primary_genre_id: if genre_id.is_empty() {
                         None
                     } else {
                         Some(genre_id.to_string())
@@ -2039,6 +2218,10 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     } else {
                         Some(cover_url.to_string())
                     },
+                    original_title: None,
+                    original_language: None,
+                    translation_notes: None,
+                    custom_fields,
                 };
 
                 match api_client.update_title(&id.to_string(), request).await {
@@ -2048,6 +2231,9 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     }
                     Err(e) => {
                         eprintln!("Failed to update title: {}", e);
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_title_save_error(format!("Error: {}", e).into());
+                        }
                     }
                 }
             }).unwrap();
@@ -2194,6 +2380,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     condition: condition_enum,
                     location_id: if location_id.is_empty() { None } else { Some(location_id.to_string()) },
                     individual_notes: if notes.is_empty() { None } else { Some(notes.to_string()) },
+                    custom_fields: Default::default(),
                 };
 
                 match api_client.create_volume(request).await {
@@ -2255,6 +2442,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     location_id: if location_id.is_empty() { None } else { Some(location_id.to_string()) },
                     loan_status: None,
                     individual_notes: if notes.is_empty() { None } else { Some(notes.to_string()) },
+                    custom_fields: Default::default(),
                 };
 
                 match api_client.update_volume(&id.to_string(), request).await {
@@ -2544,6 +2732,11 @@ async fn run() -> Result<(), Box<dyn Error>> {
             slint::spawn_local(async move {
                 println!("Fetching book data from ISBN (create mode): {}", isbn);
 
+                if let Err(e) = shared::isbn::Isbn::parse(&isbn) {
+                    eprintln!("Invalid ISBN {}: {}", isbn, e);
+                    return;
+                }
+
                 match api_client.lookup_isbn(isbn.to_string()).await {
                     Ok(book_data) => {
                         println!("Successfully fetched book data: {}", book_data.title);
@@ -2604,6 +2797,11 @@ async fn run() -> Result<(), Box<dyn Error>> {
             slint::spawn_local(async move {
                 println!("Fetching book data from ISBN (edit mode): {}", isbn);
 
+                if let Err(e) = shared::isbn::Isbn::parse(&isbn) {
+                    eprintln!("Invalid ISBN {}: {}", isbn, e);
+                    return;
+                }
+
                 match api_client.lookup_isbn(isbn.to_string()).await {
                     Ok(book_data) => {
                         println!("Successfully fetched book data: {}", book_data.title);
@@ -3189,6 +3387,105 @@ async fn run() -> Result<(), Box<dyn Error>> {
         });
     }
 
+    // ========================================================================
+    // My Loans (borrower portal) callbacks
+    // ========================================================================
+
+    // Function to load the loans of the borrower linked to the logged-in account
+    //
+    // On failure (typically an account without a linked borrower) the lists are
+    // cleared and the error is shown on the page instead.
+    let load_my_loans = {
+        let ui_weak = ui.as_weak();
+        let api_client = api_client.clone();
+
+        move || {
+            let ui_weak = ui_weak.clone();
+            let api_client = api_client.clone();
+
+            slint::spawn_local(async move {
+                println!("Loading my loans from backend...");
+
+                let to_slint = |l: &LoanDetail| MyLoanData {
+                    id: l.loan.id.to_string().into(),
+                    title: l.title.clone().into(),
+                    barcode: l.barcode.clone().into(),
+                    loan_date: l.loan.loan_date.format("%Y-%m-%d").to_string().into(),
+                    due_date: l.loan.due_date.format("%Y-%m-%d").to_string().into(),
+                    return_date: l.loan.return_date
+                        .map(|d| d.format("%Y-%m-%d").to_string())
+                        .unwrap_or_default()
+                        .into(),
+                    extension_count: l.loan.extension_count,
+                    is_overdue: l.is_overdue,
+                };
+
+                match api_client.get_my_loans().await {
+                    Ok(my_loans) => {
+                        println!("Successfully fetched {} active and {} past loans",
+                                 my_loans.active_loans.len(), my_loans.past_loans.len());
+
+                        if let Some(ui) = ui_weak.upgrade() {
+                            let active: Vec<MyLoanData> = my_loans.active_loans.iter().map(to_slint).collect();
+                            let past: Vec<MyLoanData> = my_loans.past_loans.iter().map(to_slint).collect();
+                            ui.set_my_borrower_name(my_loans.borrower_name.into());
+                            ui.set_my_active_loans(Rc::new(slint::VecModel::from(active)).into());
+                            ui.set_my_past_loans(Rc::new(slint::VecModel::from(past)).into());
+                            ui.set_my_loans_message("".into());
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to fetch my loans: {}", e);
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_my_borrower_name("".into());
+                            ui.set_my_active_loans(Rc::new(slint::VecModel::<MyLoanData>::default()).into());
+                            ui.set_my_past_loans(Rc::new(slint::VecModel::<MyLoanData>::default()).into());
+                            ui.set_my_loans_message(e.to_string().into());
+                        }
+                    }
+                }
+            }).unwrap();
+        }
+    };
+
+    // Connect load-my-loans callback
+    {
+        let load_my_loans = load_my_loans.clone();
+        ui.on_load_my_loans(move || {
+            load_my_loans();
+        });
+    }
+
+    // Handle extend-my-loan callback
+    //
+    // Extends one of the user's own loans and reloads the list, or shows the
+    // backend error (e.g. extension limit reached) on the page.
+    {
+        let load_my_loans = load_my_loans.clone();
+        let ui_weak = ui.as_weak();
+        let api_client = api_client.clone();
+        ui.on_extend_my_loan(move |loan_id| {
+            let load_my_loans = load_my_loans.clone();
+            let ui_weak = ui_weak.clone();
+            let api_client = api_client.clone();
+            let loan_id = loan_id.clone();
+
+            slint::spawn_local(async move {
+                match api_client.extend_my_loan(&loan_id.to_string()).await {
+                    Ok(()) => {
+                        load_my_loans();
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to extend my loan: {}", e);
+                        if let Some(ui) = ui_weak.upgrade() {
+                            ui.set_my_loans_message(e.to_string().into());
+                        }
+                    }
+                }
+            }).unwrap();
+        });
+    }
+
     // ========================================================================
     // Statistics callbacks
     // ========================================================================
@@ -3219,6 +3516,7 @@ async fn run() -> Result<(), Box<dyn Error>> {
                         if let Some(ui) = ui_handle.upgrade() {
                             ui.set_library_statistics(LibraryStatistics {
                                 total_titles: stats.total_titles as i32,
+                                total_works: stats.total_works as i32,
                                 total_volumes: stats.total_volumes as i32,
                                 total_authors: stats.total_authors as i32,
                                 total_publishers: stats.total_publishers as i32,
@@ -3240,8 +3538,10 @@ async fn run() -> Result<(), Box<dyn Error>> {
                     Ok(stats_data) => {
                         println!("Successfully fetched {} genre statistics", stats_data.len());
 
+                        // Root genres only: their counts include sub-genres
                         let slint_stats: Vec<GenreStatistic> = stats_data
                             .iter()
+                            .filter(|s| s.parent_id.is_none())
                             .map(|s| GenreStatistic {
                                 genre_id: s.genre_id.clone().unwrap_or_default().into(),
                                 genre_name: s.genre_name.clone().into(),
@@ -3320,6 +3620,113 @@ async fn run() -> Result<(), Box<dyn Error>> {
         });
     }
 
+    // ========================================================================
+    // Authentication callbacks
+    // ========================================================================
+
+    // Reloads every list after the session changed
+    let reload_all = {
+        let load_titles = load_titles.clone();
+        let load_locations = load_locations.clone();
+        let load_authors = load_authors.clone();
+        let load_publishers = load_publishers.clone();
+        let load_genres = load_genres.clone();
+        let load_series = load_series.clone();
+        let load_borrower_groups = load_borrower_groups.clone();
+        let load_borrowers = load_borrowers.clone();
+        let load_active_loans = load_active_loans.clone();
+        let load_statistics = load_statistics.clone();
+        move || {
+            load_titles();
+            load_locations();
+            load_authors();
+            load_publishers();
+            load_genres();
+            load_series();
+            load_borrower_groups();
+            load_borrowers();
+            load_active_loans();
+            load_statistics();
+        }
+    };
+
+    // Handle login callback
+    //
+    // Opens a session, remembers the token for the next start and reloads all
+    // data, which was rejected by the backend while logged out.
+    {
+        let ui_handle = ui.as_weak();
+        let api_client = api_client.clone();
+        let reload_all = reload_all.clone();
+        ui.on_login(move |username, password| {
+            let ui_handle = ui_handle.clone();
+            let api_client = api_client.clone();
+            let reload_all = reload_all.clone();
+
+            slint::spawn_local(async move {
+                match api_client.login(username.as_str(), password.as_str()).await {
+                    Ok(login) => {
+                        save_session_token(Some(&login.token));
+                        if let Some(ui) = ui_handle.upgrade() {
+                            ui.set_login_error("".into());
+                            ui.set_current_user_role(login.user.role.to_string().into());
+                            ui.set_current_username(login.user.username.into());
+                        }
+                        reload_all();
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to log in: {}", e);
+                        if let Some(ui) = ui_handle.upgrade() {
+                            ui.set_login_error("Invalid username or password".into());
+                        }
+                    }
+                }
+            }).unwrap();
+        });
+    }
+
+    // Handle logout callback
+    {
+        let ui_handle = ui.as_weak();
+        let api_client = api_client.clone();
+        ui.on_logout(move || {
+            let ui_handle = ui_handle.clone();
+            let api_client = api_client.clone();
+
+            slint::spawn_local(async move {
+                if let Err(e) = api_client.logout().await {
+                    eprintln!("Failed to log out: {}", e);
+                }
+                save_session_token(None);
+                if let Some(ui) = ui_handle.upgrade() {
+                    ui.set_current_username("".into());
+                    ui.set_current_user_role("".into());
+                }
+            }).unwrap();
+        });
+    }
+
+    // Check the restored session, if any
+    if api_client.token().is_some() {
+        let ui_handle = ui.as_weak();
+        let api_client = api_client.clone();
+        slint::spawn_local(async move {
+            match api_client.get_current_user().await {
+                Ok(user) => {
+                    if let Some(ui) = ui_handle.upgrade() {
+                        ui.set_current_user_role(user.role.to_string().into());
+                        ui.set_current_username(user.username.into());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Saved session is no longer valid: {}", e);
+                    api_client.set_token(None);
+                    save_session_token(None);
+                }
+            }
+        }).unwrap();
+    }
+
     // Load loan management data on startup
     load_borrower_groups();
     load_borrowers();
@@ -68,6 +68,10 @@ pub use shared::dtos::genres::CreateGenreRequest;
 
 pub use shared::dtos::genres::UpdateGenreRequest;
 
+pub use shared::models::custom_fields::CustomField;
+
+pub use shared::models::enums::CustomFieldScope;
+
 pub use shared::models::enums::VolumeCondition;
 
 pub use shared::models::enums::LoanStatus;
@@ -88,6 +92,11 @@ pub struct IsbnLookupResponse {
     pub publication_year: Option<i32>,
     pub pages: Option<i32>,
     pub language: Option<String>,
+    /// Original title and language when the edition is a translation
+    #[serde(default)]
+    pub original_title: Option<String>,
+    #[serde(default)]
+    pub original_language: Option<String>,
     pub isbn: String,
     pub summary: Option<String>,
     pub categories: Vec<String>,
@@ -130,6 +139,8 @@ pub use shared::dtos::loans::CreateLoanRequest;
 
 pub use shared::dtos::loans::CreateLoanResponse;
 
+pub use shared::dtos::loans::MyLoansResponse;
+
 
 // ============================================================================
 // Statistics Models
@@ -140,6 +151,9 @@ pub use shared::dtos::loans::CreateLoanResponse;
 pub struct GenreStatistic {
     pub genre_id: Option<String>,
     pub genre_name: String,
+    /// Parent genre; counts of a parent include its sub-genres
+    #[serde(default)]
+    pub parent_id: Option<String>,
     pub volume_count: i64,
     pub title_count: i64,
 }
@@ -164,6 +178,8 @@ pub struct LoanStatistic {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LibraryStatistics {
     pub total_titles: i64,
+    #[serde(default)]
+    pub total_works: i64,
     pub total_volumes: i64,
     pub total_authors: i64,
     pub total_publishers: i64,
@@ -200,3 +216,11 @@ pub struct DuplicateDetectionResponse {
 pub use shared::dtos::titles::MergeTitlesRequest;
 
 pub use shared::dtos::titles::MergeTitlesResponse;
+
+// ============================================================================
+// Authentication Models
+// ============================================================================
+
+pub use shared::models::users::User;
+
+pub use shared::dtos::users::{LoginRequest, LoginResponse};
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use crate::models::api_tokens::ApiToken;
+use crate::models::enums::ApiScope;
+
+/// Request payload for creating an API token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    pub scopes: Vec<ApiScope>,
+    /// Lifetime in days; the token never expires when omitted
+    pub expires_in_days: Option<i64>,
+}
+
+/// Response from creating an API token.
+///
+/// `token` is the only time the secret is returned; store it safely.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiTokenResponse {
+    pub token: String,
+    pub api_token: ApiToken,
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::titles::TitleSearchParams;
+use crate::models::enums::VolumeCondition;
+
+/// Changes applied to every title of a bulk edit. Fields left out are not touched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkTitlePatch {
+    /// New primary genre UUID; the other genres of each title are kept
+    pub genre_id: Option<String>,
+    /// Series UUID, or an empty string to take the titles out of their series
+    pub series_id: Option<String>,
+    pub language: Option<String>,
+    /// Tag names to add, created if needed
+    #[serde(default)]
+    pub add_tags: Vec<String>,
+    /// Tag names to remove
+    #[serde(default)]
+    pub remove_tags: Vec<String>,
+}
+
+/// Request to apply the same changes to many titles at once.
+///
+/// The titles are the ones listed in `title_ids` plus those matching `filter`,
+/// whose sorting and pagination are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkTitleEditRequest {
+    #[serde(default)]
+    pub title_ids: Vec<String>,
+    pub filter: Option<TitleSearchParams>,
+    pub patch: BulkTitlePatch,
+    /// Report what would change without saving anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Changes applied to every volume of a bulk edit. Fields left out are not touched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkVolumePatch {
+    /// Location UUID, or an empty string to clear the location
+    pub location_id: Option<String>,
+    pub condition: Option<VolumeCondition>,
+}
+
+/// Request to apply the same changes to many volumes at once.
+///
+/// The volumes are the ones listed in `volume_ids` plus every volume of the
+/// titles matching `filter`, whose sorting and pagination are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkVolumeEditRequest {
+    #[serde(default)]
+    pub volume_ids: Vec<String>,
+    pub filter: Option<TitleSearchParams>,
+    pub patch: BulkVolumePatch,
+    /// Report what would change without saving anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::{CustomFieldScope, CustomFieldType};
+
+/// Request payload for defining a custom field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomFieldRequest {
+    pub name: String,
+    pub field_type: CustomFieldType,
+    pub scope: CustomFieldScope,
+    /// Allowed values, required for `enum` fields
+    #[serde(default)]
+    pub options: Vec<String>,
+}
+
+/// Request payload for updating a custom field.
+///
+/// The type and scope of a field cannot change once values are stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCustomFieldRequest {
+    pub name: Option<String>,
+    /// Replaces the allowed values of an `enum` field
+    pub options: Option<Vec<String>>,
+}
+
+/// Query parameters for listing custom fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldListParams {
+    /// Only list the fields of this scope
+    pub scope: Option<CustomFieldScope>,
+}
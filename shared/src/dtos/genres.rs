@@ -5,6 +5,9 @@ use serde::{Deserialize, Serialize};
 pub struct CreateGenreRequest {
     pub name: String,
     pub description: Option<String>,
+    /// UUID of the parent genre, none for a root genre
+    #[serde(default)]
+    pub parent_id: Option<String>,
 }
 
 /// Request payload for updating an existing genre.
@@ -12,4 +15,7 @@ pub struct CreateGenreRequest {
 pub struct UpdateGenreRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// New parent genre UUID; an empty string moves the genre to the root
+    #[serde(default)]
+    pub parent_id: Option<String>,
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::models::loans::LoanDetail;
 
 /// Request payload for creating a new loan by barcode scanning.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,3 +21,14 @@ pub struct CreateLoanResponse {
     pub due_date: i64,
     pub loan_duration_days: i32,
 }
+
+/// Loans of the borrower linked to the current user (self-service portal).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MyLoansResponse {
+    pub borrower_id: String,
+    pub borrower_name: String,
+    /// Loans not yet returned, soonest due first
+    pub active_loans: Vec<LoanDetail>,
+    /// Returned loans, most recent first
+    pub past_loans: Vec<LoanDetail>,
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::ReadingStatus;
+
+/// Request payload for starting to track a title for the current user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReadingRecordRequest {
+    pub title_id: String,
+    /// Defaults to `to_read`
+    #[serde(default)]
+    pub status: ReadingStatus,
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+    pub current_page: Option<i32>,
+}
+
+/// Request payload for updating a reading record; missing fields are kept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateReadingRecordRequest {
+    pub status: Option<ReadingStatus>,
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+    pub current_page: Option<i32>,
+}
+
+/// Query parameters for listing reading records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingListParams {
+    /// Reader to list, defaults to the current user
+    pub user_id: Option<String>,
+    pub status: Option<ReadingStatus>,
+    pub title_id: Option<String>,
+    /// Only readings finished during this year (for `/reading/finished`, defaults to the current year)
+    pub year: Option<i32>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::TitleNoteKind;
+
+/// Request payload for rating and/or reviewing a title as the current user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTitleReviewRequest {
+    pub rating: Option<i32>,
+    pub review: Option<String>,
+}
+
+/// Request payload for creating or replacing a note on a title.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleNoteRequest {
+    /// Defaults to `note`
+    #[serde(default)]
+    pub kind: TitleNoteKind,
+    pub content: String,
+    pub page: Option<i32>,
+}
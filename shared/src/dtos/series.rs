@@ -5,6 +5,13 @@ use serde::{Deserialize, Serialize};
 pub struct CreateSeriesRequest {
     pub name: String,
     pub description: Option<String>,
+    /// UUID of the parent series, none for a root series
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub total_volumes: Option<i32>,
+    #[serde(default)]
+    pub reading_order: Option<String>,
 }
 
 /// Request payload for updating an existing series.
@@ -12,4 +19,18 @@ pub struct CreateSeriesRequest {
 pub struct UpdateSeriesRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// New parent series UUID; an empty string moves the series to the root
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub total_volumes: Option<i32>,
+    #[serde(default)]
+    pub reading_order: Option<String>,
+}
+
+/// Query parameters for listing series.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeriesListParams {
+    /// Count the titles of sub-series in each series' `title_count`
+    pub include_descendants: Option<bool>,
 }
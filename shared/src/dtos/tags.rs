@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Request payload for tagging a title. The tag is created if it does not exist yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddTagToTitleRequest {
+    pub name: String,
+}
+
+/// Request payload for renaming a tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameTagRequest {
+    pub name: String,
+}
@@ -1,5 +1,10 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::models::enums::{AuthorRole, IdentifierType, TitleRelationType};
+use crate::models::tags::normalize_tag_name;
+
 /// Request payload for creating a new title.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTitleRequest {
@@ -11,13 +16,22 @@ pub struct CreateTitleRequest {
     pub publication_year: Option<i32>,
     pub pages: Option<i32>,
     pub language: String,
+    /// Title of the original work, for a translation
+    pub original_title: Option<String>,
+    /// Language code of the original work, for a translation
+    pub original_language: Option<String>,
+    pub translation_notes: Option<String>,
     pub dewey_code: Option<String>,
-    #[serde(alias = "genre")]
-    pub genre_id: Option<String>,
+    /// Genre UUIDs; the first one is the primary genre
+    #[serde(default)]
+    pub genre_ids: Vec<String>,
     pub series_id: Option<String>,
     pub series_number: Option<String>,
     pub summary: Option<String>,
     pub cover_url: Option<String>,
+    /// Custom field values by custom field UUID; a blank value clears the field
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
 }
 
 /// Request payload for updating an existing title.
@@ -31,13 +45,22 @@ pub struct UpdateTitleRequest {
     pub publication_year: Option<i32>,
     pub pages: Option<i32>,
     pub language: Option<String>,
+    /// Original title, original language and translation notes; a blank value clears the field
+    pub original_title: Option<String>,
+    pub original_language: Option<String>,
+    pub translation_notes: Option<String>,
     pub dewey_code: Option<String>,
-    #[serde(alias = "genre")]
-    pub genre_id: Option<String>,
+    /// Replaces all genres; the first one is the primary genre
+    pub genre_ids: Option<Vec<String>>,
+    /// Makes this genre primary, adding it if needed and keeping the others
+    pub primary_genre_id: Option<String>,
     pub series_id: Option<String>,
     pub series_number: Option<String>,
     pub summary: Option<String>,
     pub cover_url: Option<String>,
+    /// Custom field values by custom field UUID; a blank value clears the field
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
 }
 
 /// Parameters for advanced title search and filtering.
@@ -47,7 +70,11 @@ pub struct TitleSearchParams {
     pub title: Option<String>,
     pub subtitle: Option<String>,
     pub isbn: Option<String>,
+    /// Any identifier (ISBN, EAN, ISSN, LCCN, OCLC), matched against the title's
+    /// ISBN and its additional identifiers
+    pub identifier: Option<String>,
     pub series_id: Option<String>,
+    pub work_id: Option<String>,
     pub author_id: Option<String>,
     pub genre_id: Option<String>,
     pub publisher_id: Option<String>,
@@ -56,8 +83,23 @@ pub struct TitleSearchParams {
     pub language: Option<String>,
     pub dewey_code: Option<String>,
     pub has_volumes: Option<bool>,
+    /// Only titles on the wishlist (true) or not on it (false)
+    pub wishlist: Option<bool>,
+    /// Only titles the current user has not read yet (true) or has read (false)
+    pub unread: Option<bool>,
     pub available: Option<bool>,
     pub location_id: Option<String>,
+    /// Comma-separated tag names, e.g. `signed,gift from grandma`
+    pub tags: Option<String>,
+    /// How `tags` combine: `all` (default, AND) or `any` (OR)
+    pub tags_match: Option<String>,
+    /// Custom field UUID to filter on, together with `custom_field_value`
+    pub custom_field_id: Option<String>,
+    /// Value of `custom_field_id`: substring for text fields, exact value otherwise.
+    /// Volume fields match titles having at least one volume with this value.
+    pub custom_field_value: Option<String>,
+    /// Return a single title (the first in sort order) per work
+    pub group_by_work: Option<bool>,
     #[serde(default = "default_sort_by")]
     pub sort_by: String,
     #[serde(default = "default_sort_order")]
@@ -83,8 +125,8 @@ fn default_limit() -> i64 {
 impl TitleSearchParams {
     pub fn validate(&mut self) -> Result<(), String> {
         match self.sort_by.as_str() {
-            "title" | "publication_year" | "created_at" => {},
-            _ => return Err(format!("Invalid sort_by field: {}. Must be one of: title, publication_year, created_at", self.sort_by)),
+            "title" | "publication_year" | "created_at" | "rating" | "series_number" => {},
+            _ => return Err(format!("Invalid sort_by field: {}. Must be one of: title, publication_year, created_at, rating, series_number", self.sort_by)),
         }
 
         match self.sort_order.as_str() {
@@ -109,8 +151,77 @@ impl TitleSearchParams {
             }
         }
 
+        match self.tags_match.as_deref() {
+            None | Some("all") | Some("any") => {},
+            Some(other) => return Err(format!("Invalid tags_match: {}. Must be all or any", other)),
+        }
+
+        if self.custom_field_id.is_some() != self.custom_field_value.is_some() {
+            return Err("custom_field_id and custom_field_value must be given together".to_string());
+        }
+
         Ok(())
     }
+
+    /// The normalized tag names of the `tags` filter, without duplicates.
+    pub fn tag_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .tags
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(normalize_tag_name)
+            .collect();
+        names.sort_by_key(|name| name.to_lowercase());
+        names.dedup_by(|a, b| a.to_lowercase() == b.to_lowercase());
+        names
+    }
+}
+
+/// Request payload for adding an identifier to a title.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTitleIdentifierRequest {
+    pub identifier_type: IdentifierType,
+    pub value: String,
+}
+
+/// Request payload for updating a title identifier.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTitleIdentifierRequest {
+    pub identifier_type: Option<IdentifierType>,
+    pub value: Option<String>,
+}
+
+/// Request payload for relating a title to another one.
+///
+/// `relation_type` reads from the title in the URL: a `translation_of` relation posted
+/// on a translation points to its original.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTitleRelationRequest {
+    pub relation_type: TitleRelationType,
+    pub related_title_id: String,
+}
+
+/// An author of a contents entry in a create or update request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleContentAuthorRequest {
+    pub author_id: String,
+    pub role: AuthorRole,
+}
+
+/// Request to add or replace a contents entry of an anthology or collection.
+///
+/// An update replaces every field, including the authors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleContentRequest {
+    pub title: String,
+    pub page: Option<i32>,
+    pub original_year: Option<i32>,
+    /// Position in the table of contents; new entries go last when left out,
+    /// updated entries keep their place
+    pub position: Option<i32>,
+    #[serde(default)]
+    pub authors: Vec<TitleContentAuthorRequest>,
 }
 
 /// Request to merge a secondary title into a primary title.
@@ -128,3 +239,10 @@ pub struct MergeTitlesResponse {
     pub secondary_title_deleted: bool,
     pub message: String,
 }
+
+/// Query parameters for comparing two revisions of a title.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleRevisionDiffParams {
+    pub from: i32,
+    pub to: i32,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::TrashItemType;
+
+/// Query parameters for listing the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashListParams {
+    /// Only list items of this type
+    pub item_type: Option<TrashItemType>,
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use crate::models::enums::UserRole;
+use crate::models::users::User;
+
+/// Request payload for logging in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+/// Response from a successful login.
+///
+/// The `token` must be sent as `Authorization: Bearer <token>` on every
+/// subsequent API call (the backend also sets it as a session cookie).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginResponse {
+    pub token: String,
+    /// Expiration timestamp (seconds since epoch)
+    pub expires_at: i64,
+    pub user: User,
+}
+
+/// Request payload for creating a new user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUserRequest {
+    pub username: String,
+    pub password: String,
+    pub display_name: Option<String>,
+    /// Defaults to `reader` when omitted
+    #[serde(default)]
+    pub role: Option<UserRole>,
+    /// Borrower linked to this account (for the self-service portal)
+    #[serde(default)]
+    pub borrower_id: Option<String>,
+}
+
+/// Request payload for updating an existing user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateUserRequest {
+    pub display_name: Option<String>,
+    pub password: Option<String>,
+    pub is_active: Option<bool>,
+    pub role: Option<UserRole>,
+    /// Borrower linked to this account; an empty string removes the link
+    pub borrower_id: Option<String>,
+}
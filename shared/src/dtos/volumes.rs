@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use crate::models::enums::{VolumeCondition, LoanStatus};
 
@@ -9,6 +11,9 @@ pub struct CreateVolumeRequest {
     pub condition: VolumeCondition,
     pub location_id: Option<String>,
     pub individual_notes: Option<String>,
+    /// Custom field values by custom field UUID; a blank value clears the field
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
 }
 
 /// Request payload for updating an existing volume.
@@ -19,4 +24,7 @@ pub struct UpdateVolumeRequest {
     pub location_id: Option<String>,
     pub loan_status: Option<LoanStatus>,
     pub individual_notes: Option<String>,
+    /// Custom field values by custom field UUID; a blank value clears the field
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
 }
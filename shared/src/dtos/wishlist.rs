@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::{VolumeCondition, WishlistPriority};
+use crate::models::wishlist::WishlistShareLink;
+
+/// Request payload for putting a title on the wishlist or changing its entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetWishlistItemRequest {
+    /// Defaults to `medium`
+    #[serde(default)]
+    pub priority: WishlistPriority,
+    pub desired_edition: Option<String>,
+    pub max_price: Option<f64>,
+    pub notes: Option<String>,
+}
+
+/// Request payload for acquiring a wished title, creating its first volume.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcquireWishlistItemRequest {
+    pub barcode: String,
+    pub condition: VolumeCondition,
+    pub location_id: Option<String>,
+    pub individual_notes: Option<String>,
+}
+
+/// Request payload for creating a wishlist share link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWishlistShareLinkRequest {
+    pub name: String,
+}
+
+/// Response to the creation of a share link, the only time its token is shown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWishlistShareLinkResponse {
+    #[serde(flatten)]
+    pub link: WishlistShareLink,
+    /// Token to put in `/api/v1/public/wishlist/{token}`
+    pub token: String,
+}
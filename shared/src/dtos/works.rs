@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Request payload for creating a new work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWorkRequest {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// Request payload for updating an existing work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWorkRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
@@ -0,0 +1,188 @@
+//! Validation and normalization of title identifiers (ISBN, EAN, ISSN, LCCN, OCLC).
+//!
+//! Identifiers are stored in normalized form so that the same identifier typed
+//! in different ways (`0-306-40615-2`, `0306406152`) is found by search and
+//! duplicate detection.
+
+use crate::isbn::{self, Isbn};
+use crate::models::enums::IdentifierType;
+
+/// Validates `value` as an identifier of the given kind and returns its normalized form.
+///
+/// | Kind | Normalized form |
+/// |------|-----------------|
+/// | `isbn10` | 10 characters, no hyphens (an ISBN-13 starting with 978 is converted) |
+/// | `isbn13` | 13 digits, no hyphens (an ISBN-10 is converted) |
+/// | `ean` | 8 or 13 digits |
+/// | `issn` | `NNNN-NNNC` |
+/// | `lccn` | Library of Congress normalized form (e.g. `n78-89035` becomes `n78089035`) |
+/// | `oclc` | Digits without `ocm`/`ocn`/`on`/`(OCoLC)` prefix or leading zeros |
+///
+/// # Errors
+///
+/// Returns a human-readable message when the value is malformed or its check digit is wrong.
+pub fn normalize(kind: IdentifierType, value: &str) -> Result<String, String> {
+    match kind {
+        IdentifierType::Isbn10 => {
+            let isbn = Isbn::parse(value).map_err(|e| e.to_string())?;
+            isbn.to_isbn10()
+                .ok_or_else(|| "an ISBN starting with 979 has no ISBN-10 form".to_string())
+        }
+        IdentifierType::Isbn13 => Isbn::parse(value)
+            .map(|isbn| isbn.as_str().to_string())
+            .map_err(|e| e.to_string()),
+        IdentifierType::Ean => normalize_ean(value),
+        IdentifierType::Issn => normalize_issn(value),
+        IdentifierType::Lccn => normalize_lccn(value),
+        IdentifierType::Oclc => normalize_oclc(value),
+    }
+}
+
+/// Every normalized value `input` could stand for, whatever its kind.
+///
+/// Used to search by identifier when the kind is unknown. A valid ISBN yields
+/// both its ISBN-10 and ISBN-13 forms.
+pub fn search_candidates(input: &str) -> Vec<String> {
+    let mut values: Vec<String> = IdentifierType::ALL
+        .into_iter()
+        .filter_map(|kind| normalize(kind, input).ok())
+        .collect();
+    if values.is_empty() {
+        values.push(isbn::normalize(input));
+    }
+    values.sort();
+    values.dedup();
+    values
+}
+
+fn normalize_ean(value: &str) -> Result<String, String> {
+    let digits = isbn::normalize(value);
+    if digits.len() != 8 && digits.len() != 13 {
+        return Err(format!("an EAN has 8 or 13 digits, found {}", digits.len()));
+    }
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err("an EAN only contains digits".to_string());
+    }
+
+    // Weights alternate 3, 1, 3... starting from the digit left of the check digit
+    let (body, check) = digits.split_at(digits.len() - 1);
+    let sum: u32 = body
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| (b - b'0') as u32 * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    let expected = (10 - sum % 10) % 10;
+    if check != expected.to_string() {
+        return Err("EAN check digit is wrong".to_string());
+    }
+    Ok(digits)
+}
+
+fn normalize_issn(value: &str) -> Result<String, String> {
+    let compact = isbn::normalize(value);
+    let compact = compact.strip_prefix("ISSN").unwrap_or(&compact);
+    if !compact.is_ascii() {
+        return Err("an ISSN only contains digits and a final X".to_string());
+    }
+    if compact.len() != 8 {
+        return Err(format!("an ISSN has 8 characters, found {}", compact.len()));
+    }
+    let (body, check) = compact.split_at(7);
+    if !body.chars().all(|c| c.is_ascii_digit()) {
+        return Err("an ISSN only contains digits and a final X".to_string());
+    }
+
+    let sum: u32 = body
+        .bytes()
+        .zip((2..=8).rev())
+        .map(|(b, weight)| (b - b'0') as u32 * weight)
+        .sum();
+    let expected = match (11 - sum % 11) % 11 {
+        10 => "X".to_string(),
+        d => d.to_string(),
+    };
+    if check != expected {
+        return Err("ISSN check digit is wrong".to_string());
+    }
+    Ok(format!("{}-{}", &compact[..4], &compact[4..]))
+}
+
+fn normalize_lccn(value: &str) -> Result<String, String> {
+    // Library of Congress normalization: drop blanks and any "/suffix", then
+    // left-pad the serial number after a hyphen to 6 digits.
+    let mut lccn: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some(slash) = lccn.find('/') {
+        lccn.truncate(slash);
+    }
+    if let Some((year, serial)) = lccn.split_once('-') {
+        lccn = format!("{}{:0>6}", year, serial);
+    }
+    let lccn = lccn.to_ascii_lowercase();
+
+    let prefix_len = lccn.chars().take_while(|c| c.is_ascii_lowercase()).count();
+    let digits = &lccn[prefix_len..];
+    if prefix_len > 3
+        || !(digits.len() == 8 || digits.len() == 10)
+        || !digits.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err("an LCCN is an optional 1-3 letter prefix followed by 8 or 10 digits".to_string());
+    }
+    Ok(lccn)
+}
+
+fn normalize_oclc(value: &str) -> Result<String, String> {
+    let lower = value.trim().to_ascii_lowercase();
+    let number = lower.strip_prefix("(ocolc)").unwrap_or(&lower);
+    let number = ["ocm", "ocn", "on"]
+        .iter()
+        .find_map(|prefix| number.strip_prefix(prefix))
+        .unwrap_or(number)
+        .trim();
+
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return Err("an OCLC number only contains digits".to_string());
+    }
+    let number = number.trim_start_matches('0');
+    if number.is_empty() {
+        return Err("an OCLC number cannot be zero".to_string());
+    }
+    Ok(number.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(IdentifierType::Isbn10, "978-0-306-40615-7").unwrap(), "0306406152");
+        assert_eq!(normalize(IdentifierType::Isbn13, "0-306-40615-2").unwrap(), "9780306406157");
+        assert_eq!(normalize(IdentifierType::Ean, "4006381333931").unwrap(), "4006381333931");
+        assert_eq!(normalize(IdentifierType::Ean, "73513537").unwrap(), "73513537");
+        assert_eq!(normalize(IdentifierType::Issn, "0317 8471").unwrap(), "0317-8471");
+        assert_eq!(normalize(IdentifierType::Issn, "2434-561x").unwrap(), "2434-561X");
+        assert_eq!(normalize(IdentifierType::Lccn, "n78-89035").unwrap(), "n78089035");
+        assert_eq!(normalize(IdentifierType::Lccn, "2001-1114 /AC/r932").unwrap(), "2001001114");
+        assert_eq!(normalize(IdentifierType::Oclc, "(OCoLC)ocm00012345").unwrap(), "12345");
+    }
+
+    #[test]
+    fn test_normalize_errors() {
+        assert!(normalize(IdentifierType::Isbn10, "979-10-90636-07-1").is_err());
+        assert!(normalize(IdentifierType::Ean, "4006381333932").is_err());
+        assert!(normalize(IdentifierType::Issn, "0317-8472").is_err());
+        assert!(normalize(IdentifierType::Lccn, "abcd12345678").is_err());
+        assert!(normalize(IdentifierType::Oclc, "12a45").is_err());
+        // 8 bytes, but the seventh character is two bytes long
+        assert!(normalize(IdentifierType::Issn, "123456é").is_err());
+        assert_eq!(search_candidates("123456é"), vec!["123456é".to_string()]);
+    }
+
+    #[test]
+    fn test_search_candidates() {
+        let candidates = search_candidates("0-306-40615-2");
+        assert!(candidates.contains(&"0306406152".to_string()));
+        assert!(candidates.contains(&"9780306406157".to_string()));
+    }
+}
@@ -0,0 +1,233 @@
+//! ISBN validation, ISBN-10/ISBN-13 conversion and hyphenation.
+//!
+//! Every ISBN is handled internally as its 13-digit form, so an ISBN-10 and
+//! its ISBN-13 twin compare equal:
+//!
+//! ```
+//! use shared::isbn::Isbn;
+//!
+//! let isbn10 = Isbn::parse("2-07-036822-X").unwrap();
+//! let isbn13 = Isbn::parse("978-2070368228").unwrap();
+//! assert_eq!(isbn10, isbn13);
+//! assert_eq!(isbn13.to_isbn10().as_deref(), Some("207036822X"));
+//! assert_eq!(isbn13.hyphenated(), "978-2-07-036822-8");
+//! ```
+
+mod ranges;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Reasons an ISBN is rejected by [`Isbn::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IsbnError {
+    /// Not 10 or 13 characters once hyphens and spaces are removed
+    InvalidLength(usize),
+    /// A character other than a digit (or a final `X` in an ISBN-10)
+    InvalidCharacter(char),
+    /// The check digit does not match the other digits
+    InvalidChecksum,
+    /// A 13-digit number that does not start with 978 or 979
+    InvalidPrefix,
+}
+
+impl fmt::Display for IsbnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IsbnError::InvalidLength(len) => {
+                write!(f, "an ISBN has 10 or 13 digits, found {}", len)
+            }
+            IsbnError::InvalidCharacter(c) => write!(f, "invalid character '{}' in ISBN", c),
+            IsbnError::InvalidChecksum => write!(f, "ISBN check digit is wrong"),
+            IsbnError::InvalidPrefix => write!(f, "an ISBN-13 starts with 978 or 979"),
+        }
+    }
+}
+
+impl std::error::Error for IsbnError {}
+
+/// A validated ISBN, stored as its 13 digits without separators.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Isbn(String);
+
+impl Isbn {
+    /// Parses an ISBN-10 or ISBN-13, with or without hyphens and spaces.
+    pub fn parse(input: &str) -> Result<Self, IsbnError> {
+        let compact = normalize(input);
+        // Reject non-ASCII first: the length and slices below count bytes
+        if let Some(c) = compact.chars().find(|c| !c.is_ascii()) {
+            return Err(IsbnError::InvalidCharacter(c));
+        }
+
+        match compact.len() {
+            10 => {
+                if let Some(c) = compact[..9].chars().find(|c| !c.is_ascii_digit()) {
+                    return Err(IsbnError::InvalidCharacter(c));
+                }
+                let last = compact.as_bytes()[9] as char;
+                if !last.is_ascii_digit() && last != 'X' {
+                    return Err(IsbnError::InvalidCharacter(last));
+                }
+                if isbn10_check_digit(&compact[..9]) != last {
+                    return Err(IsbnError::InvalidChecksum);
+                }
+                let body = format!("978{}", &compact[..9]);
+                let check = isbn13_check_digit(&body);
+                Ok(Isbn(format!("{}{}", body, check)))
+            }
+            13 => {
+                if let Some(c) = compact.chars().find(|c| !c.is_ascii_digit()) {
+                    return Err(IsbnError::InvalidCharacter(c));
+                }
+                if !compact.starts_with("978") && !compact.starts_with("979") {
+                    return Err(IsbnError::InvalidPrefix);
+                }
+                if isbn13_check_digit(&compact[..12]) != compact.as_bytes()[12] as char {
+                    return Err(IsbnError::InvalidChecksum);
+                }
+                Ok(Isbn(compact))
+            }
+            len => Err(IsbnError::InvalidLength(len)),
+        }
+    }
+
+    /// The 13 digits, without hyphens.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The ISBN-10 form without hyphens. `None` for 979 ISBNs, which have no ISBN-10.
+    pub fn to_isbn10(&self) -> Option<String> {
+        if !self.0.starts_with("978") {
+            return None;
+        }
+        let body = &self.0[3..12];
+        Some(format!("{}{}", body, isbn10_check_digit(body)))
+    }
+
+    /// The ISBN-13 with hyphens between prefix, group, registrant, publication
+    /// and check digit (e.g. `978-2-07-036822-8`).
+    ///
+    /// Falls back to the bare 13 digits when the group or registrant is not
+    /// covered by the bundled range data.
+    pub fn hyphenated(&self) -> String {
+        match self.parts() {
+            Some((group, registrant, publication)) => format!(
+                "{}-{}-{}-{}-{}",
+                &self.0[..3],
+                group,
+                registrant,
+                publication,
+                &self.0[12..]
+            ),
+            None => self.0.clone(),
+        }
+    }
+
+    /// Splits the digits between prefix and check digit into group, registrant
+    /// and publication using the bundled range data.
+    fn parts(&self) -> Option<(&str, &str, &str)> {
+        let prefix = &self.0[..3];
+        let rest = &self.0[3..12];
+
+        let group_len = ranges::segment_length(ranges::GROUPS, prefix, rest)?;
+        let (group, rest) = rest.split_at(group_len);
+
+        let key = format!("{}-{}", prefix, group);
+        let registrant_len = ranges::segment_length(ranges::REGISTRANTS, &key, rest)?;
+        if registrant_len >= rest.len() {
+            return None;
+        }
+        let (registrant, publication) = rest.split_at(registrant_len);
+
+        Some((group, registrant, publication))
+    }
+}
+
+impl fmt::Display for Isbn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.hyphenated())
+    }
+}
+
+impl FromStr for Isbn {
+    type Err = IsbnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Isbn::parse(s)
+    }
+}
+
+/// Removes hyphens and whitespace and upper-cases letters, so a final `x` becomes `X`.
+///
+/// Does not validate anything; use [`Isbn::parse`] for that.
+pub fn normalize(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Check digit of an ISBN-10 from its first 9 digits (`X` stands for 10).
+fn isbn10_check_digit(body: &str) -> char {
+    let sum: u32 = body
+        .bytes()
+        .zip((2..=10).rev())
+        .map(|(b, weight)| (b - b'0') as u32 * weight)
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        d => char::from_digit(d, 10).unwrap(),
+    }
+}
+
+/// Check digit of an ISBN-13 from its first 12 digits.
+fn isbn13_check_digit(body: &str) -> char {
+    let sum: u32 = body
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| (b - b'0') as u32 * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    char::from_digit((10 - sum % 10) % 10, 10).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_convert() {
+        let isbn = Isbn::parse("0-306-40615-2").unwrap();
+        assert_eq!(isbn.as_str(), "9780306406157");
+        assert_eq!(isbn.to_isbn10().as_deref(), Some("0306406152"));
+        assert_eq!(Isbn::parse("978 0 306 40615 7").unwrap(), isbn);
+
+        let with_x = Isbn::parse("080442957x").unwrap();
+        assert_eq!(with_x.to_isbn10().as_deref(), Some("080442957X"));
+
+        assert_eq!(Isbn::parse("979-10-90636-07-1").unwrap().to_isbn10(), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Isbn::parse("0-306-40615-3"), Err(IsbnError::InvalidChecksum));
+        assert_eq!(Isbn::parse("9780306406158"), Err(IsbnError::InvalidChecksum));
+        assert_eq!(Isbn::parse("12345"), Err(IsbnError::InvalidLength(5)));
+        assert_eq!(Isbn::parse("03064X6152"), Err(IsbnError::InvalidCharacter('X')));
+        assert_eq!(Isbn::parse("1234567890128"), Err(IsbnError::InvalidPrefix));
+        // 10 bytes, but the ninth character is two bytes long
+        assert_eq!(Isbn::parse("12345678é"), Err(IsbnError::InvalidCharacter('é')));
+        assert_eq!(Isbn::parse("978030640615é"), Err(IsbnError::InvalidCharacter('é')));
+    }
+
+    #[test]
+    fn test_hyphenated() {
+        assert_eq!(Isbn::parse("9780306406157").unwrap().hyphenated(), "978-0-306-40615-7");
+        assert_eq!(Isbn::parse("9783161484100").unwrap().hyphenated(), "978-3-16-148410-0");
+        assert_eq!(Isbn::parse("9782070368228").unwrap().hyphenated(), "978-2-07-036822-8");
+        assert_eq!(Isbn::parse("9791090636071").unwrap().hyphenated(), "979-10-90636-07-1");
+        // Group 99901 is not part of the bundled ranges
+        assert_eq!(Isbn::parse("9789990100006").unwrap().hyphenated(), "9789990100006");
+    }
+}
@@ -0,0 +1,188 @@
+//! Registration group and registrant ranges used to hyphenate ISBNs.
+//!
+//! Taken from the International ISBN Agency range message
+//! (<https://www.isbn-international.org/range_file_generation>). Only the
+//! groups commonly found in a European home library are bundled; ISBNs from
+//! other groups are valid but shown without hyphens. Add an entry to
+//! `REGISTRANTS` to support another group.
+//!
+//! Each rule is `(start, end, length)`: when the next 7 digits (right-padded
+//! with zeros) fall within `start..=end`, the segment is `length` digits long.
+//! A length of 0 means the range is not allocated.
+
+pub(crate) type Rule = (u32, u32, usize);
+
+/// Registration group lengths, keyed by the EAN prefix.
+pub(crate) const GROUPS: &[(&str, &[Rule])] = &[
+    (
+        "978",
+        &[
+            (0, 5999999, 1),
+            (6000000, 6499999, 3),
+            (6500000, 6599999, 2),
+            (6600000, 6999999, 0),
+            (7000000, 7999999, 1),
+            (8000000, 9499999, 2),
+            (9500000, 9899999, 3),
+            (9900000, 9989999, 4),
+            (9990000, 9999999, 5),
+        ],
+    ),
+    (
+        "979",
+        &[
+            (0, 999999, 0),
+            (1000000, 1299999, 2),
+            (1300000, 7999999, 0),
+            (8000000, 8999999, 1),
+            (9000000, 9999999, 0),
+        ],
+    ),
+];
+
+/// Registrant lengths, keyed by `prefix-group`.
+pub(crate) const REGISTRANTS: &[(&str, &[Rule])] = &[
+    // English language
+    (
+        "978-0",
+        &[
+            (0, 1999999, 2),
+            (2000000, 6999999, 3),
+            (7000000, 8499999, 4),
+            (8500000, 8999999, 5),
+            (9000000, 9499999, 6),
+            (9500000, 9999999, 7),
+        ],
+    ),
+    // English language
+    (
+        "978-1",
+        &[
+            (0, 999999, 2),
+            (1000000, 3999999, 3),
+            (4000000, 5499999, 4),
+            (5500000, 8697999, 5),
+            (8698000, 9989999, 6),
+            (9990000, 9999999, 7),
+        ],
+    ),
+    // French language
+    (
+        "978-2",
+        &[
+            (0, 1999999, 2),
+            (2000000, 3499999, 3),
+            (3500000, 3999999, 5),
+            (4000000, 6999999, 3),
+            (7000000, 8399999, 4),
+            (8400000, 8999999, 5),
+            (9000000, 9499999, 6),
+            (9500000, 9999999, 7),
+        ],
+    ),
+    // German language
+    (
+        "978-3",
+        &[
+            (0, 299999, 2),
+            (300000, 339999, 3),
+            (340000, 369999, 4),
+            (370000, 399999, 5),
+            (400000, 1999999, 2),
+            (2000000, 6999999, 3),
+            (7000000, 8499999, 4),
+            (8500000, 8999999, 5),
+            (9000000, 9499999, 6),
+            (9500000, 9539999, 7),
+            (9540000, 9699999, 5),
+            (9700000, 9849999, 7),
+            (9850000, 9999999, 5),
+        ],
+    ),
+    // Japan
+    (
+        "978-4",
+        &[
+            (0, 1999999, 2),
+            (2000000, 6999999, 3),
+            (7000000, 8499999, 4),
+            (8500000, 8999999, 5),
+            (9000000, 9499999, 6),
+            (9500000, 9999999, 7),
+        ],
+    ),
+    // Spain
+    (
+        "978-84",
+        &[
+            (0, 1399999, 2),
+            (1400000, 1499999, 3),
+            (1500000, 1999999, 5),
+            (2000000, 6999999, 3),
+            (7000000, 8499999, 4),
+            (8500000, 8999999, 5),
+            (9000000, 9199999, 4),
+            (9200000, 9239999, 6),
+            (9240000, 9299999, 5),
+            (9300000, 9499999, 6),
+            (9500000, 9699999, 5),
+            (9700000, 9999999, 4),
+        ],
+    ),
+    // Italy
+    (
+        "978-88",
+        &[
+            (0, 1999999, 2),
+            (2000000, 5999999, 3),
+            (6000000, 8499999, 4),
+            (8500000, 8999999, 5),
+            (9000000, 9099999, 6),
+            (9100000, 9299999, 3),
+            (9300000, 9399999, 4),
+            (9400000, 9499999, 6),
+            (9500000, 9999999, 5),
+        ],
+    ),
+    // France
+    (
+        "979-10",
+        &[
+            (0, 1999999, 2),
+            (2000000, 6999999, 3),
+            (7000000, 8999999, 4),
+            (9000000, 9759999, 5),
+            (9760000, 9999999, 6),
+        ],
+    ),
+    // Korea
+    (
+        "979-11",
+        &[
+            (0, 2499999, 2),
+            (2500000, 5499999, 3),
+            (5500000, 8499999, 4),
+            (8500000, 9499999, 5),
+            (9500000, 9999999, 6),
+        ],
+    ),
+];
+
+/// Length of the segment starting at `digits` according to the rules for `key`.
+///
+/// `None` if `key` has no rules or the range is not allocated.
+pub(crate) fn segment_length(table: &[(&str, &[Rule])], key: &str, digits: &str) -> Option<usize> {
+    let (_, rules) = table.iter().find(|(k, _)| *k == key)?;
+
+    let mut padded: String = digits.chars().take(7).collect();
+    while padded.len() < 7 {
+        padded.push('0');
+    }
+    let value: u32 = padded.parse().ok()?;
+
+    rules
+        .iter()
+        .find(|(start, end, _)| (*start..=*end).contains(&value))
+        .map(|(_, _, length)| *length)
+        .filter(|length| *length > 0)
+}
@@ -0,0 +1,335 @@
+//! Catalogue of ISO 639 languages and normalization of language codes.
+//!
+//! Languages are stored as their ISO 639-1 code (`fr`), or their ISO 639-2
+//! code for the few languages without one (`grc`). Input may be any of the
+//! codes or a name in English, French or German, so that "FR", "fre" and
+//! "Français" all end up as `fr`.
+
+use serde::Serialize;
+
+/// A language of the catalogue, with its names in the frontend's languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Language {
+    /// Stored code: ISO 639-1, or ISO 639-2 when the language has no ISO 639-1 code
+    pub code: &'static str,
+    /// ISO 639-2 bibliographic code, used by library catalogues (MARC)
+    pub iso639_2b: &'static str,
+    /// ISO 639-2 terminology code
+    pub iso639_2t: &'static str,
+    pub name_en: &'static str,
+    pub name_fr: &'static str,
+    pub name_de: &'static str,
+}
+
+impl Language {
+    /// Name of the language in `locale` (`fr`, `de`), in English for any other locale.
+    pub fn name(&self, locale: &str) -> &'static str {
+        match locale {
+            "fr" => self.name_fr,
+            "de" => self.name_de,
+            _ => self.name_en,
+        }
+    }
+}
+
+/// Every language of the catalogue: ISO 639-1 languages in code order, then
+/// the ISO 639-2 languages and special codes useful to a library.
+pub const LANGUAGES: &[Language] = &[
+    Language { code: "aa", iso639_2b: "aar", iso639_2t: "aar", name_en: "Afar", name_fr: "afar", name_de: "Afar" },
+    Language { code: "ab", iso639_2b: "abk", iso639_2t: "abk", name_en: "Abkhazian", name_fr: "abkhaze", name_de: "Abchasisch" },
+    Language { code: "ae", iso639_2b: "ave", iso639_2t: "ave", name_en: "Avestan", name_fr: "avestique", name_de: "Avestisch" },
+    Language { code: "af", iso639_2b: "afr", iso639_2t: "afr", name_en: "Afrikaans", name_fr: "afrikaans", name_de: "Afrikaans" },
+    Language { code: "ak", iso639_2b: "aka", iso639_2t: "aka", name_en: "Akan", name_fr: "akan", name_de: "Akan" },
+    Language { code: "am", iso639_2b: "amh", iso639_2t: "amh", name_en: "Amharic", name_fr: "amharique", name_de: "Amharisch" },
+    Language { code: "an", iso639_2b: "arg", iso639_2t: "arg", name_en: "Aragonese", name_fr: "aragonais", name_de: "Aragonesisch" },
+    Language { code: "ar", iso639_2b: "ara", iso639_2t: "ara", name_en: "Arabic", name_fr: "arabe", name_de: "Arabisch" },
+    Language { code: "as", iso639_2b: "asm", iso639_2t: "asm", name_en: "Assamese", name_fr: "assamais", name_de: "Assamesisch" },
+    Language { code: "av", iso639_2b: "ava", iso639_2t: "ava", name_en: "Avaric", name_fr: "avar", name_de: "Awarisch" },
+    Language { code: "ay", iso639_2b: "aym", iso639_2t: "aym", name_en: "Aymara", name_fr: "aymara", name_de: "Aymara" },
+    Language { code: "az", iso639_2b: "aze", iso639_2t: "aze", name_en: "Azerbaijani", name_fr: "azéri", name_de: "Aserbaidschanisch" },
+    Language { code: "ba", iso639_2b: "bak", iso639_2t: "bak", name_en: "Bashkir", name_fr: "bachkir", name_de: "Baschkirisch" },
+    Language { code: "be", iso639_2b: "bel", iso639_2t: "bel", name_en: "Belarusian", name_fr: "biélorusse", name_de: "Belarussisch" },
+    Language { code: "bg", iso639_2b: "bul", iso639_2t: "bul", name_en: "Bulgarian", name_fr: "bulgare", name_de: "Bulgarisch" },
+    Language { code: "bi", iso639_2b: "bis", iso639_2t: "bis", name_en: "Bislama", name_fr: "bichelamar", name_de: "Bislama" },
+    Language { code: "bm", iso639_2b: "bam", iso639_2t: "bam", name_en: "Bambara", name_fr: "bambara", name_de: "Bambara" },
+    Language { code: "bn", iso639_2b: "ben", iso639_2t: "ben", name_en: "Bengali", name_fr: "bengali", name_de: "Bengalisch" },
+    Language { code: "bo", iso639_2b: "tib", iso639_2t: "bod", name_en: "Tibetan", name_fr: "tibétain", name_de: "Tibetisch" },
+    Language { code: "br", iso639_2b: "bre", iso639_2t: "bre", name_en: "Breton", name_fr: "breton", name_de: "Bretonisch" },
+    Language { code: "bs", iso639_2b: "bos", iso639_2t: "bos", name_en: "Bosnian", name_fr: "bosniaque", name_de: "Bosnisch" },
+    Language { code: "ca", iso639_2b: "cat", iso639_2t: "cat", name_en: "Catalan", name_fr: "catalan", name_de: "Katalanisch" },
+    Language { code: "ce", iso639_2b: "che", iso639_2t: "che", name_en: "Chechen", name_fr: "tchétchène", name_de: "Tschetschenisch" },
+    Language { code: "ch", iso639_2b: "cha", iso639_2t: "cha", name_en: "Chamorro", name_fr: "chamorro", name_de: "Chamorro" },
+    Language { code: "co", iso639_2b: "cos", iso639_2t: "cos", name_en: "Corsican", name_fr: "corse", name_de: "Korsisch" },
+    Language { code: "cr", iso639_2b: "cre", iso639_2t: "cre", name_en: "Cree", name_fr: "cree", name_de: "Cree" },
+    Language { code: "cs", iso639_2b: "cze", iso639_2t: "ces", name_en: "Czech", name_fr: "tchèque", name_de: "Tschechisch" },
+    Language { code: "cu", iso639_2b: "chu", iso639_2t: "chu", name_en: "Church Slavic", name_fr: "slavon d'église", name_de: "Kirchenslawisch" },
+    Language { code: "cv", iso639_2b: "chv", iso639_2t: "chv", name_en: "Chuvash", name_fr: "tchouvache", name_de: "Tschuwaschisch" },
+    Language { code: "cy", iso639_2b: "wel", iso639_2t: "cym", name_en: "Welsh", name_fr: "gallois", name_de: "Walisisch" },
+    Language { code: "da", iso639_2b: "dan", iso639_2t: "dan", name_en: "Danish", name_fr: "danois", name_de: "Dänisch" },
+    Language { code: "de", iso639_2b: "ger", iso639_2t: "deu", name_en: "German", name_fr: "allemand", name_de: "Deutsch" },
+    Language { code: "dv", iso639_2b: "div", iso639_2t: "div", name_en: "Divehi", name_fr: "maldivien", name_de: "Dhivehi" },
+    Language { code: "dz", iso639_2b: "dzo", iso639_2t: "dzo", name_en: "Dzongkha", name_fr: "dzongkha", name_de: "Dzongkha" },
+    Language { code: "ee", iso639_2b: "ewe", iso639_2t: "ewe", name_en: "Ewe", name_fr: "éwé", name_de: "Ewe" },
+    Language { code: "el", iso639_2b: "gre", iso639_2t: "ell", name_en: "Greek", name_fr: "grec", name_de: "Griechisch" },
+    Language { code: "en", iso639_2b: "eng", iso639_2t: "eng", name_en: "English", name_fr: "anglais", name_de: "Englisch" },
+    Language { code: "eo", iso639_2b: "epo", iso639_2t: "epo", name_en: "Esperanto", name_fr: "espéranto", name_de: "Esperanto" },
+    Language { code: "es", iso639_2b: "spa", iso639_2t: "spa", name_en: "Spanish", name_fr: "espagnol", name_de: "Spanisch" },
+    Language { code: "et", iso639_2b: "est", iso639_2t: "est", name_en: "Estonian", name_fr: "estonien", name_de: "Estnisch" },
+    Language { code: "eu", iso639_2b: "baq", iso639_2t: "eus", name_en: "Basque", name_fr: "basque", name_de: "Baskisch" },
+    Language { code: "fa", iso639_2b: "per", iso639_2t: "fas", name_en: "Persian", name_fr: "persan", name_de: "Persisch" },
+    Language { code: "ff", iso639_2b: "ful", iso639_2t: "ful", name_en: "Fulah", name_fr: "peul", name_de: "Fulfulde" },
+    Language { code: "fi", iso639_2b: "fin", iso639_2t: "fin", name_en: "Finnish", name_fr: "finnois", name_de: "Finnisch" },
+    Language { code: "fj", iso639_2b: "fij", iso639_2t: "fij", name_en: "Fijian", name_fr: "fidjien", name_de: "Fidschianisch" },
+    Language { code: "fo", iso639_2b: "fao", iso639_2t: "fao", name_en: "Faroese", name_fr: "féroïen", name_de: "Färöisch" },
+    Language { code: "fr", iso639_2b: "fre", iso639_2t: "fra", name_en: "French", name_fr: "français", name_de: "Französisch" },
+    Language { code: "fy", iso639_2b: "fry", iso639_2t: "fry", name_en: "Western Frisian", name_fr: "frison occidental", name_de: "Westfriesisch" },
+    Language { code: "ga", iso639_2b: "gle", iso639_2t: "gle", name_en: "Irish", name_fr: "irlandais", name_de: "Irisch" },
+    Language { code: "gd", iso639_2b: "gla", iso639_2t: "gla", name_en: "Scottish Gaelic", name_fr: "gaélique écossais", name_de: "Schottisch-Gälisch" },
+    Language { code: "gl", iso639_2b: "glg", iso639_2t: "glg", name_en: "Galician", name_fr: "galicien", name_de: "Galicisch" },
+    Language { code: "gn", iso639_2b: "grn", iso639_2t: "grn", name_en: "Guarani", name_fr: "guarani", name_de: "Guaraní" },
+    Language { code: "gu", iso639_2b: "guj", iso639_2t: "guj", name_en: "Gujarati", name_fr: "goudjarati", name_de: "Gujarati" },
+    Language { code: "gv", iso639_2b: "glv", iso639_2t: "glv", name_en: "Manx", name_fr: "mannois", name_de: "Manx" },
+    Language { code: "ha", iso639_2b: "hau", iso639_2t: "hau", name_en: "Hausa", name_fr: "haoussa", name_de: "Hausa" },
+    Language { code: "he", iso639_2b: "heb", iso639_2t: "heb", name_en: "Hebrew", name_fr: "hébreu", name_de: "Hebräisch" },
+    Language { code: "hi", iso639_2b: "hin", iso639_2t: "hin", name_en: "Hindi", name_fr: "hindi", name_de: "Hindi" },
+    Language { code: "ho", iso639_2b: "hmo", iso639_2t: "hmo", name_en: "Hiri Motu", name_fr: "hiri motu", name_de: "Hiri Motu" },
+    Language { code: "hr", iso639_2b: "hrv", iso639_2t: "hrv", name_en: "Croatian", name_fr: "croate", name_de: "Kroatisch" },
+    Language { code: "ht", iso639_2b: "hat", iso639_2t: "hat", name_en: "Haitian Creole", name_fr: "créole haïtien", name_de: "Haitianisch" },
+    Language { code: "hu", iso639_2b: "hun", iso639_2t: "hun", name_en: "Hungarian", name_fr: "hongrois", name_de: "Ungarisch" },
+    Language { code: "hy", iso639_2b: "arm", iso639_2t: "hye", name_en: "Armenian", name_fr: "arménien", name_de: "Armenisch" },
+    Language { code: "hz", iso639_2b: "her", iso639_2t: "her", name_en: "Herero", name_fr: "héréro", name_de: "Herero" },
+    Language { code: "ia", iso639_2b: "ina", iso639_2t: "ina", name_en: "Interlingua", name_fr: "interlingua", name_de: "Interlingua" },
+    Language { code: "id", iso639_2b: "ind", iso639_2t: "ind", name_en: "Indonesian", name_fr: "indonésien", name_de: "Indonesisch" },
+    Language { code: "ie", iso639_2b: "ile", iso639_2t: "ile", name_en: "Interlingue", name_fr: "interlingue", name_de: "Interlingue" },
+    Language { code: "ig", iso639_2b: "ibo", iso639_2t: "ibo", name_en: "Igbo", name_fr: "igbo", name_de: "Igbo" },
+    Language { code: "ii", iso639_2b: "iii", iso639_2t: "iii", name_en: "Sichuan Yi", name_fr: "yi du Sichuan", name_de: "Sichuan-Yi" },
+    Language { code: "ik", iso639_2b: "ipk", iso639_2t: "ipk", name_en: "Inupiaq", name_fr: "inupiak", name_de: "Inupiaq" },
+    Language { code: "io", iso639_2b: "ido", iso639_2t: "ido", name_en: "Ido", name_fr: "ido", name_de: "Ido" },
+    Language { code: "is", iso639_2b: "ice", iso639_2t: "isl", name_en: "Icelandic", name_fr: "islandais", name_de: "Isländisch" },
+    Language { code: "it", iso639_2b: "ita", iso639_2t: "ita", name_en: "Italian", name_fr: "italien", name_de: "Italienisch" },
+    Language { code: "iu", iso639_2b: "iku", iso639_2t: "iku", name_en: "Inuktitut", name_fr: "inuktitut", name_de: "Inuktitut" },
+    Language { code: "ja", iso639_2b: "jpn", iso639_2t: "jpn", name_en: "Japanese", name_fr: "japonais", name_de: "Japanisch" },
+    Language { code: "jv", iso639_2b: "jav", iso639_2t: "jav", name_en: "Javanese", name_fr: "javanais", name_de: "Javanisch" },
+    Language { code: "ka", iso639_2b: "geo", iso639_2t: "kat", name_en: "Georgian", name_fr: "géorgien", name_de: "Georgisch" },
+    Language { code: "kg", iso639_2b: "kon", iso639_2t: "kon", name_en: "Kongo", name_fr: "kikongo", name_de: "Kikongo" },
+    Language { code: "ki", iso639_2b: "kik", iso639_2t: "kik", name_en: "Kikuyu", name_fr: "kikuyu", name_de: "Kikuyu" },
+    Language { code: "kj", iso639_2b: "kua", iso639_2t: "kua", name_en: "Kuanyama", name_fr: "kuanyama", name_de: "Kwanyama" },
+    Language { code: "kk", iso639_2b: "kaz", iso639_2t: "kaz", name_en: "Kazakh", name_fr: "kazakh", name_de: "Kasachisch" },
+    Language { code: "kl", iso639_2b: "kal", iso639_2t: "kal", name_en: "Kalaallisut", name_fr: "groenlandais", name_de: "Grönländisch" },
+    Language { code: "km", iso639_2b: "khm", iso639_2t: "khm", name_en: "Khmer", name_fr: "khmer", name_de: "Khmer" },
+    Language { code: "kn", iso639_2b: "kan", iso639_2t: "kan", name_en: "Kannada", name_fr: "kannada", name_de: "Kannada" },
+    Language { code: "ko", iso639_2b: "kor", iso639_2t: "kor", name_en: "Korean", name_fr: "coréen", name_de: "Koreanisch" },
+    Language { code: "kr", iso639_2b: "kau", iso639_2t: "kau", name_en: "Kanuri", name_fr: "kanouri", name_de: "Kanuri" },
+    Language { code: "ks", iso639_2b: "kas", iso639_2t: "kas", name_en: "Kashmiri", name_fr: "cachemiri", name_de: "Kaschmiri" },
+    Language { code: "ku", iso639_2b: "kur", iso639_2t: "kur", name_en: "Kurdish", name_fr: "kurde", name_de: "Kurdisch" },
+    Language { code: "kv", iso639_2b: "kom", iso639_2t: "kom", name_en: "Komi", name_fr: "komi", name_de: "Komi" },
+    Language { code: "kw", iso639_2b: "cor", iso639_2t: "cor", name_en: "Cornish", name_fr: "cornique", name_de: "Kornisch" },
+    Language { code: "ky", iso639_2b: "kir", iso639_2t: "kir", name_en: "Kyrgyz", name_fr: "kirghize", name_de: "Kirgisisch" },
+    Language { code: "la", iso639_2b: "lat", iso639_2t: "lat", name_en: "Latin", name_fr: "latin", name_de: "Latein" },
+    Language { code: "lb", iso639_2b: "ltz", iso639_2t: "ltz", name_en: "Luxembourgish", name_fr: "luxembourgeois", name_de: "Luxemburgisch" },
+    Language { code: "lg", iso639_2b: "lug", iso639_2t: "lug", name_en: "Ganda", name_fr: "ganda", name_de: "Luganda" },
+    Language { code: "li", iso639_2b: "lim", iso639_2t: "lim", name_en: "Limburgish", name_fr: "limbourgeois", name_de: "Limburgisch" },
+    Language { code: "ln", iso639_2b: "lin", iso639_2t: "lin", name_en: "Lingala", name_fr: "lingala", name_de: "Lingala" },
+    Language { code: "lo", iso639_2b: "lao", iso639_2t: "lao", name_en: "Lao", name_fr: "lao", name_de: "Laotisch" },
+    Language { code: "lt", iso639_2b: "lit", iso639_2t: "lit", name_en: "Lithuanian", name_fr: "lituanien", name_de: "Litauisch" },
+    Language { code: "lu", iso639_2b: "lub", iso639_2t: "lub", name_en: "Luba-Katanga", name_fr: "luba-katanga", name_de: "Luba-Katanga" },
+    Language { code: "lv", iso639_2b: "lav", iso639_2t: "lav", name_en: "Latvian", name_fr: "letton", name_de: "Lettisch" },
+    Language { code: "mg", iso639_2b: "mlg", iso639_2t: "mlg", name_en: "Malagasy", name_fr: "malgache", name_de: "Malagasy" },
+    Language { code: "mh", iso639_2b: "mah", iso639_2t: "mah", name_en: "Marshallese", name_fr: "marshallais", name_de: "Marshallesisch" },
+    Language { code: "mi", iso639_2b: "mao", iso639_2t: "mri", name_en: "Maori", name_fr: "maori", name_de: "Maori" },
+    Language { code: "mk", iso639_2b: "mac", iso639_2t: "mkd", name_en: "Macedonian", name_fr: "macédonien", name_de: "Mazedonisch" },
+    Language { code: "ml", iso639_2b: "mal", iso639_2t: "mal", name_en: "Malayalam", name_fr: "malayalam", name_de: "Malayalam" },
+    Language { code: "mn", iso639_2b: "mon", iso639_2t: "mon", name_en: "Mongolian", name_fr: "mongol", name_de: "Mongolisch" },
+    Language { code: "mr", iso639_2b: "mar", iso639_2t: "mar", name_en: "Marathi", name_fr: "marathi", name_de: "Marathi" },
+    Language { code: "ms", iso639_2b: "may", iso639_2t: "msa", name_en: "Malay", name_fr: "malais", name_de: "Malaiisch" },
+    Language { code: "mt", iso639_2b: "mlt", iso639_2t: "mlt", name_en: "Maltese", name_fr: "maltais", name_de: "Maltesisch" },
+    Language { code: "my", iso639_2b: "bur", iso639_2t: "mya", name_en: "Burmese", name_fr: "birman", name_de: "Birmanisch" },
+    Language { code: "na", iso639_2b: "nau", iso639_2t: "nau", name_en: "Nauru", name_fr: "nauruan", name_de: "Nauruisch" },
+    Language { code: "nb", iso639_2b: "nob", iso639_2t: "nob", name_en: "Norwegian Bokmål", name_fr: "norvégien bokmål", name_de: "Bokmål" },
+    Language { code: "nd", iso639_2b: "nde", iso639_2t: "nde", name_en: "North Ndebele", name_fr: "ndébélé du Nord", name_de: "Nord-Ndebele" },
+    Language { code: "ne", iso639_2b: "nep", iso639_2t: "nep", name_en: "Nepali", name_fr: "népalais", name_de: "Nepali" },
+    Language { code: "ng", iso639_2b: "ndo", iso639_2t: "ndo", name_en: "Ndonga", name_fr: "ndonga", name_de: "Ndonga" },
+    Language { code: "nl", iso639_2b: "dut", iso639_2t: "nld", name_en: "Dutch", name_fr: "néerlandais", name_de: "Niederländisch" },
+    Language { code: "nn", iso639_2b: "nno", iso639_2t: "nno", name_en: "Norwegian Nynorsk", name_fr: "norvégien nynorsk", name_de: "Nynorsk" },
+    Language { code: "no", iso639_2b: "nor", iso639_2t: "nor", name_en: "Norwegian", name_fr: "norvégien", name_de: "Norwegisch" },
+    Language { code: "nr", iso639_2b: "nbl", iso639_2t: "nbl", name_en: "South Ndebele", name_fr: "ndébélé du Sud", name_de: "Süd-Ndebele" },
+    Language { code: "nv", iso639_2b: "nav", iso639_2t: "nav", name_en: "Navajo", name_fr: "navajo", name_de: "Navajo" },
+    Language { code: "ny", iso639_2b: "nya", iso639_2t: "nya", name_en: "Chichewa", name_fr: "chichewa", name_de: "Chichewa" },
+    Language { code: "oc", iso639_2b: "oci", iso639_2t: "oci", name_en: "Occitan", name_fr: "occitan", name_de: "Okzitanisch" },
+    Language { code: "oj", iso639_2b: "oji", iso639_2t: "oji", name_en: "Ojibwa", name_fr: "ojibwa", name_de: "Ojibwe" },
+    Language { code: "om", iso639_2b: "orm", iso639_2t: "orm", name_en: "Oromo", name_fr: "oromo", name_de: "Oromo" },
+    Language { code: "or", iso639_2b: "ori", iso639_2t: "ori", name_en: "Oriya", name_fr: "oriya", name_de: "Oriya" },
+    Language { code: "os", iso639_2b: "oss", iso639_2t: "oss", name_en: "Ossetian", name_fr: "ossète", name_de: "Ossetisch" },
+    Language { code: "pa", iso639_2b: "pan", iso639_2t: "pan", name_en: "Punjabi", name_fr: "pendjabi", name_de: "Panjabi" },
+    Language { code: "pi", iso639_2b: "pli", iso639_2t: "pli", name_en: "Pali", name_fr: "pali", name_de: "Pali" },
+    Language { code: "pl", iso639_2b: "pol", iso639_2t: "pol", name_en: "Polish", name_fr: "polonais", name_de: "Polnisch" },
+    Language { code: "ps", iso639_2b: "pus", iso639_2t: "pus", name_en: "Pashto", name_fr: "pachto", name_de: "Paschtu" },
+    Language { code: "pt", iso639_2b: "por", iso639_2t: "por", name_en: "Portuguese", name_fr: "portugais", name_de: "Portugiesisch" },
+    Language { code: "qu", iso639_2b: "que", iso639_2t: "que", name_en: "Quechua", name_fr: "quechua", name_de: "Quechua" },
+    Language { code: "rm", iso639_2b: "roh", iso639_2t: "roh", name_en: "Romansh", name_fr: "romanche", name_de: "Rätoromanisch" },
+    Language { code: "rn", iso639_2b: "run", iso639_2t: "run", name_en: "Rundi", name_fr: "kirundi", name_de: "Kirundi" },
+    Language { code: "ro", iso639_2b: "rum", iso639_2t: "ron", name_en: "Romanian", name_fr: "roumain", name_de: "Rumänisch" },
+    Language { code: "ru", iso639_2b: "rus", iso639_2t: "rus", name_en: "Russian", name_fr: "russe", name_de: "Russisch" },
+    Language { code: "rw", iso639_2b: "kin", iso639_2t: "kin", name_en: "Kinyarwanda", name_fr: "kinyarwanda", name_de: "Kinyarwanda" },
+    Language { code: "sa", iso639_2b: "san", iso639_2t: "san", name_en: "Sanskrit", name_fr: "sanskrit", name_de: "Sanskrit" },
+    Language { code: "sc", iso639_2b: "srd", iso639_2t: "srd", name_en: "Sardinian", name_fr: "sarde", name_de: "Sardisch" },
+    Language { code: "sd", iso639_2b: "snd", iso639_2t: "snd", name_en: "Sindhi", name_fr: "sindhi", name_de: "Sindhi" },
+    Language { code: "se", iso639_2b: "sme", iso639_2t: "sme", name_en: "Northern Sami", name_fr: "same du Nord", name_de: "Nordsamisch" },
+    Language { code: "sg", iso639_2b: "sag", iso639_2t: "sag", name_en: "Sango", name_fr: "sango", name_de: "Sango" },
+    Language { code: "si", iso639_2b: "sin", iso639_2t: "sin", name_en: "Sinhala", name_fr: "cingalais", name_de: "Singhalesisch" },
+    Language { code: "sk", iso639_2b: "slo", iso639_2t: "slk", name_en: "Slovak", name_fr: "slovaque", name_de: "Slowakisch" },
+    Language { code: "sl", iso639_2b: "slv", iso639_2t: "slv", name_en: "Slovenian", name_fr: "slovène", name_de: "Slowenisch" },
+    Language { code: "sm", iso639_2b: "smo", iso639_2t: "smo", name_en: "Samoan", name_fr: "samoan", name_de: "Samoanisch" },
+    Language { code: "sn", iso639_2b: "sna", iso639_2t: "sna", name_en: "Shona", name_fr: "shona", name_de: "Shona" },
+    Language { code: "so", iso639_2b: "som", iso639_2t: "som", name_en: "Somali", name_fr: "somali", name_de: "Somali" },
+    Language { code: "sq", iso639_2b: "alb", iso639_2t: "sqi", name_en: "Albanian", name_fr: "albanais", name_de: "Albanisch" },
+    Language { code: "sr", iso639_2b: "srp", iso639_2t: "srp", name_en: "Serbian", name_fr: "serbe", name_de: "Serbisch" },
+    Language { code: "ss", iso639_2b: "ssw", iso639_2t: "ssw", name_en: "Swati", name_fr: "swati", name_de: "Swazi" },
+    Language { code: "st", iso639_2b: "sot", iso639_2t: "sot", name_en: "Southern Sotho", name_fr: "sotho du Sud", name_de: "Süd-Sotho" },
+    Language { code: "su", iso639_2b: "sun", iso639_2t: "sun", name_en: "Sundanese", name_fr: "soundanais", name_de: "Sundanesisch" },
+    Language { code: "sv", iso639_2b: "swe", iso639_2t: "swe", name_en: "Swedish", name_fr: "suédois", name_de: "Schwedisch" },
+    Language { code: "sw", iso639_2b: "swa", iso639_2t: "swa", name_en: "Swahili", name_fr: "swahili", name_de: "Swahili" },
+    Language { code: "ta", iso639_2b: "tam", iso639_2t: "tam", name_en: "Tamil", name_fr: "tamoul", name_de: "Tamil" },
+    Language { code: "te", iso639_2b: "tel", iso639_2t: "tel", name_en: "Telugu", name_fr: "télougou", name_de: "Telugu" },
+    Language { code: "tg", iso639_2b: "tgk", iso639_2t: "tgk", name_en: "Tajik", name_fr: "tadjik", name_de: "Tadschikisch" },
+    Language { code: "th", iso639_2b: "tha", iso639_2t: "tha", name_en: "Thai", name_fr: "thaï", name_de: "Thailändisch" },
+    Language { code: "ti", iso639_2b: "tir", iso639_2t: "tir", name_en: "Tigrinya", name_fr: "tigrigna", name_de: "Tigrinya" },
+    Language { code: "tk", iso639_2b: "tuk", iso639_2t: "tuk", name_en: "Turkmen", name_fr: "turkmène", name_de: "Turkmenisch" },
+    Language { code: "tl", iso639_2b: "tgl", iso639_2t: "tgl", name_en: "Tagalog", name_fr: "tagalog", name_de: "Tagalog" },
+    Language { code: "tn", iso639_2b: "tsn", iso639_2t: "tsn", name_en: "Tswana", name_fr: "tswana", name_de: "Setswana" },
+    Language { code: "to", iso639_2b: "ton", iso639_2t: "ton", name_en: "Tongan", name_fr: "tongien", name_de: "Tongaisch" },
+    Language { code: "tr", iso639_2b: "tur", iso639_2t: "tur", name_en: "Turkish", name_fr: "turc", name_de: "Türkisch" },
+    Language { code: "ts", iso639_2b: "tso", iso639_2t: "tso", name_en: "Tsonga", name_fr: "tsonga", name_de: "Xitsonga" },
+    Language { code: "tt", iso639_2b: "tat", iso639_2t: "tat", name_en: "Tatar", name_fr: "tatar", name_de: "Tatarisch" },
+    Language { code: "tw", iso639_2b: "twi", iso639_2t: "twi", name_en: "Twi", name_fr: "twi", name_de: "Twi" },
+    Language { code: "ty", iso639_2b: "tah", iso639_2t: "tah", name_en: "Tahitian", name_fr: "tahitien", name_de: "Tahitianisch" },
+    Language { code: "ug", iso639_2b: "uig", iso639_2t: "uig", name_en: "Uyghur", name_fr: "ouïghour", name_de: "Uigurisch" },
+    Language { code: "uk", iso639_2b: "ukr", iso639_2t: "ukr", name_en: "Ukrainian", name_fr: "ukrainien", name_de: "Ukrainisch" },
+    Language { code: "ur", iso639_2b: "urd", iso639_2t: "urd", name_en: "Urdu", name_fr: "ourdou", name_de: "Urdu" },
+    Language { code: "uz", iso639_2b: "uzb", iso639_2t: "uzb", name_en: "Uzbek", name_fr: "ouzbek", name_de: "Usbekisch" },
+    Language { code: "ve", iso639_2b: "ven", iso639_2t: "ven", name_en: "Venda", name_fr: "venda", name_de: "Venda" },
+    Language { code: "vi", iso639_2b: "vie", iso639_2t: "vie", name_en: "Vietnamese", name_fr: "vietnamien", name_de: "Vietnamesisch" },
+    Language { code: "vo", iso639_2b: "vol", iso639_2t: "vol", name_en: "Volapük", name_fr: "volapük", name_de: "Volapük" },
+    Language { code: "wa", iso639_2b: "wln", iso639_2t: "wln", name_en: "Walloon", name_fr: "wallon", name_de: "Wallonisch" },
+    Language { code: "wo", iso639_2b: "wol", iso639_2t: "wol", name_en: "Wolof", name_fr: "wolof", name_de: "Wolof" },
+    Language { code: "xh", iso639_2b: "xho", iso639_2t: "xho", name_en: "Xhosa", name_fr: "xhosa", name_de: "isiXhosa" },
+    Language { code: "yi", iso639_2b: "yid", iso639_2t: "yid", name_en: "Yiddish", name_fr: "yiddish", name_de: "Jiddisch" },
+    Language { code: "yo", iso639_2b: "yor", iso639_2t: "yor", name_en: "Yoruba", name_fr: "yoruba", name_de: "Yoruba" },
+    Language { code: "za", iso639_2b: "zha", iso639_2t: "zha", name_en: "Zhuang", name_fr: "zhuang", name_de: "Zhuang" },
+    Language { code: "zh", iso639_2b: "chi", iso639_2t: "zho", name_en: "Chinese", name_fr: "chinois", name_de: "Chinesisch" },
+    Language { code: "zu", iso639_2b: "zul", iso639_2t: "zul", name_en: "Zulu", name_fr: "zoulou", name_de: "isiZulu" },
+    Language { code: "grc", iso639_2b: "grc", iso639_2t: "grc", name_en: "Ancient Greek", name_fr: "grec ancien", name_de: "Altgriechisch" },
+    Language { code: "mul", iso639_2b: "mul", iso639_2t: "mul", name_en: "Multiple languages", name_fr: "multilingue", name_de: "Mehrsprachig" },
+    Language { code: "und", iso639_2b: "und", iso639_2t: "und", name_en: "Undetermined", name_fr: "indéterminé", name_de: "Unbestimmt" },
+];
+
+/// Looks up a language by its stored code.
+pub fn find(code: &str) -> Option<&'static Language> {
+    LANGUAGES.iter().find(|language| language.code == code)
+}
+
+/// Resolves `input` to a language of the catalogue and returns its stored code.
+///
+/// Accepts, ignoring case and surrounding spaces:
+///
+/// - an ISO 639-1 or ISO 639-2 (bibliographic or terminology) code: `fr`, `fre`, `fra`
+/// - a name in English, French or German: `French`, `français`, `Französisch`
+/// - a code followed by a region or script: `pt-BR`, `zh_Hant` (the suffix is dropped)
+///
+/// # Errors
+///
+/// Returns a human-readable message when the input is blank or not a known language.
+pub fn normalize(input: &str) -> Result<String, String> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return Err("a language is required".to_string());
+    }
+
+    let by_code = |code: &str| {
+        LANGUAGES
+            .iter()
+            .find(|language| language.code == code || language.iso639_2b == code || language.iso639_2t == code)
+    };
+    let by_name = |name: &str| {
+        LANGUAGES.iter().find(|language| {
+            [language.name_en, language.name_fr, language.name_de]
+                .iter()
+                .any(|candidate| candidate.to_lowercase() == name)
+        })
+    };
+    let by_prefix = |input: &str| {
+        input
+            .split_once(['-', '_'])
+            .and_then(|(prefix, _)| by_code(prefix))
+    };
+
+    by_code(&input)
+        .or_else(|| by_name(&input))
+        .or_else(|| by_prefix(&input))
+        .map(|language| language.code.to_string())
+        .ok_or_else(|| format!("'{}' is not a known ISO 639 language", input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        for input in ["fr", "FR", " fr ", "fre", "FRA", "French", "français", "Französisch", "fr-CA"] {
+            assert_eq!(normalize(input).unwrap(), "fr", "{}", input);
+        }
+        assert_eq!(normalize("ger").unwrap(), "de");
+        assert_eq!(normalize("pt_BR").unwrap(), "pt");
+        assert_eq!(normalize("zh-Hant").unwrap(), "zh");
+        assert_eq!(normalize("Luba-Katanga").unwrap(), "lu");
+        assert_eq!(normalize("grec ancien").unwrap(), "grc");
+    }
+
+    #[test]
+    fn test_normalize_errors() {
+        assert!(normalize("").is_err());
+        assert!(normalize("  ").is_err());
+        assert!(normalize("xx").is_err());
+        assert!(normalize("Klingon").is_err());
+        assert!(normalize("xx-FR").is_err());
+    }
+
+    #[test]
+    fn test_catalogue_is_unambiguous() {
+        let mut keys: Vec<String> = LANGUAGES
+            .iter()
+            .flat_map(|language| {
+                // A name may be shared between locales or with a code of the same language
+                let mut keys: Vec<String> = [
+                    language.code, language.iso639_2b, language.iso639_2t,
+                    language.name_en, language.name_fr, language.name_de,
+                ]
+                .iter()
+                .map(|key| key.to_lowercase())
+                .collect();
+                keys.sort();
+                keys.dedup();
+                keys
+            })
+            .collect();
+        let count = keys.len();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), count);
+
+        for language in LANGUAGES {
+            assert_eq!(normalize(language.code).unwrap(), language.code);
+            assert_eq!(find(language.code), Some(language));
+        }
+    }
+
+    #[test]
+    fn test_name() {
+        let french = find("fr").unwrap();
+        assert_eq!(french.name("fr"), "français");
+        assert_eq!(french.name("de"), "Französisch");
+        assert_eq!(french.name("en"), "French");
+        assert_eq!(french.name("it"), "French");
+    }
+}
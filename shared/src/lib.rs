@@ -1,2 +1,5 @@
+pub mod identifiers;
+pub mod isbn;
+pub mod languages;
 pub mod models;
 pub mod dtos;
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::enums::ApiScope;
+
+/// Long-lived API token used by scripts and integrations.
+///
+/// The secret itself is only returned once, at creation. This struct holds
+/// the metadata shown when listing tokens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiToken {
+    /// Unique identifier (UUID)
+    pub id: Uuid,
+    /// Name chosen by the owner (e.g. "Nightly report")
+    pub name: String,
+    /// First characters of the token, to recognize it in lists
+    pub token_prefix: String,
+    /// Permissions granted to the token
+    pub scopes: Vec<ApiScope>,
+    /// Timestamp of creation
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Last time the token was used, if ever
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Expiration, if any
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Revocation time; revoked tokens are rejected
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub revoked_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::BulkItemStatus;
+use crate::models::titles::TitleFieldChange;
+
+/// Result of a bulk edit for one title or volume.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkItemResult {
+    /// ID of the title or volume (UUID)
+    pub id: String,
+    /// Title, or barcode of the volume, when the item exists
+    pub label: Option<String>,
+    pub status: BulkItemStatus,
+    /// Fields changed by the patch, with their old and new values
+    #[serde(default)]
+    pub changes: Vec<TitleFieldChange>,
+}
+
+/// Report of a bulk edit, with the outcome of each item.
+///
+/// A bulk edit runs in a single transaction: either every item is saved or
+/// none is. A dry run computes the same report and rolls everything back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkEditReport {
+    pub dry_run: bool,
+    /// Number of items selected by IDs and filter
+    pub matched: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub not_found: usize,
+    pub items: Vec<BulkItemResult>,
+}
+
+impl BulkEditReport {
+    /// Builds the report and its counters from the per-item results.
+    pub fn new(dry_run: bool, items: Vec<BulkItemResult>) -> Self {
+        let count = |status: BulkItemStatus| items.iter().filter(|item| item.status == status).count();
+        BulkEditReport {
+            dry_run,
+            matched: items.len(),
+            updated: count(BulkItemStatus::Updated),
+            unchanged: count(BulkItemStatus::Unchanged),
+            not_found: count(BulkItemStatus::NotFound),
+            items,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, status: BulkItemStatus) -> BulkItemResult {
+        BulkItemResult { id: id.to_string(), label: None, status, changes: Vec::new() }
+    }
+
+    #[test]
+    fn test_bulk_edit_report_counts() {
+        let report = BulkEditReport::new(true, vec![
+            item("a", BulkItemStatus::Updated),
+            item("b", BulkItemStatus::Unchanged),
+            item("c", BulkItemStatus::Updated),
+            item("d", BulkItemStatus::NotFound),
+        ]);
+        assert!(report.dry_run);
+        assert_eq!(report.matched, 4);
+        assert_eq!(report.updated, 2);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.not_found, 1);
+    }
+}
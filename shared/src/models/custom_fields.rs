@@ -0,0 +1,137 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::enums::{CustomFieldScope, CustomFieldType};
+
+/// Maximum length of a custom field name, in characters.
+pub const MAX_CUSTOM_FIELD_NAME_LENGTH: usize = 100;
+
+/// A field defined by an administrator to store data the schema does not
+/// have (e.g. reading level on titles, box number on volumes).
+///
+/// Values are stored as text in a normalized form, see [`CustomField::normalize_value`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomField {
+    /// Unique identifier (UUID)
+    pub id: Uuid,
+    /// Label shown in forms
+    pub name: String,
+    /// Type of the values
+    pub field_type: CustomFieldType,
+    /// Whether the field applies to titles or to volumes
+    pub scope: CustomFieldScope,
+    /// Allowed values of an `enum` field, empty for the other types
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Timestamp of creation
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of last update
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CustomField {
+    /// Validates a value for this field and returns the form to store.
+    ///
+    /// | Type | Stored form |
+    /// |------|-------------|
+    /// | `text` | Trimmed text |
+    /// | `number` | Shortest decimal form (`"12.50"` becomes `"12.5"`) |
+    /// | `date` | `YYYY-MM-DD` |
+    /// | `boolean` | `true` or `false` (`yes`/`no`, `1`/`0` are accepted) |
+    /// | `enum` | The matching option, compared case-insensitively |
+    ///
+    /// Returns `Ok(None)` for a blank value, which clears the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable message when the value does not fit the field type.
+    pub fn normalize_value(&self, value: &str) -> Result<Option<String>, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+
+        let normalized = match self.field_type {
+            CustomFieldType::Text => value.to_string(),
+            CustomFieldType::Number => match value.parse::<f64>() {
+                Ok(number) if number.is_finite() => number.to_string(),
+                _ => return Err(format!("'{}' is not a number", value)),
+            },
+            CustomFieldType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .map_err(|_| format!("'{}' is not a date in YYYY-MM-DD format", value))?,
+            CustomFieldType::Boolean => match value.to_ascii_lowercase().as_str() {
+                "true" | "yes" | "1" => "true".to_string(),
+                "false" | "no" | "0" => "false".to_string(),
+                _ => return Err(format!("'{}' is not true or false", value)),
+            },
+            CustomFieldType::Enum => self
+                .options
+                .iter()
+                .find(|option| option.eq_ignore_ascii_case(value))
+                .cloned()
+                .ok_or_else(|| format!("'{}' is not one of: {}", value, self.options.join(", ")))?,
+        };
+        Ok(Some(normalized))
+    }
+}
+
+/// Cleans the option list of an `enum` field: trims options and drops blanks and duplicates.
+pub fn normalize_options(options: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for option in options {
+        let option = option.trim();
+        if !option.is_empty() && !normalized.iter().any(|o| o.eq_ignore_ascii_case(option)) {
+            normalized.push(option.to_string());
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(field_type: CustomFieldType, options: &[&str]) -> CustomField {
+        CustomField {
+            id: Uuid::new_v4(),
+            name: "Field".to_string(),
+            field_type,
+            scope: CustomFieldScope::Title,
+            options: options.iter().map(|o| o.to_string()).collect(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_value() {
+        let number = field(CustomFieldType::Number, &[]);
+        assert_eq!(number.normalize_value(" 12.50 ").unwrap().as_deref(), Some("12.5"));
+        assert_eq!(number.normalize_value("3").unwrap().as_deref(), Some("3"));
+        assert!(number.normalize_value("twelve").is_err());
+
+        let date = field(CustomFieldType::Date, &[]);
+        assert_eq!(date.normalize_value("2024-5-1").unwrap().as_deref(), Some("2024-05-01"));
+        assert!(date.normalize_value("01/05/2024").is_err());
+
+        let boolean = field(CustomFieldType::Boolean, &[]);
+        assert_eq!(boolean.normalize_value("Yes").unwrap().as_deref(), Some("true"));
+        assert!(boolean.normalize_value("maybe").is_err());
+
+        let level = field(CustomFieldType::Enum, &["A1", "B2"]);
+        assert_eq!(level.normalize_value("b2").unwrap().as_deref(), Some("B2"));
+        assert!(level.normalize_value("C1").is_err());
+
+        assert_eq!(field(CustomFieldType::Text, &[]).normalize_value("   ").unwrap(), None);
+    }
+
+    #[test]
+    fn test_normalize_options() {
+        let options = vec![" A1 ".to_string(), "".to_string(), "a1".to_string(), "B2".to_string()];
+        assert_eq!(normalize_options(&options), vec!["A1".to_string(), "B2".to_string()]);
+    }
+}
@@ -145,6 +145,21 @@ pub enum AuthorRole {
     Editor,
 }
 
+impl AuthorRole {
+    pub const ALL: [AuthorRole; 5] = [
+        AuthorRole::MainAuthor,
+        AuthorRole::CoAuthor,
+        AuthorRole::Translator,
+        AuthorRole::Illustrator,
+        AuthorRole::Editor,
+    ];
+
+    /// Parses the value of the `role` column of `title_authors` and `title_content_authors`.
+    pub fn from_db(value: &str) -> Option<Self> {
+        AuthorRole::ALL.into_iter().find(|role| role.to_string() == value)
+    }
+}
+
 impl std::fmt::Display for AuthorRole {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -165,3 +180,503 @@ pub enum DuplicateConfidence {
     Medium,
     Low,
 }
+
+/// Role of a user account, ordered from least to most privileged.
+///
+/// A role includes every permission of the roles before it, so an admin can
+/// do everything a librarian can.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    /// Can browse the catalog and see their own loans
+    #[default]
+    Reader,
+    /// Can edit the catalog and manage borrowers and loans
+    Librarian,
+    /// Can also manage user accounts
+    Admin,
+}
+
+impl UserRole {
+    /// Parses the value stored in the `users.role` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "reader" => Some(UserRole::Reader),
+            "librarian" => Some(UserRole::Librarian),
+            "admin" => Some(UserRole::Admin),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserRole::Reader => write!(f, "reader"),
+            UserRole::Librarian => write!(f, "librarian"),
+            UserRole::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+/// Permission granted to an API token.
+///
+/// `write` scopes include the matching `read` scope.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ApiScope {
+    /// Read titles, volumes, authors, publishers, genres, series, locations and statistics
+    #[serde(rename = "catalog:read")]
+    CatalogRead,
+    /// Create, update and delete catalog entries
+    #[serde(rename = "catalog:write")]
+    CatalogWrite,
+    /// Read borrowers, borrower groups and loans
+    #[serde(rename = "loans:read")]
+    LoansRead,
+    /// Create, return and extend loans and manage borrowers
+    #[serde(rename = "loans:write")]
+    LoansWrite,
+    /// Read user accounts
+    #[serde(rename = "users:read")]
+    UsersRead,
+    /// Manage user accounts
+    #[serde(rename = "users:write")]
+    UsersWrite,
+}
+
+impl ApiScope {
+    /// All scopes, in display order.
+    pub const ALL: [ApiScope; 6] = [
+        ApiScope::CatalogRead,
+        ApiScope::CatalogWrite,
+        ApiScope::LoansRead,
+        ApiScope::LoansWrite,
+        ApiScope::UsersRead,
+        ApiScope::UsersWrite,
+    ];
+
+    /// Parses a scope name such as `catalog:read`.
+    pub fn parse(value: &str) -> Option<Self> {
+        ApiScope::ALL.into_iter().find(|scope| scope.to_string() == value)
+    }
+
+    /// Returns `true` if holding `self` allows an action requiring `required`.
+    pub fn grants(self, required: ApiScope) -> bool {
+        self == required
+            || matches!(
+                (self, required),
+                (ApiScope::CatalogWrite, ApiScope::CatalogRead)
+                    | (ApiScope::LoansWrite, ApiScope::LoansRead)
+                    | (ApiScope::UsersWrite, ApiScope::UsersRead)
+            )
+    }
+}
+
+impl std::fmt::Display for ApiScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiScope::CatalogRead => write!(f, "catalog:read"),
+            ApiScope::CatalogWrite => write!(f, "catalog:write"),
+            ApiScope::LoansRead => write!(f, "loans:read"),
+            ApiScope::LoansWrite => write!(f, "loans:write"),
+            ApiScope::UsersRead => write!(f, "users:read"),
+            ApiScope::UsersWrite => write!(f, "users:write"),
+        }
+    }
+}
+
+/// Kind of a catalogue identifier attached to a title.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum IdentifierType {
+    /// ISBN-10, for books published before 2007
+    Isbn10,
+    /// ISBN-13
+    Isbn13,
+    /// EAN-13 barcode (books without ISBN, audio, video)
+    Ean,
+    /// International Standard Serial Number, for series and periodicals
+    Issn,
+    /// Library of Congress Control Number
+    Lccn,
+    /// OCLC (WorldCat) record number
+    Oclc,
+}
+
+impl IdentifierType {
+    /// All identifier types, in display order.
+    pub const ALL: [IdentifierType; 6] = [
+        IdentifierType::Isbn10,
+        IdentifierType::Isbn13,
+        IdentifierType::Ean,
+        IdentifierType::Issn,
+        IdentifierType::Lccn,
+        IdentifierType::Oclc,
+    ];
+
+    /// Parses the value stored in the `title_identifiers.identifier_type` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        IdentifierType::ALL.into_iter().find(|kind| kind.to_string() == value)
+    }
+}
+
+impl std::fmt::Display for IdentifierType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentifierType::Isbn10 => write!(f, "isbn10"),
+            IdentifierType::Isbn13 => write!(f, "isbn13"),
+            IdentifierType::Ean => write!(f, "ean"),
+            IdentifierType::Issn => write!(f, "issn"),
+            IdentifierType::Lccn => write!(f, "lccn"),
+            IdentifierType::Oclc => write!(f, "oclc"),
+        }
+    }
+}
+
+/// Type of the values of a user-defined custom field.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomFieldType {
+    /// Free text
+    Text,
+    /// Integer or decimal number
+    Number,
+    /// Calendar date (`YYYY-MM-DD`)
+    Date,
+    /// Yes/no
+    Boolean,
+    /// One of the options listed in the field definition
+    Enum,
+}
+
+impl CustomFieldType {
+    /// All custom field types, in display order.
+    pub const ALL: [CustomFieldType; 5] = [
+        CustomFieldType::Text,
+        CustomFieldType::Number,
+        CustomFieldType::Date,
+        CustomFieldType::Boolean,
+        CustomFieldType::Enum,
+    ];
+
+    /// Parses the value stored in the `custom_fields.field_type` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        CustomFieldType::ALL.into_iter().find(|kind| kind.to_string() == value)
+    }
+}
+
+impl std::fmt::Display for CustomFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomFieldType::Text => write!(f, "text"),
+            CustomFieldType::Number => write!(f, "number"),
+            CustomFieldType::Date => write!(f, "date"),
+            CustomFieldType::Boolean => write!(f, "boolean"),
+            CustomFieldType::Enum => write!(f, "enum"),
+        }
+    }
+}
+
+/// Kind of record a custom field applies to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomFieldScope {
+    /// Bibliographic data shared by all copies (e.g. reading level)
+    Title,
+    /// Data of one physical copy (e.g. where it was bought, box number)
+    Volume,
+}
+
+impl CustomFieldScope {
+    /// Parses the value stored in the `custom_fields.scope` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "title" => Some(CustomFieldScope::Title),
+            "volume" => Some(CustomFieldScope::Volume),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CustomFieldScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomFieldScope::Title => write!(f, "title"),
+            CustomFieldScope::Volume => write!(f, "volume"),
+        }
+    }
+}
+
+/// Kind of record that can be moved to the trash.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum TrashItemType {
+    Title,
+    Author,
+    Borrower,
+}
+
+impl TrashItemType {
+    pub const ALL: [TrashItemType; 3] = [
+        TrashItemType::Title,
+        TrashItemType::Author,
+        TrashItemType::Borrower,
+    ];
+
+    /// Parses the item type returned by the trash queries.
+    pub fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "title" => Some(TrashItemType::Title),
+            "author" => Some(TrashItemType::Author),
+            "borrower" => Some(TrashItemType::Borrower),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TrashItemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrashItemType::Title => write!(f, "title"),
+            TrashItemType::Author => write!(f, "author"),
+            TrashItemType::Borrower => write!(f, "borrower"),
+        }
+    }
+}
+
+/// How much a title on the wishlist is wanted.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum WishlistPriority {
+    High,
+    #[default]
+    Medium,
+    Low,
+}
+
+impl WishlistPriority {
+    /// All priorities, highest first.
+    pub const ALL: [WishlistPriority; 3] = [
+        WishlistPriority::High,
+        WishlistPriority::Medium,
+        WishlistPriority::Low,
+    ];
+
+    /// Parses the value of the `wishlist_items.priority` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "high" => Some(WishlistPriority::High),
+            "medium" => Some(WishlistPriority::Medium),
+            "low" => Some(WishlistPriority::Low),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for WishlistPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WishlistPriority::High => write!(f, "high"),
+            WishlistPriority::Medium => write!(f, "medium"),
+            WishlistPriority::Low => write!(f, "low"),
+        }
+    }
+}
+
+/// Where a person is in reading a title.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadingStatus {
+    #[default]
+    ToRead,
+    Reading,
+    Read,
+}
+
+impl ReadingStatus {
+    pub const ALL: [ReadingStatus; 3] = [
+        ReadingStatus::ToRead,
+        ReadingStatus::Reading,
+        ReadingStatus::Read,
+    ];
+
+    /// Parses the value of the `reading_records.status` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "to_read" => Some(ReadingStatus::ToRead),
+            "reading" => Some(ReadingStatus::Reading),
+            "read" => Some(ReadingStatus::Read),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ReadingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadingStatus::ToRead => write!(f, "to_read"),
+            ReadingStatus::Reading => write!(f, "reading"),
+            ReadingStatus::Read => write!(f, "read"),
+        }
+    }
+}
+
+/// Kind of a personal note on a title.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum TitleNoteKind {
+    #[default]
+    Note,
+    /// A favourite passage, quoted from the book
+    Quote,
+}
+
+impl TitleNoteKind {
+    pub const ALL: [TitleNoteKind; 2] = [TitleNoteKind::Note, TitleNoteKind::Quote];
+
+    /// Parses the value of the `title_notes.kind` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        match value {
+            "note" => Some(TitleNoteKind::Note),
+            "quote" => Some(TitleNoteKind::Quote),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TitleNoteKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TitleNoteKind::Note => write!(f, "note"),
+            TitleNoteKind::Quote => write!(f, "quote"),
+        }
+    }
+}
+
+/// How a title relates to another one, read as "this title is a ... the other title".
+///
+/// Every type has an inverse (the sequel of a book makes the book its prequel), so a
+/// relation is stored once and shown on both titles.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleRelationType {
+    SequelOf,
+    PrequelOf,
+    TranslationOf,
+    HasTranslation,
+    CommentaryOn,
+    HasCommentary,
+    AdaptationOf,
+    HasAdaptation,
+    /// Symmetric: a companion book, e.g. an atlas or a guide to the same world
+    CompanionTo,
+}
+
+impl TitleRelationType {
+    pub const ALL: [TitleRelationType; 9] = [
+        TitleRelationType::SequelOf,
+        TitleRelationType::PrequelOf,
+        TitleRelationType::TranslationOf,
+        TitleRelationType::HasTranslation,
+        TitleRelationType::CommentaryOn,
+        TitleRelationType::HasCommentary,
+        TitleRelationType::AdaptationOf,
+        TitleRelationType::HasAdaptation,
+        TitleRelationType::CompanionTo,
+    ];
+
+    /// The relation as seen from the other title.
+    pub fn inverse(self) -> Self {
+        match self {
+            TitleRelationType::SequelOf => TitleRelationType::PrequelOf,
+            TitleRelationType::PrequelOf => TitleRelationType::SequelOf,
+            TitleRelationType::TranslationOf => TitleRelationType::HasTranslation,
+            TitleRelationType::HasTranslation => TitleRelationType::TranslationOf,
+            TitleRelationType::CommentaryOn => TitleRelationType::HasCommentary,
+            TitleRelationType::HasCommentary => TitleRelationType::CommentaryOn,
+            TitleRelationType::AdaptationOf => TitleRelationType::HasAdaptation,
+            TitleRelationType::HasAdaptation => TitleRelationType::AdaptationOf,
+            TitleRelationType::CompanionTo => TitleRelationType::CompanionTo,
+        }
+    }
+
+    /// Whether relations of this type are stored as is, rather than as their inverse
+    /// from the other title.
+    pub fn is_stored(self) -> bool {
+        matches!(
+            self,
+            TitleRelationType::SequelOf
+                | TitleRelationType::TranslationOf
+                | TitleRelationType::CommentaryOn
+                | TitleRelationType::AdaptationOf
+                | TitleRelationType::CompanionTo
+        )
+    }
+
+    /// Parses the value of the `title_relations.relation_type` column.
+    pub fn from_db(value: &str) -> Option<Self> {
+        TitleRelationType::ALL.into_iter().find(|kind| kind.to_string() == value)
+    }
+}
+
+impl std::fmt::Display for TitleRelationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TitleRelationType::SequelOf => write!(f, "sequel_of"),
+            TitleRelationType::PrequelOf => write!(f, "prequel_of"),
+            TitleRelationType::TranslationOf => write!(f, "translation_of"),
+            TitleRelationType::HasTranslation => write!(f, "has_translation"),
+            TitleRelationType::CommentaryOn => write!(f, "commentary_on"),
+            TitleRelationType::HasCommentary => write!(f, "has_commentary"),
+            TitleRelationType::AdaptationOf => write!(f, "adaptation_of"),
+            TitleRelationType::HasAdaptation => write!(f, "has_adaptation"),
+            TitleRelationType::CompanionTo => write!(f, "companion_to"),
+        }
+    }
+}
+
+/// Outcome of a bulk edit for one title or volume.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    /// At least one field changed (or would change, in a dry run)
+    Updated,
+    /// The patch did not change anything
+    Unchanged,
+    /// No such item, or its title is in the trash
+    NotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_relation_inverse() {
+        for kind in TitleRelationType::ALL {
+            assert_eq!(kind.inverse().inverse(), kind);
+            // Every relation can be stored from one of its two sides
+            assert!(kind.is_stored() || kind.inverse().is_stored());
+            assert_eq!(TitleRelationType::from_db(&kind.to_string()), Some(kind));
+        }
+        assert_eq!(TitleRelationType::SequelOf.inverse(), TitleRelationType::PrequelOf);
+        assert!(!TitleRelationType::HasTranslation.is_stored());
+    }
+
+    #[test]
+    fn test_author_role_from_db() {
+        for role in AuthorRole::ALL {
+            assert_eq!(AuthorRole::from_db(&role.to_string()), Some(role));
+        }
+        assert_eq!(AuthorRole::from_db("main_author"), Some(AuthorRole::MainAuthor));
+        assert_eq!(AuthorRole::from_db("narrator"), None);
+    }
+
+    #[test]
+    fn test_trash_item_type_from_db() {
+        for item_type in TrashItemType::ALL {
+            assert_eq!(TrashItemType::from_db(&item_type.to_string()), Some(item_type));
+        }
+        assert_eq!(TrashItemType::from_db("volume"), None);
+    }
+}
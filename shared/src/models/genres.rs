@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// Genre represents a category or classification for books.
@@ -10,6 +11,9 @@ pub struct Genre {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
+    /// UUID of the parent genre (e.g. "Fantasy" for "Urban Fantasy")
+    #[serde(default)]
+    pub parent_id: Option<String>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
     #[serde(with = "chrono::serde::ts_seconds")]
@@ -23,3 +27,111 @@ pub struct GenreWithTitleCount {
     pub genre: Genre,
     pub title_count: i64,
 }
+
+/// A genre in the genre tree, with its sub-genres.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenreTreeNode {
+    #[serde(flatten)]
+    pub genre: Genre,
+    /// Names from the root genre down to this one (e.g. "Fiction > Fantasy > Urban Fantasy")
+    pub full_path: String,
+    /// Depth in the tree, 0 for root genres
+    pub level: i32,
+    /// Titles carrying this genre itself
+    pub title_count: i64,
+    /// Titles carrying this genre or one of its descendants
+    pub total_title_count: i64,
+    pub children: Vec<GenreTreeNode>,
+}
+
+impl GenreTreeNode {
+    /// Builds the genre tree from a flat list of genres and their own title counts.
+    ///
+    /// `total_title_count` sums the title counts of the whole subtree; a title
+    /// carrying several genres of the same subtree is counted once per genre.
+    /// Children are sorted by name. A genre whose parent is missing from the
+    /// list is treated as a root.
+    pub fn build_tree(genres: Vec<GenreWithTitleCount>) -> Vec<GenreTreeNode> {
+        let ids: HashSet<String> = genres.iter().map(|g| g.genre.id.to_string()).collect();
+        let mut children: HashMap<Option<String>, Vec<GenreWithTitleCount>> = HashMap::new();
+        for genre in genres {
+            let parent = genre.genre.parent_id.clone().filter(|parent| ids.contains(parent));
+            children.entry(parent).or_default().push(genre);
+        }
+
+        fn build(
+            parent: Option<String>,
+            path: &str,
+            level: i32,
+            children: &mut HashMap<Option<String>, Vec<GenreWithTitleCount>>,
+        ) -> Vec<GenreTreeNode> {
+            let mut genres = children.remove(&parent).unwrap_or_default();
+            genres.sort_by_key(|g| g.genre.name.to_lowercase());
+            genres
+                .into_iter()
+                .map(|genre| {
+                    let full_path = if path.is_empty() {
+                        genre.genre.name.clone()
+                    } else {
+                        format!("{} > {}", path, genre.genre.name)
+                    };
+                    let nodes = build(Some(genre.genre.id.to_string()), &full_path, level + 1, children);
+                    let total_title_count =
+                        genre.title_count + nodes.iter().map(|n| n.total_title_count).sum::<i64>();
+                    GenreTreeNode {
+                        genre: genre.genre,
+                        full_path,
+                        level,
+                        title_count: genre.title_count,
+                        total_title_count,
+                        children: nodes,
+                    }
+                })
+                .collect()
+        }
+
+        build(None, "", 0, &mut children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn genre(name: &str, parent: Option<&GenreWithTitleCount>, title_count: i64) -> GenreWithTitleCount {
+        GenreWithTitleCount {
+            genre: Genre {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                description: None,
+                parent_id: parent.map(|p| p.genre.id.to_string()),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            title_count,
+        }
+    }
+
+    #[test]
+    fn test_build_tree() {
+        let fiction = genre("Fiction", None, 1);
+        let fantasy = genre("Fantasy", Some(&fiction), 2);
+        let urban = genre("Urban Fantasy", Some(&fantasy), 3);
+        let humour = genre("Humour", Some(&fiction), 4);
+        let poetry = genre("Poetry", None, 5);
+
+        let tree = GenreTreeNode::build_tree(vec![urban, poetry, humour, fantasy, fiction]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].genre.name, "Fiction");
+        assert_eq!(tree[0].total_title_count, 10);
+        assert_eq!(tree[0].children[0].genre.name, "Fantasy");
+        assert_eq!(tree[0].children[1].genre.name, "Humour");
+
+        let urban = &tree[0].children[0].children[0];
+        assert_eq!(urban.full_path, "Fiction > Fantasy > Urban Fantasy");
+        assert_eq!(urban.level, 2);
+        assert_eq!(tree[1].total_title_count, 5);
+    }
+}
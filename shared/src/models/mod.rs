@@ -8,3 +8,13 @@ pub mod publishers;
 pub mod genres;
 pub mod series;
 pub mod locations;
+pub mod users;
+pub mod api_tokens;
+pub mod works;
+pub mod tags;
+pub mod custom_fields;
+pub mod trash;
+pub mod wishlist;
+pub mod reading;
+pub mod reviews;
+pub mod bulk;
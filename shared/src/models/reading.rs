@@ -0,0 +1,61 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::ReadingStatus;
+
+/// One reading of a title by a person (a user account).
+///
+/// Re-reading a title adds a new record once the previous one is `read`, so the
+/// records of a person and title form their reading history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingRecord {
+    /// Unique identifier (UUID)
+    pub id: String,
+    /// UUID of the reader's user account
+    pub user_id: String,
+    pub username: String,
+    pub title_id: String,
+    pub title: String,
+    /// Page count of the title, if known
+    pub pages: Option<i32>,
+    pub status: ReadingStatus,
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+    /// Last page read
+    pub current_page: Option<i32>,
+    /// Percentage of the title read, from `current_page` and `pages` (100 once read)
+    pub progress_percent: Option<i32>,
+    /// 1 for the first reading of the title by this person, 2 for the first re-read, ...
+    pub reading_number: i64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReadingRecord {
+    /// Computes `progress_percent` from the status, current page and page count.
+    pub fn progress(status: ReadingStatus, current_page: Option<i32>, pages: Option<i32>) -> Option<i32> {
+        if status == ReadingStatus::Read {
+            return Some(100);
+        }
+        match (current_page, pages) {
+            (Some(page), Some(pages)) if pages > 0 => Some((page.clamp(0, pages) * 100 / pages).min(100)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        assert_eq!(ReadingRecord::progress(ReadingStatus::Reading, Some(50), Some(200)), Some(25));
+        assert_eq!(ReadingRecord::progress(ReadingStatus::Reading, Some(50), None), None);
+        assert_eq!(ReadingRecord::progress(ReadingStatus::Reading, None, Some(200)), None);
+        assert_eq!(ReadingRecord::progress(ReadingStatus::ToRead, Some(0), Some(0)), None);
+        assert_eq!(ReadingRecord::progress(ReadingStatus::Read, None, None), Some(100));
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::TitleNoteKind;
+
+/// Lowest star rating.
+pub const MIN_RATING: i32 = 1;
+
+/// Highest star rating.
+pub const MAX_RATING: i32 = 5;
+
+/// A user's rating and review of a title; each user has at most one per title.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleReview {
+    /// Unique identifier (UUID)
+    pub id: String,
+    pub title_id: String,
+    pub user_id: String,
+    pub username: String,
+    /// Stars, from `MIN_RATING` to `MAX_RATING`
+    pub rating: Option<i32>,
+    /// Written review
+    pub review: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A private note or favourite quote of a user on a title, only visible to its author.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleNote {
+    /// Unique identifier (UUID)
+    pub id: String,
+    pub title_id: String,
+    pub kind: TitleNoteKind,
+    pub content: String,
+    /// Page the note refers to
+    pub page: Option<i32>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// Represents a book series or collection.
@@ -10,6 +11,15 @@ pub struct Series {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
+    /// UUID of the parent series (e.g. the "Star Wars" universe for "The High Republic")
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Number of volumes published in the series, when known
+    #[serde(default)]
+    pub total_volumes: Option<i32>,
+    /// Recommended reading order, when it differs from the numbering
+    #[serde(default)]
+    pub reading_order: Option<String>,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
     #[serde(with = "chrono::serde::ts_seconds")]
@@ -21,5 +31,217 @@ pub struct Series {
 pub struct SeriesWithTitleCount {
     #[serde(flatten)]
     pub series: Series,
+    /// Names from the root series down to this one (e.g. "Discworld > City Watch")
+    #[serde(default)]
+    pub full_path: String,
+    /// Depth in the tree, 0 for root series
+    #[serde(default)]
+    pub level: i32,
     pub title_count: i64,
 }
+
+/// A series in the series tree, with its sub-series.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesTreeNode {
+    #[serde(flatten)]
+    pub series: Series,
+    /// Names from the root series down to this one
+    pub full_path: String,
+    /// Depth in the tree, 0 for root series
+    pub level: i32,
+    /// Titles of this series itself
+    pub title_count: i64,
+    /// Titles of this series or one of its sub-series
+    pub total_title_count: i64,
+    pub children: Vec<SeriesTreeNode>,
+}
+
+impl SeriesTreeNode {
+    /// Builds the series tree from a flat list of series and their own title counts.
+    ///
+    /// Children are sorted by name. A series whose parent is missing from the
+    /// list is treated as a root.
+    pub fn build_tree(series: Vec<SeriesWithTitleCount>) -> Vec<SeriesTreeNode> {
+        let ids: HashSet<String> = series.iter().map(|s| s.series.id.to_string()).collect();
+        let mut children: HashMap<Option<String>, Vec<SeriesWithTitleCount>> = HashMap::new();
+        for entry in series {
+            let parent = entry.series.parent_id.clone().filter(|parent| ids.contains(parent));
+            children.entry(parent).or_default().push(entry);
+        }
+
+        fn build(
+            parent: Option<String>,
+            path: &str,
+            level: i32,
+            children: &mut HashMap<Option<String>, Vec<SeriesWithTitleCount>>,
+        ) -> Vec<SeriesTreeNode> {
+            let mut series = children.remove(&parent).unwrap_or_default();
+            series.sort_by_key(|s| s.series.name.to_lowercase());
+            series
+                .into_iter()
+                .map(|entry| {
+                    let full_path = if path.is_empty() {
+                        entry.series.name.clone()
+                    } else {
+                        format!("{} > {}", path, entry.series.name)
+                    };
+                    let nodes = build(Some(entry.series.id.to_string()), &full_path, level + 1, children);
+                    let total_title_count =
+                        entry.title_count + nodes.iter().map(|n| n.total_title_count).sum::<i64>();
+                    SeriesTreeNode {
+                        series: entry.series,
+                        full_path,
+                        level,
+                        title_count: entry.title_count,
+                        total_title_count,
+                        children: nodes,
+                    }
+                })
+                .collect()
+        }
+
+        build(None, "", 0, &mut children)
+    }
+
+    /// Flattens a tree back into a list in path order, with `full_path` and `level` set.
+    ///
+    /// With `include_descendants`, each `title_count` is the total of the subtree.
+    pub fn flatten(nodes: Vec<SeriesTreeNode>, include_descendants: bool) -> Vec<SeriesWithTitleCount> {
+        let mut list = Vec::new();
+        for node in nodes {
+            list.push(SeriesWithTitleCount {
+                series: node.series,
+                full_path: node.full_path,
+                level: node.level,
+                title_count: if include_descendants { node.total_title_count } else { node.title_count },
+            });
+            list.extend(SeriesTreeNode::flatten(node.children, include_descendants));
+        }
+        list
+    }
+}
+
+/// A series with the numbers missing from the collection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesDetail {
+    #[serde(flatten)]
+    pub series: Series,
+    pub title_count: i64,
+    /// Titles of the series with at least one volume
+    pub owned_count: i64,
+    /// Whole numbers up to `total_volumes` (or the highest owned number) with no owned title
+    pub missing_numbers: Vec<i32>,
+}
+
+/// Largest `total_volumes` a series can have; series numbers above it are not
+/// considered when looking for missing volumes.
+pub const MAX_SERIES_VOLUMES: i32 = 10_000;
+
+/// Parses the number of a title in its series, e.g. `"Vol. 2"`, `"10"` or `"3.5"`.
+///
+/// Takes the first number in the string, with `.` or `,` as decimal separator.
+/// Returns `None` when the string holds no number.
+pub fn parse_series_number(value: &str) -> Option<f64> {
+    let start = value.find(|c: char| c.is_ascii_digit())?;
+    let rest = &value[start..];
+    let mut end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    // Keep a decimal part, but not a trailing separator ("2." or "2, 3")
+    if let Some(fraction) = rest[end..].strip_prefix(['.', ',']) {
+        let digits = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+        if digits > 0 {
+            end += 1 + digits;
+        }
+    }
+    rest[..end].replace(',', ".").parse().ok()
+}
+
+/// Lists the whole numbers from 1 to `total_volumes` that none of the owned numbers covers.
+///
+/// Without a known total, the highest owned number is the end of the range. Fractional
+/// numbers (hors-séries such as "3.5") never fill a gap. The range never goes beyond
+/// [`MAX_SERIES_VOLUMES`], whatever the total or the owned numbers say.
+pub fn missing_series_numbers<'a>(
+    owned: impl IntoIterator<Item = &'a str>,
+    total_volumes: Option<i32>,
+) -> Vec<i32> {
+    let owned: Vec<f64> = owned.into_iter().filter_map(parse_series_number).collect();
+    let max = f64::from(MAX_SERIES_VOLUMES);
+    let last = total_volumes.unwrap_or_else(|| {
+        owned
+            .iter()
+            .filter(|n| n.fract() == 0.0 && **n <= max)
+            .fold(0.0_f64, |max, n| max.max(*n)) as i32
+    });
+    (1..=last.min(MAX_SERIES_VOLUMES)).filter(|n| !owned.contains(&f64::from(*n))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(name: &str, parent: Option<&SeriesWithTitleCount>, title_count: i64) -> SeriesWithTitleCount {
+        SeriesWithTitleCount {
+            series: Series {
+                id: Uuid::new_v4(),
+                name: name.to_string(),
+                description: None,
+                parent_id: parent.map(|p| p.series.id.to_string()),
+                total_volumes: None,
+                reading_order: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            full_path: String::new(),
+            level: 0,
+            title_count,
+        }
+    }
+
+    #[test]
+    fn test_series_tree() {
+        let discworld = series("Discworld", None, 2);
+        let watch = series("City Watch", Some(&discworld), 8);
+        let witches = series("Witches", Some(&discworld), 6);
+        let asterix = series("Asterix", None, 38);
+
+        let tree = SeriesTreeNode::build_tree(vec![witches, asterix, watch, discworld]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[1].series.name, "Discworld");
+        assert_eq!(tree[1].total_title_count, 16);
+        assert_eq!(tree[1].children[0].full_path, "Discworld > City Watch");
+        assert_eq!(tree[1].children[1].level, 1);
+
+        let list = SeriesTreeNode::flatten(tree.clone(), false);
+        let paths: Vec<&str> = list.iter().map(|s| s.full_path.as_str()).collect();
+        assert_eq!(paths, ["Asterix", "Discworld", "Discworld > City Watch", "Discworld > Witches"]);
+        assert_eq!(list[1].title_count, 2);
+        assert_eq!(SeriesTreeNode::flatten(tree, true)[1].title_count, 16);
+    }
+
+    #[test]
+    fn test_parse_series_number() {
+        assert_eq!(parse_series_number("10"), Some(10.0));
+        assert_eq!(parse_series_number("Vol. 2"), Some(2.0));
+        assert_eq!(parse_series_number("3.5"), Some(3.5));
+        assert_eq!(parse_series_number("T03,5"), Some(3.5));
+        assert_eq!(parse_series_number("2, part one"), Some(2.0));
+        assert_eq!(parse_series_number("Hors-série"), None);
+        assert_eq!(parse_series_number(""), None);
+    }
+
+    #[test]
+    fn test_missing_series_numbers() {
+        assert_eq!(missing_series_numbers(["1", "Vol. 3", "3.5"], Some(5)), vec![2, 4, 5]);
+        assert_eq!(missing_series_numbers(["1", "4", "special"], None), vec![2, 3]);
+        assert_eq!(missing_series_numbers([], Some(2)), vec![1, 2]);
+        assert!(missing_series_numbers(["2.5"], None).is_empty());
+    }
+
+    #[test]
+    fn test_missing_series_numbers_huge_numbers() {
+        assert_eq!(missing_series_numbers(["1", "99999999999"], None), Vec::<i32>::new());
+        assert_eq!(missing_series_numbers(["2", "2147483647"], None), vec![1]);
+        assert_eq!(missing_series_numbers([], Some(i32::MAX)).len(), MAX_SERIES_VOLUMES as usize);
+    }
+}
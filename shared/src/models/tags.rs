@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Maximum length of a tag name, in characters.
+pub const MAX_TAG_LENGTH: usize = 100;
+
+/// A free-form label attached to titles (e.g. "signed", "to-read-2026").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(sqlx::FromRow))]
+pub struct Tag {
+    #[cfg_attr(feature = "backend", sqlx(try_from = "String"))]
+    pub id: Uuid,
+    pub name: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Tag with the number of titles carrying it, used for the tag cloud.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagWithTitleCount {
+    #[serde(flatten)]
+    pub tag: Tag,
+    pub title_count: i64,
+}
+
+/// Trims a tag name and collapses inner whitespace, so "  to  read " and
+/// "to read" are the same tag.
+///
+/// Returns `None` when the name is empty or longer than [`MAX_TAG_LENGTH`].
+pub fn normalize_tag_name(name: &str) -> Option<String> {
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    if name.is_empty() || name.chars().count() > MAX_TAG_LENGTH {
+        return None;
+    }
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tag_name() {
+        assert_eq!(normalize_tag_name("  gift from   grandma ").as_deref(), Some("gift from grandma"));
+        assert_eq!(normalize_tag_name("signed").as_deref(), Some("signed"));
+        assert_eq!(normalize_tag_name("   "), None);
+        assert_eq!(normalize_tag_name(&"x".repeat(MAX_TAG_LENGTH + 1)), None);
+    }
+}
@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::enums::{AuthorRole, IdentifierType, TitleRelationType, WishlistPriority};
+
 /// Title represents the abstract book metadata shared across all physical copies.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "backend", derive(sqlx::FromRow))]
@@ -25,19 +29,43 @@ pub struct Title {
     pub pages: Option<i32>,
     /// Language code (e.g., "en", "fr")
     pub language: String,
+    /// Title of the original work, for a translation
+    #[serde(default)]
+    pub original_title: Option<String>,
+    /// Language code of the original work, for a translation
+    #[serde(default)]
+    pub original_language: Option<String>,
+    /// Notes on the translation (translator, abridgement, bilingual edition...)
+    #[serde(default)]
+    pub translation_notes: Option<String>,
     /// Dewey Decimal Classification code (e.g., "005.133")
     pub dewey_code: Option<String>,
     /// Genre name
     #[cfg_attr(feature = "backend", sqlx(rename = "genre_old"))]
     pub genre: Option<String>,
-    /// UUID of the genre entity
+    /// UUID of the primary genre
     pub genre_id: Option<String>,
+    /// UUIDs of all genres of the title, primary genre first
+    #[serde(default)]
+    #[cfg_attr(feature = "backend", sqlx(skip))]
+    pub genre_ids: Vec<String>,
     /// Series name
     pub series_name: Option<String>,
     /// UUID of the series entity
     pub series_id: Option<String>,
     /// Number within the series (e.g., "1", "Vol. 2")
     pub series_number: Option<String>,
+    /// UUID of the work this title is an edition or translation of
+    #[serde(default)]
+    pub work_id: Option<String>,
+    /// Values of the title's custom fields, by custom field UUID
+    #[serde(default)]
+    #[cfg_attr(feature = "backend", sqlx(skip))]
+    pub custom_fields: BTreeMap<String, String>,
+    /// Priority of the title on the wishlist, `None` if it is not wished for
+    #[serde(default)]
+    #[cfg_attr(feature = "backend", sqlx(skip))]
+    pub wishlist_priority: Option<WishlistPriority>,
     /// Plot summary or description
     pub summary: Option<String>,
     /// URL to the cover image
@@ -57,7 +85,7 @@ pub struct Title {
     pub updated_at: DateTime<Utc>,
 }
 
-/// Extends `Title` with the count of physical volumes.
+/// Extends `Title` with the count of physical volumes and its rating.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TitleWithCount {
     /// The base title data
@@ -65,4 +93,113 @@ pub struct TitleWithCount {
     pub title: Title,
     /// Number of physical copies (volumes) associated with this title
     pub volume_count: i64,
+    /// Average star rating given by users, `None` if nobody rated the title
+    #[serde(default)]
+    pub average_rating: Option<f64>,
+    /// Number of users who rated the title
+    #[serde(default)]
+    pub rating_count: i64,
+}
+
+/// An additional identifier of a title (e.g. the paperback ISBN, an ISSN or an OCLC number).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleIdentifier {
+    /// Unique identifier (UUID)
+    pub id: Uuid,
+    /// UUID of the title
+    pub title_id: Uuid,
+    /// Kind of identifier
+    pub identifier_type: IdentifierType,
+    /// Normalized value (see `shared::identifiers::normalize`)
+    pub value: String,
+    /// Timestamp of creation
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A relation between a title and another one, seen from the title it is listed for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleRelation {
+    /// Unique identifier (UUID), shared by both sides of the relation
+    pub id: Uuid,
+    /// What this title is to the related one (e.g. `translation_of`)
+    pub relation_type: TitleRelationType,
+    /// UUID of the related title
+    pub related_title_id: Uuid,
+    /// Main title of the related title
+    pub related_title: String,
+    /// Timestamp of creation
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// An author of a contents entry, with their role in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleContentAuthor {
+    /// UUID of the author
+    pub author_id: Uuid,
+    /// Full name of the author
+    pub name: String,
+    pub role: AuthorRole,
+}
+
+/// A story, essay or poem contained in an anthology or collection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleContent {
+    /// Unique identifier (UUID)
+    pub id: Uuid,
+    /// UUID of the anthology or collection
+    pub title_id: Uuid,
+    /// Position in the table of contents, from 1
+    pub position: i32,
+    /// Title of the story
+    pub title: String,
+    /// Page on which the story starts
+    pub page: Option<i32>,
+    /// Year the story was first published
+    pub original_year: Option<i32>,
+    /// Authors of the story, in display order
+    pub authors: Vec<TitleContentAuthor>,
+}
+
+/// One field changed by a title revision; values are `None` when the field was empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TitleFieldChange {
+    /// Name of the field (e.g. `title`, `publication_year`, `genre_ids`)
+    pub field: String,
+    /// Value before the change
+    pub old_value: Option<String>,
+    /// Value after the change
+    pub new_value: Option<String>,
+}
+
+/// A recorded change to the metadata of a title.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleRevision {
+    /// Unique identifier (UUID)
+    pub id: Uuid,
+    /// UUID of the title
+    pub title_id: Uuid,
+    /// Number of the revision, starting at 1 for each title
+    pub revision_number: i32,
+    /// UUID of the user who made the change, if still known
+    pub user_id: Option<String>,
+    /// Username of the user who made the change, if still known
+    pub username: Option<String>,
+    /// Fields changed by this revision
+    pub changes: Vec<TitleFieldChange>,
+    /// Timestamp of the change
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// The fields of a title that differ between two of its revisions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleRevisionDiff {
+    /// Revision the comparison starts from
+    pub from_revision: i32,
+    /// Revision the comparison ends at
+    pub to_revision: i32,
+    /// Value of each differing field after `from_revision` (`old_value`) and after `to_revision` (`new_value`)
+    pub changes: Vec<TitleFieldChange>,
 }
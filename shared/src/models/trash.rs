@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::TrashItemType;
+
+/// A deleted title, author or borrower waiting in the trash.
+///
+/// Deleted records keep their row with a `deleted_at` timestamp and are hidden
+/// from every list and search until they are restored or purged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashItem {
+    pub item_type: TrashItemType,
+    /// ID of the deleted record (UUID)
+    pub id: String,
+    /// Title, author full name or borrower name
+    pub name: String,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub deleted_at: DateTime<Utc>,
+    /// When the item will be purged automatically, `None` if retention is disabled
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub purge_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::enums::UserRole;
+
+/// User account allowed to access the API.
+///
+/// The password hash is never part of this struct so it cannot leak
+/// through API responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(sqlx::FromRow))]
+pub struct User {
+    /// Unique identifier (UUID)
+    #[cfg_attr(feature = "backend", sqlx(try_from = "String"))]
+    pub id: Uuid,
+    /// Login name (unique)
+    pub username: String,
+    /// Optional name shown in the interface
+    pub display_name: Option<String>,
+    /// Permission level
+    #[cfg_attr(feature = "backend", sqlx(skip))]
+    pub role: UserRole,
+    /// Borrower whose loans this user sees in the self-service portal
+    #[serde(default)]
+    pub borrower_id: Option<String>,
+    /// Disabled accounts cannot log in
+    pub is_active: bool,
+    /// Timestamp of creation
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of last update
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
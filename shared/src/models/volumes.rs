@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -25,6 +27,10 @@ pub struct Volume {
     pub loan_status: LoanStatus,
     /// Individual notes
     pub individual_notes: Option<String>,
+    /// Values of the volume's custom fields, by custom field UUID
+    #[serde(default)]
+    #[cfg_attr(feature = "backend", sqlx(skip))]
+    pub custom_fields: BTreeMap<String, String>,
     /// Timestamp of creation
     #[serde(with = "chrono::serde::ts_seconds")]
     pub created_at: DateTime<Utc>,
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::WishlistPriority;
+
+/// A title the library would like to acquire.
+///
+/// Wishlist entries are attached to a title, usually one without volumes yet;
+/// acquiring the title creates its first volume and removes the entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WishlistItem {
+    /// UUID of the wished title
+    pub title_id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub isbn: Option<String>,
+    /// Names of the title's authors, comma-separated in display order
+    pub authors: Option<String>,
+    pub priority: WishlistPriority,
+    /// Edition wanted, e.g. "hardback" or "2nd edition"
+    pub desired_edition: Option<String>,
+    /// Highest price worth paying
+    pub max_price: Option<f64>,
+    pub notes: Option<String>,
+    /// Number of volumes already owned
+    pub volume_count: i64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A wishlist entry as shown through a share link, without internal notes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedWishlistItem {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub isbn: Option<String>,
+    pub authors: Option<String>,
+    pub priority: WishlistPriority,
+    pub desired_edition: Option<String>,
+    pub max_price: Option<f64>,
+}
+
+/// The wishlist as exported through a share link, highest priority first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedWishlist {
+    /// Name of the share link
+    pub name: String,
+    pub items: Vec<SharedWishlistItem>,
+}
+
+/// A link giving read-only access to the wishlist without an account.
+///
+/// Only the hash of the link's token is stored; the token itself is returned
+/// once, when the link is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WishlistShareLink {
+    /// Unique identifier (UUID)
+    pub id: String,
+    /// Who the link was made for, e.g. "Family"
+    pub name: String,
+    /// Username of the creator, if the account still exists
+    pub created_by: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+}
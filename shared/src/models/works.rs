@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An abstract work grouping the editions and translations of the same text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "backend", derive(sqlx::FromRow))]
+pub struct Work {
+    #[cfg_attr(feature = "backend", sqlx(try_from = "String"))]
+    pub id: Uuid,
+    /// Reference title of the work, usually the original title
+    pub title: String,
+    pub description: Option<String>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Extended work information including the number of titles (editions) attached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkWithTitleCount {
+    #[serde(flatten)]
+    pub work: Work,
+    pub title_count: i64,
+}